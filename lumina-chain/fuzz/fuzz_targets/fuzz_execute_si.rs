@@ -47,6 +47,7 @@ fuzz_target!(|data: FuzzInput| {
     state.stabilization_pool_balance = state.stabilization_pool_balance.saturating_add(1);
     state.reserve_ratio = 1.0;
     state.oracle_prices.insert("LUSD-USD".to_string(), 1_000_000);
+    state.oracle_prices.insert("LUMINA-USD".to_string(), 1_000_000);
 
     let mut ctx = ExecutionContext {
         state: &mut state,
@@ -67,7 +68,7 @@ fuzz_target!(|data: FuzzInput| {
         },
         2 => StablecoinInstruction::FlashMint {
             amount: (data.amount % 1_000_000).saturating_add(1),
-            collateral_asset: AssetType::LUSD,
+            collateral_asset: AssetType::Lumina,
             collateral_amount: (data.collateral_amount % 2_000_000).saturating_add(1),
             commitment: data.commitment,
         },
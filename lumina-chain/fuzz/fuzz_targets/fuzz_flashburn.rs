@@ -4,8 +4,8 @@ use arbitrary::Arbitrary;
 use libfuzzer_sys::fuzz_target;
 
 use lumina_execution::{execute_si, ExecutionContext};
-use lumina_types::instruction::StablecoinInstruction;
-use lumina_types::state::GlobalState;
+use lumina_types::instruction::{AssetType, StablecoinInstruction};
+use lumina_types::state::{GlobalState, Vault};
 
 #[derive(Arbitrary, Debug)]
 struct FlashBurnInput {
@@ -17,12 +17,22 @@ fuzz_target!(|data: FlashBurnInput| {
     let mut state = GlobalState::default();
 
     // Seed a pending flash mint so burn can do real work.
+    let pending = (data.amount % 1_000_000).saturating_add(1);
     {
         let acct = state.accounts.entry(data.sender).or_default();
-        acct.pending_flash_mint = (data.amount % 1_000_000).saturating_add(1);
+        acct.pending_flash_mint = pending;
         acct.pending_flash_collateral = 2_000_000;
-        acct.lusd_balance = acct.pending_flash_mint;
+        acct.pending_flash_asset = Some(AssetType::Lumina);
+        acct.lusd_balance = pending;
     }
+    state.vaults.insert(
+        data.sender,
+        Vault {
+            collateral: [("Lumina".to_string(), 2_000_000)].into(),
+            debt: pending,
+        },
+    );
+    state.oracle_prices.insert("LUMINA-USD".to_string(), 1_000_000);
 
     state.total_lusd_supply = state.total_lusd_supply.saturating_add(10_000_000);
     state.stabilization_pool_balance = state.stabilization_pool_balance.saturating_add(10_000_000);
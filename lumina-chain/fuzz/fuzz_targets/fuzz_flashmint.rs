@@ -17,8 +17,9 @@ struct FlashMintInput {
 
 fuzz_target!(|data: FlashMintInput| {
     let mut state = GlobalState::default();
-    state.oracle_prices.insert("LUSD-USD".to_string(), 1_000_000);
+    state.oracle_prices.insert("LUMINA-USD".to_string(), 1_000_000);
     state.reserve_ratio = 1.0;
+    state.accounts.entry(data.sender).or_default().lumina_balance = 2_000_000;
 
     let mut ctx = ExecutionContext {
         state: &mut state,
@@ -28,7 +29,7 @@ fuzz_target!(|data: FlashMintInput| {
 
     let si = StablecoinInstruction::FlashMint {
         amount: (data.amount % 1_000_000).saturating_add(1),
-        collateral_asset: AssetType::LUSD,
+        collateral_asset: AssetType::Lumina,
         collateral_amount: (data.collateral_amount % 2_000_000).saturating_add(1),
         commitment: data.commitment,
     };
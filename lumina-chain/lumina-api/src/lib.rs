@@ -5,8 +5,10 @@ use axum::{
     routing::{get, post},
     Json, Router,
 };
+use lumina_execution::{gas, verify_transaction_signature};
 use lumina_storage::db::Storage;
 use lumina_types::block::Block;
+use lumina_types::error::ExecutionError;
 use lumina_types::state::GlobalState;
 use lumina_types::transaction::Transaction;
 use prometheus_client::encoding::text::encode;
@@ -49,9 +51,16 @@ pub async fn start_server(
         .route("/tx/signing_bytes", post(tx_signing_bytes))
         .route("/tx", post(submit_tx))
         .route("/block/{height}", get(get_block))
+        .route("/receipt/{tx_id}", get(get_receipt))
         .route("/account/{address}", get(get_account))
+        .route("/redemptions/{address}", get(get_redemptions))
+        .route("/assets/{ticker}", get(get_asset))
+        .route("/yield-tokens/{token_id}", get(get_yield_token))
+        .route("/vaults/{address}", get(get_vault))
         .route("/faucet", post(faucet))
         .route("/validators", get(get_validators))
+        .route("/governance/proposals", get(get_proposals))
+        .route("/params", get(get_params))
         .route("/insurance", get(get_insurance))
         .layer(cors)
         .with_state(state);
@@ -76,6 +85,8 @@ async fn get_state(State(state): State<AppState>) -> Json<serde_json::Value> {
         "stabilization_pool_balance": guard.stabilization_pool_balance,
         "circuit_breaker_active": guard.circuit_breaker_active,
         "insurance_fund_balance": guard.insurance_fund_balance,
+        "insurance_fund_lumina": guard.insurance_fund_lumina,
+        "shielded_lusd_supply": guard.shielded_lusd_supply,
         "health_index": guard.health_index,
         "validator_count": guard.validators.len(),
         "custodian_count": guard.custodians.len(),
@@ -218,6 +229,27 @@ async fn get_block(State(state): State<AppState>, Path(height): Path<u64>) -> Js
     }
 }
 
+async fn get_receipt(
+    State(state): State<AppState>,
+    Path(tx_id): Path<String>,
+) -> Json<serde_json::Value> {
+    if let Ok(bytes) = hex::decode(tx_id.trim_start_matches("0x")) {
+        if let Ok(key) = <[u8; 32]>::try_from(bytes.as_slice()) {
+            if let Ok(Some(receipt)) = state.storage.load_receipt(&key) {
+                let error = receipt.error();
+                return Json(serde_json::json!({
+                    "tx_id": tx_id,
+                    "success": receipt.is_success(),
+                    "error_code": error.map(|e| e.code()),
+                    "error_message": error.map(|e| e.to_string()),
+                    "receipt": receipt,
+                }));
+            }
+        }
+    }
+    Json(serde_json::json!({"error": "Receipt not found"}))
+}
+
 #[derive(serde::Deserialize)]
 struct UnsignedTxRequest {
     pub sender: [u8; 32],
@@ -254,19 +286,52 @@ async fn get_account(
             let mut key = [0u8; 32];
             key.copy_from_slice(&bytes);
             if let Some(account) = guard.accounts.get(&key) {
+                let roles: Vec<_> = guard
+                    .roles
+                    .iter()
+                    .filter(|(_, holders)| holders.contains(&key))
+                    .map(|(role, _)| *role)
+                    .collect();
                 return Json(serde_json::json!({
                     "address": address,
                     "lusd_balance": account.lusd_balance,
                     "ljun_balance": account.ljun_balance,
                     "lumina_balance": account.lumina_balance,
+                    "confidential_commitment": account.commitment.map(hex::encode),
+                    "pending_confidential_commitment": account.pending_commitment.map(hex::encode),
+                    "has_auditor_key": account.auditor_key.is_some(),
+                    "has_receive_key": account.receive_key.is_some(),
                     "nonce": account.nonce,
                     "has_passkey": account.passkey_device_key.is_some(),
                     "guardian_count": account.guardians.len(),
                     "has_pq": account.pq_pubkey.is_some(),
+                    "passkey_auth": account.passkey_auth,
+                    "multisig": account.multisig.as_ref().map(|m| serde_json::json!({
+                        "threshold": m.threshold,
+                        "member_count": m.members.len(),
+                    })),
+                    "session_keys": account.session_keys.iter().map(|k| serde_json::json!({
+                        "pubkey": hex::encode(k.pubkey),
+                        "spend_limit": k.spend_limit,
+                        "spent_in_period": k.spent_in_period,
+                        "expires_at": k.expires_at,
+                    })).collect::<Vec<_>>(),
                     "credit_score": account.credit_score,
-                    "yield_positions": account.yield_positions.len(),
+                    "yield_positions": guard.yield_positions.values().filter(|p| {
+                        p.principal_owner == Some(key) || p.yield_owner == Some(key)
+                    }).count(),
                     "active_streams": account.active_streams.len(),
+                    "outgoing_streams": account.active_streams,
+                    "incoming_streams": account.incoming_streams,
                     "custom_balances": account.custom_balances,
+                    "allowances": account.allowances.iter().flat_map(|(spender, by_asset)| {
+                        by_asset.iter().map(move |(asset, amount)| serde_json::json!({
+                            "spender": hex::encode(spender),
+                            "asset": asset,
+                            "amount": amount,
+                        }))
+                    }).collect::<Vec<_>>(),
+                    "roles": roles,
                 }));
             }
         }
@@ -274,11 +339,124 @@ async fn get_account(
     Json(serde_json::json!({"error": "Account not found"}))
 }
 
+async fn get_redemptions(
+    State(state): State<AppState>,
+    Path(address): Path<String>,
+) -> Json<serde_json::Value> {
+    let guard = state.global_state.read().await;
+    if let Ok(bytes) = hex::decode(address.trim_start_matches("0x")) {
+        if let Ok(key) = <[u8; 32]>::try_from(bytes.as_slice()) {
+            let requests: Vec<serde_json::Value> = guard
+                .fair_redeem_queue
+                .iter()
+                .enumerate()
+                .filter(|(_, r)| r.address == key)
+                .map(|(position, r)| {
+                    serde_json::json!({
+                        "request_id": r.request_id,
+                        "position": position,
+                        "amount": r.amount,
+                        "filled": r.filled,
+                        "remaining": r.remaining(),
+                        "timestamp": r.timestamp,
+                    })
+                })
+                .collect();
+            return Json(serde_json::json!({
+                "address": address,
+                "queue_length": guard.fair_redeem_queue.len(),
+                "requests": requests,
+            }));
+        }
+    }
+    Json(serde_json::json!({"error": "Invalid address"}))
+}
+
+async fn get_asset(
+    State(state): State<AppState>,
+    Path(ticker): Path<String>,
+) -> Json<serde_json::Value> {
+    let guard = state.global_state.read().await;
+    match guard.asset_registry.get(&ticker) {
+        Some(info) => Json(serde_json::json!({
+            "ticker": ticker,
+            "issuer": hex::encode(info.issuer),
+            "decimals": info.decimals,
+            "total_supply": info.total_supply,
+            "max_supply": info.max_supply,
+            "freeze_authority": info.freeze_authority.map(hex::encode),
+            "frozen_accounts": info.frozen.len(),
+        })),
+        None => Json(serde_json::json!({"error": "Asset not found"})),
+    }
+}
+
+async fn get_yield_token(
+    State(state): State<AppState>,
+    Path(token_id): Path<u64>,
+) -> Json<serde_json::Value> {
+    let guard = state.global_state.read().await;
+    match guard.yield_positions.get(&token_id) {
+        Some(position) => Json(serde_json::json!({
+            "token_id": token_id,
+            "principal": position.principal,
+            "issued_height": position.issued_height,
+            "maturity_height": position.maturity_height,
+            "principal_owner": position.principal_owner.map(hex::encode),
+            "yield_owner": position.yield_owner.map(hex::encode),
+            "accrued_yield": position.accrued_yield,
+        })),
+        None => Json(serde_json::json!({"error": "Yield token not found"})),
+    }
+}
+
+async fn get_vault(
+    State(state): State<AppState>,
+    Path(address): Path<String>,
+) -> Json<serde_json::Value> {
+    let guard = state.global_state.read().await;
+    if let Ok(bytes) = hex::decode(address.trim_start_matches("0x")) {
+        if let Ok(key) = <[u8; 32]>::try_from(bytes.as_slice()) {
+            let vault = guard.vaults.get(&key).cloned().unwrap_or_default();
+            let auctions: Vec<serde_json::Value> = guard
+                .vault_auctions
+                .iter()
+                .filter(|(_, a)| a.owner == key)
+                .map(|(auction_id, a)| {
+                    serde_json::json!({
+                        "auction_id": auction_id,
+                        "lot": a.lot,
+                        "debt": a.debt,
+                        "penalty": a.penalty,
+                        "start_price": a.start_price,
+                        "start_height": a.start_height,
+                    })
+                })
+                .collect();
+            return Json(serde_json::json!({
+                "address": address,
+                "collateral": vault.collateral,
+                "debt": vault.debt,
+                "auctions": auctions,
+            }));
+        }
+    }
+    Json(serde_json::json!({"error": "Invalid address"}))
+}
+
 async fn submit_tx(
     State(state): State<AppState>,
     Json(tx): Json<Transaction>,
 ) -> Json<serde_json::Value> {
     let tx_id = hex::encode(tx.id());
+    if let Err(e) = admit_tx(&state, &tx).await {
+        return Json(serde_json::json!({
+            "status": "rejected",
+            "tx_id": tx_id,
+            "error_code": e.code(),
+            "error_message": e.to_string(),
+        }));
+    }
     match state.tx_sender.send(tx).await {
         Ok(_) => Json(serde_json::json!({
             "status": "submitted",
@@ -286,16 +464,48 @@ async fn submit_tx(
         })),
         Err(_) => Json(serde_json::json!({
             "status": "failed",
-            "error": "Channel full or closed",
+            "error_code": "MEMPOOL_UNAVAILABLE",
+            "error_message": "Channel full or closed",
         })),
     }
 }
 
+/// Stateless and sender-state checks a transaction must pass before it is queued.
+/// Nonces ahead of the account are accepted so clients can pipeline transactions.
+async fn admit_tx(state: &AppState, tx: &Transaction) -> Result<(), ExecutionError> {
+    let guard = state.global_state.read().await;
+    let account = guard.accounts.get(&tx.sender).cloned().unwrap_or_default();
+    // Session key expiry is judged against the block the transaction would land in
+    let next_height = state
+        .storage
+        .load_tip()
+        .ok()
+        .flatten()
+        .map(|(height, _)| height + 1)
+        .unwrap_or_default();
+    verify_transaction_signature(&account, tx, next_height)?;
+    if tx.nonce < account.nonce {
+        return Err(ExecutionError::InvalidNonce {
+            expected: account.nonce,
+            got: tx.nonce,
+        });
+    }
+    if tx.gas_price < gas::MIN_GAS_PRICE {
+        return Err(ExecutionError::GasPriceTooLow {
+            price: tx.gas_price,
+            min: gas::MIN_GAS_PRICE,
+        });
+    }
+    Ok(())
+}
+
 async fn faucet(
     State(state): State<AppState>,
     Json(req): Json<serde_json::Value>,
 ) -> Json<serde_json::Value> {
     let amount: u64 = 10_000;
+    // Lumina for gas so funded wallets can submit transactions.
+    let gas_amount: u64 = 1_000_000;
 
     let addr_hex = req
         .get("address")
@@ -322,13 +532,15 @@ async fn faucet(
     let mut guard = state.global_state.write().await;
     let account = guard.accounts.entry(key).or_default();
     account.lusd_balance = account.lusd_balance.saturating_add(amount);
+    account.lumina_balance = account.lumina_balance.saturating_add(gas_amount);
     guard.total_lusd_supply = guard.total_lusd_supply.saturating_add(amount);
 
     Json(serde_json::json!({
         "status": "funded",
         "address": format!("0x{}", addr_hex),
         "amount": amount,
-        "asset": "LUSD"
+        "asset": "LUSD",
+        "gas_amount": gas_amount,
     }))
 }
 
@@ -340,15 +552,55 @@ async fn get_validators(State(state): State<AppState>) -> Json<serde_json::Value
         .map(|v| {
             serde_json::json!({
                 "pubkey": hex::encode(v.pubkey),
+                "operator": hex::encode(v.operator),
                 "stake": v.stake,
                 "power": v.power,
                 "is_green": v.is_green,
+                "status": v.status,
+                "unbonding": v.unbonding,
+                "commission_bps": v.commission_bps,
+                "delegated": v.delegated,
+                "delegator_count": v.delegations.len(),
             })
         })
         .collect();
     Json(serde_json::json!({ "validators": validators }))
 }
 
+async fn get_params(State(state): State<AppState>) -> Json<serde_json::Value> {
+    let guard = state.global_state.read().await;
+    Json(serde_json::json!({
+        "protocol_params": guard.protocol_params,
+        "governance_params": guard.governance_params,
+    }))
+}
+
+async fn get_proposals(State(state): State<AppState>) -> Json<serde_json::Value> {
+    let guard = state.global_state.read().await;
+    let proposals: Vec<serde_json::Value> = guard
+        .proposals
+        .values()
+        .map(|p| {
+            let (yes_power, no_power) = p.tally();
+            serde_json::json!({
+                "id": p.id,
+                "proposer": hex::encode(p.proposer),
+                "action": p.action,
+                "submitted_height": p.submitted_height,
+                "voting_end_height": p.voting_end_height,
+                "yes_power": yes_power,
+                "no_power": no_power,
+                "vote_count": p.votes.len(),
+                "status": p.status,
+            })
+        })
+        .collect();
+    Json(serde_json::json!({
+        "governance_params": guard.governance_params,
+        "proposals": proposals,
+    }))
+}
+
 async fn get_insurance(State(state): State<AppState>) -> Json<serde_json::Value> {
     let guard = state.global_state.read().await;
     Json(serde_json::json!({
@@ -52,17 +52,36 @@ enum Commands {
         #[arg(long)]
         address: String,
     },
+    /// Show queued redemptions and their queue positions
+    Redemptions {
+        #[arg(long)]
+        address: String,
+    },
+    /// Show a registered asset's issuer, supply and freeze settings
+    Asset {
+        #[arg(long)]
+        ticker: String,
+    },
     /// Get block info
     Block {
         #[arg(long)]
         height: u64,
     },
+    /// Get a transaction receipt
+    Receipt {
+        #[arg(long)]
+        tx_id: String,
+    },
     /// Query the Lumina Health Index
     Health,
     /// Query insurance fund
     Insurance,
     /// Query validators
     Validators,
+    /// Query governance proposals
+    Proposals,
+    /// Query protocol parameters
+    Params,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -117,10 +136,11 @@ async fn main() -> Result<()> {
 
             let instruction = match asset.to_lowercase().as_str() {
                 "senior" | "lusd" => {
-                    let collateral = amount.saturating_mul(120) / 100;
+                    let collateral = amount.saturating_mul(150) / 100;
                     let zk = ZkManager::setup();
                     StablecoinInstruction::MintSenior {
                         amount: *amount,
+                        collateral_asset: AssetType::Lumina,
                         collateral_amount: collateral,
                         proof: zk.prove_reserves(vec![collateral], collateral),
                     }
@@ -217,6 +237,36 @@ async fn main() -> Result<()> {
                 println!("Block not found");
             }
         }
+        Commands::Redemptions { address } => {
+            let res = client
+                .get(format!("{}/redemptions/{}", cli.node_url, address))
+                .send()
+                .await?
+                .text()
+                .await?;
+
+            println!("Redemptions:\n{}", res);
+        }
+        Commands::Asset { ticker } => {
+            let res = client
+                .get(format!("{}/assets/{}", cli.node_url, ticker))
+                .send()
+                .await?
+                .text()
+                .await?;
+
+            println!("Asset:\n{}", res);
+        }
+        Commands::Receipt { tx_id } => {
+            let res = client
+                .get(format!("{}/receipt/{}", cli.node_url, tx_id))
+                .send()
+                .await?
+                .text()
+                .await?;
+
+            println!("Receipt:\n{}", res);
+        }
         Commands::Health => {
             let res = client
                 .get(format!("{}/health", cli.node_url))
@@ -247,6 +297,26 @@ async fn main() -> Result<()> {
 
             println!("Validators:\n{}", res);
         }
+        Commands::Proposals => {
+            let res = client
+                .get(format!("{}/governance/proposals", cli.node_url))
+                .send()
+                .await?
+                .text()
+                .await?;
+
+            println!("Governance Proposals:\n{}", res);
+        }
+        Commands::Params => {
+            let res = client
+                .get(format!("{}/params", cli.node_url))
+                .send()
+                .await?
+                .text()
+                .await?;
+
+            println!("Protocol Parameters:\n{}", res);
+        }
     }
 
     Ok(())
@@ -1,9 +1,12 @@
 #![cfg(feature = "malachite")]
 
 use async_trait::async_trait;
-use lumina_crypto::signatures::PublicKey;
-use lumina_execution::{end_block, execute_transaction, ExecutionContext};
+use lumina_execution::{
+    end_block, execute_transaction, verify_transaction_signature, ExecutionContext,
+};
 use lumina_storage::db::Storage;
+use lumina_types::error::ExecutionError;
+use lumina_types::receipt::Receipt;
 use lumina_types::state::GlobalState;
 use lumina_types::transaction::Transaction;
 use serde::{Deserialize, Serialize};
@@ -33,6 +36,35 @@ pub struct DeliverTxRequest {
     pub tx: Vec<u8>,
 }
 
+/// Mempool admission result. `code` is `None` when the transaction is accepted,
+/// otherwise the stable `ExecutionError::code` of the rejection.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckTxResponse {
+    pub code: Option<String>,
+    pub log: String,
+}
+
+impl CheckTxResponse {
+    pub fn is_ok(&self) -> bool {
+        self.code.is_none()
+    }
+}
+
+impl From<Result<(), ExecutionError>> for CheckTxResponse {
+    fn from(result: Result<(), ExecutionError>) -> Self {
+        match result {
+            Ok(()) => CheckTxResponse {
+                code: None,
+                log: String::new(),
+            },
+            Err(e) => CheckTxResponse {
+                code: Some(e.code().to_string()),
+                log: e.to_string(),
+            },
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EndBlockRequest {
     pub height: u64,
@@ -129,22 +161,26 @@ impl LuminaApp {
         tx: &Transaction,
         height: u64,
         timestamp: u64,
-    ) -> Result<(), String> {
+    ) -> Result<(), ExecutionError> {
         let mut dry_run_state = state.clone();
         let mut dry_ctx = ExecutionContext {
             state: &mut dry_run_state,
             height,
             timestamp,
         };
-        execute_transaction(tx, &mut dry_ctx).map_err(|e| e.to_string())
+        execute_transaction(tx, &mut dry_ctx).map(|_| ())
     }
 
-    fn prevalidate_inflight(&self, tx: &Transaction) -> Result<(), String> {
-        let height = self
-            .inflight
+    /// Height the next delivered transaction executes at.
+    fn pending_height(&self) -> u64 {
+        self.inflight
             .as_ref()
             .map(|b| b.height)
-            .unwrap_or(self.height + 1);
+            .unwrap_or(self.height + 1)
+    }
+
+    fn prevalidate_inflight(&self, tx: &Transaction) -> Result<(), ExecutionError> {
+        let height = self.pending_height();
         let timestamp = self
             .inflight
             .as_ref()
@@ -165,12 +201,13 @@ impl LuminaApp {
             };
 
             for tx_bytes in &inflight.txs {
-                let pending_tx = bincode::deserialize::<Transaction>(tx_bytes)
-                    .map_err(|_| "invalid tx bytes in pending block".to_string())?;
-                execute_transaction(&pending_tx, &mut dry_ctx).map_err(|e| e.to_string())?;
+                let pending_tx = bincode::deserialize::<Transaction>(tx_bytes).map_err(|_| {
+                    ExecutionError::invalid_argument("Invalid tx bytes in pending block")
+                })?;
+                execute_transaction(&pending_tx, &mut dry_ctx)?;
             }
 
-            return execute_transaction(tx, &mut dry_ctx).map_err(|e| e.to_string());
+            return execute_transaction(tx, &mut dry_ctx).map(|_| ());
         }
 
         Self::prevalidate_tx_against_state(&self.state, tx, height, timestamp)
@@ -181,7 +218,7 @@ impl LuminaApp {
 pub trait Application {
     async fn init_chain(&mut self, req: InitChainRequest) -> Result<(), String>;
     async fn begin_block(&mut self, req: BeginBlockRequest) -> Result<(), String>;
-    async fn check_tx(&self, tx: &[u8]) -> bool;
+    async fn check_tx(&self, tx: &[u8]) -> CheckTxResponse;
     async fn deliver_tx(&mut self, req: DeliverTxRequest) -> Result<(), String>;
     async fn end_block(&mut self, req: EndBlockRequest) -> Result<(), String>;
     async fn commit(&mut self) -> Result<CommitResponse, String>;
@@ -214,31 +251,29 @@ impl Application for LuminaApp {
         self.persist_wal()
     }
 
-    async fn check_tx(&self, tx: &[u8]) -> bool {
+    async fn check_tx(&self, tx: &[u8]) -> CheckTxResponse {
         let tx: Transaction = match bincode::deserialize(tx) {
             Ok(tx) => tx,
-            Err(_) => return false,
+            Err(_) => {
+                return Err(ExecutionError::invalid_argument("Invalid tx bytes")).into();
+            }
         };
 
-        let tx_key = self
+        let account = self
             .state
             .accounts
             .get(&tx.sender)
-            .and_then(|account| account.pq_pubkey.clone())
-            .map(PublicKey::PostQuantum)
-            .unwrap_or(PublicKey::Ed25519(tx.sender));
-        let signing_bytes = tx.signing_bytes();
-        if tx_key.verify(&signing_bytes, &tx.signature).is_err() {
-            return false;
-        }
-
-        self.prevalidate_inflight(&tx).is_ok()
+            .cloned()
+            .unwrap_or_default();
+        verify_transaction_signature(&account, &tx, self.pending_height())
+            .and_then(|_| self.prevalidate_inflight(&tx))
+            .into()
     }
 
     async fn deliver_tx(&mut self, req: DeliverTxRequest) -> Result<(), String> {
         let tx: Transaction = bincode::deserialize::<Transaction>(&req.tx)
             .map_err(|_| "invalid tx bytes".to_string())?;
-        self.prevalidate_inflight(&tx)?;
+        self.prevalidate_inflight(&tx).map_err(|e| e.to_string())?;
         let Some(inflight) = self.inflight.as_mut() else {
             return Err("begin_block must be called first".to_string());
         };
@@ -268,12 +303,15 @@ impl Application for LuminaApp {
             timestamp: inflight.timestamp,
         };
 
+        // Failed txs are committed with failed receipts; only invalid txs abort the block
+        let mut receipts = Vec::with_capacity(inflight.txs.len());
         for tx_bytes in inflight.txs {
             let tx: Transaction = bincode::deserialize(&tx_bytes).map_err(|e| e.to_string())?;
-            execute_transaction(&tx, &mut ctx).map_err(|e| e.to_string())?;
+            receipts.push(execute_transaction(&tx, &mut ctx).map_err(|e| e.to_string())?);
         }
 
-        end_block(&mut ctx);
+        let events = end_block(&mut ctx);
+        receipts.push(Receipt::end_block(inflight.height, events));
 
         self.height = inflight.height;
         let app_hash = self.state.root_hash();
@@ -283,6 +321,11 @@ impl Application for LuminaApp {
         self.storage
             .save_state_at_height(self.height, &self.state)
             .map_err(|e| e.to_string())?;
+        for receipt in &receipts {
+            self.storage
+                .save_receipt(receipt)
+                .map_err(|e| e.to_string())?;
+        }
         self.clear_wal()?;
 
         Ok(CommitResponse {
@@ -458,13 +501,18 @@ mod tests {
                 pk[0] = i;
                 lumina_types::state::ValidatorState {
                     pubkey: pk,
+                    operator: pk,
                     stake: 10,
                     power: if i == 0 { 20 } else { 10 },
                     is_green: i == 0,
                     energy_proof: if i == 0 { Some(vec![1u8; 64]) } else { None },
+                    status: lumina_types::state::ValidatorStatus::Active,
+                    unbonding: Vec::new(),
+                    ..Default::default()
                 }
             })
-            .collect();
+            .collect::<Vec<_>>()
+            .into();
 
         let validators: Vec<[u8; 32]> = (0u8..7u8)
             .map(|i| {
@@ -2,10 +2,11 @@
 pub mod app;
 
 use anyhow::{bail, Context, Result};
-use lumina_execution::{execute_transaction, ExecutionContext};
+use lumina_execution::{end_block, execute_transaction, ExecutionContext};
 use lumina_network::NetworkCommand;
 use lumina_storage::db::Storage;
 use lumina_types::block::{Block, BlockHeader};
+use lumina_types::receipt::Receipt;
 use lumina_types::state::GlobalState;
 use lumina_types::transaction::Transaction;
 use std::collections::HashSet;
@@ -13,9 +14,6 @@ use std::sync::Arc;
 use tokio::sync::{mpsc, RwLock};
 use tracing::{error, info, warn};
 
-/// Epoch length in blocks for velocity reward epochs
-const EPOCH_LENGTH: u64 = 8640; // ~1 day at 10s/block
-
 pub struct ConsensusService {
     state: Arc<RwLock<GlobalState>>,
     storage: Arc<Storage>,
@@ -224,31 +222,29 @@ impl ConsensusService {
 
         // Execute txs to compute expected state root
         let mut next_state = parent_state;
+        let mut receipts = Vec::with_capacity(block.transactions.len());
         {
             let mut ctx = ExecutionContext {
                 state: &mut next_state,
                 height: block.header.height,
                 timestamp: block.header.timestamp,
             };
+            // Failed txs yield failed receipts; only invalid txs reject the block
             for tx in &block.transactions {
-                execute_transaction(tx, &mut ctx)?;
+                receipts.push(execute_transaction(tx, &mut ctx)?);
             }
 
             // End-of-block: verify flash mints are fully burned
-            if next_state.pending_flash_mints > 0 {
+            if ctx.state.pending_flash_mints > 0 {
                 bail!(
                     "Unresolved flash mints: {} LUSD not burned",
-                    next_state.pending_flash_mints
+                    ctx.state.pending_flash_mints
                 );
             }
 
-            // Epoch transition for velocity rewards
-            if block.header.height % EPOCH_LENGTH == 0 {
-                next_state.current_epoch = next_state
-                    .current_epoch
-                    .checked_add(1)
-                    .unwrap_or(next_state.current_epoch);
-            }
+            // End-of-block: governance tallies, epoch rollover, health index
+            let events = end_block(&mut ctx);
+            receipts.push(Receipt::end_block(ctx.height, events));
         }
 
         let expected_state_root = next_state.root_hash();
@@ -256,8 +252,14 @@ impl ConsensusService {
             bail!("Invalid state_root");
         }
 
+        let expected_receipts_root = Block::receipts_root(&receipts);
+        if block.header.receipts_root != expected_receipts_root {
+            bail!("Invalid receipts_root");
+        }
+
         // Persist fork block
         self.storage.save_block(block)?;
+        self.storage.save_block_receipts(block_hash, &receipts)?;
         self.storage
             .save_block_meta(block_hash, block.header.height, parent_hash)?;
         self.storage.save_state_by_hash(block_hash, &next_state)?;
@@ -298,6 +300,13 @@ impl ConsensusService {
                 .load_state_by_hash(hash)?
                 .ok_or_else(|| anyhow::anyhow!("Missing state for block during reorg"))?;
             self.storage.save_state_at_height(*h, &st)?;
+            let receipts = self
+                .storage
+                .load_block_receipts(hash)?
+                .ok_or_else(|| anyhow::anyhow!("Missing receipts for block during reorg"))?;
+            for receipt in &receipts {
+                self.storage.save_receipt(receipt)?;
+            }
         }
 
         let tip_state = self
@@ -324,6 +333,7 @@ fn build_block_from_parent(
     timestamp: u64,
 ) -> Result<Block> {
     let mut valid_txs = Vec::new();
+    let mut receipts = Vec::new();
 
     {
         let mut ctx = ExecutionContext {
@@ -334,12 +344,18 @@ fn build_block_from_parent(
 
         for tx in txs {
             match execute_transaction(&tx, &mut ctx) {
-                Ok(()) => valid_txs.push(tx),
+                Ok(receipt) => {
+                    valid_txs.push(tx);
+                    receipts.push(receipt);
+                }
                 Err(e) => {
-                    warn!("Tx execution failed during block build: {}", e);
+                    warn!("Dropping invalid tx during block build: {}", e);
                 }
             }
         }
+
+        let events = end_block(&mut ctx);
+        receipts.push(Receipt::end_block(height, events));
     }
 
     if valid_txs.is_empty() {
@@ -348,6 +364,7 @@ fn build_block_from_parent(
 
     let transactions_root = Block::transactions_root(&valid_txs);
     let state_root = parent_state.root_hash();
+    let receipts_root = Block::receipts_root(&receipts);
 
     Ok(Block {
         header: BlockHeader {
@@ -355,6 +372,7 @@ fn build_block_from_parent(
             prev_hash,
             transactions_root,
             state_root,
+            receipts_root,
             timestamp,
             proposer: [0u8; 32],
         },
@@ -0,0 +1,117 @@
+//! Auditor view notes for confidential transfers.
+//!
+//! A note opens one transfer's amount commitment to the holder of a Kyber768
+//! secret key: the sender encapsulates a fresh shared secret to the auditor's
+//! public key and seals `amount || blinding` under a key derived from it. The
+//! amount commitment is bound in as associated data, so a note cannot be
+//! replayed against another transfer.
+
+use crate::pq::{kyber_decapsulate, kyber_encapsulate, KYBER768_CIPHERTEXT_BYTES};
+use crate::zk::commit_value;
+use anyhow::{anyhow, ensure, Result};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+
+const AUDIT_NOTE_KDF_CONTEXT: &str = "lumina-confidential-audit-note-v1";
+/// Amount (8 bytes) and blinding factor (32 bytes).
+const OPENING_BYTES: usize = 40;
+const TAG_BYTES: usize = 16;
+
+/// Size of an encoded audit note: Kyber ciphertext followed by the sealed opening.
+pub const AUDIT_NOTE_BYTES: usize = KYBER768_CIPHERTEXT_BYTES + OPENING_BYTES + TAG_BYTES;
+
+/// Every note uses a freshly encapsulated key, so a fixed nonce is never reused.
+fn cipher(shared_secret: &[u8]) -> ChaCha20Poly1305 {
+    let key = blake3::derive_key(AUDIT_NOTE_KDF_CONTEXT, shared_secret);
+    ChaCha20Poly1305::new(Key::from_slice(&key))
+}
+
+/// Encrypt the opening of `amount_commitment` to an auditor's Kyber768 key.
+pub fn encrypt_audit_note(
+    auditor_public_key: &[u8],
+    amount_commitment: &[u8; 32],
+    amount: u64,
+    blinding: [u8; 32],
+) -> Result<Vec<u8>> {
+    let (ciphertext, shared_secret) = kyber_encapsulate(auditor_public_key)?;
+    let mut opening = Vec::with_capacity(OPENING_BYTES);
+    opening.extend_from_slice(&amount.to_le_bytes());
+    opening.extend_from_slice(&blinding);
+
+    let sealed = cipher(&shared_secret)
+        .encrypt(
+            Nonce::from_slice(&[0u8; 12]),
+            Payload {
+                msg: &opening,
+                aad: amount_commitment,
+            },
+        )
+        .map_err(|_| anyhow!("Audit note encryption failed"))?;
+
+    let mut note = ciphertext;
+    note.extend_from_slice(&sealed);
+    Ok(note)
+}
+
+/// Decrypt an audit note with the auditor's Kyber768 secret key and return the
+/// transferred amount, after checking it opens `amount_commitment`.
+pub fn decrypt_audit_note(
+    auditor_secret_key: &[u8],
+    amount_commitment: &[u8; 32],
+    note: &[u8],
+) -> Result<u64> {
+    ensure!(note.len() == AUDIT_NOTE_BYTES, "Malformed audit note");
+    let (ciphertext, sealed) = note.split_at(KYBER768_CIPHERTEXT_BYTES);
+    let shared_secret = kyber_decapsulate(auditor_secret_key, ciphertext)?;
+
+    let opening = cipher(&shared_secret)
+        .decrypt(
+            Nonce::from_slice(&[0u8; 12]),
+            Payload {
+                msg: sealed,
+                aad: amount_commitment,
+            },
+        )
+        .map_err(|_| anyhow!("Audit note decryption failed"))?;
+
+    let mut amount = [0u8; 8];
+    amount.copy_from_slice(&opening[..8]);
+    let amount = u64::from_le_bytes(amount);
+    let mut blinding = [0u8; 32];
+    blinding.copy_from_slice(&opening[8..]);
+    ensure!(
+        commit_value(amount, blinding) == *amount_commitment,
+        "Audit note does not open the amount commitment"
+    );
+    Ok(amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "pq-crypto")]
+    fn audit_note_roundtrip() {
+        use pqcrypto_kyber::kyber768;
+        use pqcrypto_traits::kem::{PublicKey as _, SecretKey as _};
+
+        let (pk, sk) = kyber768::keypair();
+        let commitment = commit_value(250, [4u8; 32]);
+        let note = encrypt_audit_note(pk.as_bytes(), &commitment, 250, [4u8; 32]).unwrap();
+        assert_eq!(note.len(), AUDIT_NOTE_BYTES);
+        assert_eq!(
+            decrypt_audit_note(sk.as_bytes(), &commitment, &note).unwrap(),
+            250
+        );
+
+        let other = commit_value(250, [5u8; 32]);
+        assert!(decrypt_audit_note(sk.as_bytes(), &other, &note).is_err());
+    }
+
+    #[test]
+    fn malformed_note_is_rejected() {
+        let commitment = commit_value(1, [0u8; 32]);
+        assert!(decrypt_audit_note(&[], &commitment, &[0u8; 10]).is_err());
+    }
+}
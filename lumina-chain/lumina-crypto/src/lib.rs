@@ -1,3 +1,4 @@
+pub mod audit;
 pub mod bls;
 pub mod hashing;
 pub mod pq;
@@ -12,6 +12,11 @@ use pqcrypto_traits::kem::{Ciphertext as _, PublicKey as _, SecretKey as _, Shar
 #[cfg(feature = "pq-crypto")]
 use pqcrypto_traits::sign::{DetachedSignature as _, PublicKey as _};
 
+/// Size of a Kyber768 public key.
+pub const KYBER768_PUBLIC_KEY_BYTES: usize = 1184;
+/// Size of a Kyber768 encapsulation ciphertext.
+pub const KYBER768_CIPHERTEXT_BYTES: usize = 1088;
+
 /// Verify Dilithium detached signatures when `pq-crypto` is enabled.
 pub fn verify_dilithium_signature(
     public_key: &[u8],
@@ -5,11 +5,14 @@ use ed25519_dalek::{Signature, Signer, Verifier};
 use rand::rngs::OsRng;
 
 pub mod post_quantum;
+pub mod webauthn;
 
 #[derive(Debug, Clone)]
 pub enum PublicKey {
     Ed25519([u8; 32]),
     PostQuantum(Vec<u8>),
+    /// SEC1-encoded P-256 passkey; signatures are encoded WebAuthn assertions
+    Passkey(Vec<u8>),
 }
 
 impl PublicKey {
@@ -19,6 +22,9 @@ impl PublicKey {
             PublicKey::PostQuantum(pubkey) => {
                 post_quantum::verify_pq_signature(pubkey, message, signature)
             }
+            PublicKey::Passkey(pubkey) => {
+                webauthn::verify_passkey_signature(pubkey, message, signature)
+            }
         }
     }
 }
@@ -0,0 +1,198 @@
+//! WebAuthn (passkey) assertions over P-256.
+//!
+//! A passkey signs `authenticatorData || SHA-256(clientDataJSON)`. The chain
+//! binds an assertion to a message by requiring the `challenge` field of the
+//! client data to be the base64url encoding of `SHA-256(message)`.
+//!
+//! Only assertions scoped to [`RP_ID`] are accepted, and signatures must be in
+//! low-S form so a third party cannot re-encode one and change the transaction id.
+
+use anyhow::{anyhow, bail, ensure, Result};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use p256::ecdsa::signature::{Signer, Verifier};
+use p256::ecdsa::{Signature, VerifyingKey};
+use sha2::{Digest, Sha256};
+
+pub use p256::ecdsa::SigningKey as PasskeySigningKey;
+
+/// Relying party id passkeys are registered under; `rpIdHash` must be its SHA-256.
+pub const RP_ID: &str = "lumina";
+/// Minimum authenticator data: rpIdHash (32) + flags (1) + signCount (4).
+const MIN_AUTHENTICATOR_DATA_LEN: usize = 37;
+/// User-present flag in the authenticator data flags byte.
+const FLAG_USER_PRESENT: u8 = 0x01;
+
+/// The parts of a WebAuthn assertion needed to verify it on-chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebAuthnAssertion {
+    pub authenticator_data: Vec<u8>,
+    pub client_data_json: Vec<u8>,
+    /// ASN.1 DER encoded ECDSA signature, as returned by the authenticator
+    pub signature: Vec<u8>,
+}
+
+impl WebAuthnAssertion {
+    /// Encodes the assertion as three u32-LE length-prefixed fields. This is the
+    /// form carried in `Transaction::signature` for passkey accounts.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let parts = [
+            &self.authenticator_data,
+            &self.client_data_json,
+            &self.signature,
+        ];
+        let mut out = Vec::with_capacity(12 + parts.iter().map(|p| p.len()).sum::<usize>());
+        for part in parts {
+            out.extend_from_slice(&(part.len() as u32).to_le_bytes());
+            out.extend_from_slice(part);
+        }
+        out
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut rest = bytes;
+        let mut next = || -> Result<Vec<u8>> {
+            ensure!(rest.len() >= 4, "Truncated WebAuthn assertion");
+            let (len, tail) = rest.split_at(4);
+            let len = u32::from_le_bytes([len[0], len[1], len[2], len[3]]) as usize;
+            ensure!(tail.len() >= len, "Truncated WebAuthn assertion");
+            let (part, tail) = tail.split_at(len);
+            rest = tail;
+            Ok(part.to_vec())
+        };
+        let assertion = WebAuthnAssertion {
+            authenticator_data: next()?,
+            client_data_json: next()?,
+            signature: next()?,
+        };
+        ensure!(rest.is_empty(), "Trailing bytes after WebAuthn assertion");
+        Ok(assertion)
+    }
+}
+
+/// The challenge a passkey must sign to authorize `message`.
+pub fn challenge_for(message: &[u8]) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(message))
+}
+
+/// Whether `device_key` is a well-formed SEC1 P-256 public key.
+pub fn is_valid_device_key(device_key: &[u8]) -> bool {
+    VerifyingKey::from_sec1_bytes(device_key).is_ok()
+}
+
+/// Verify an encoded [`WebAuthnAssertion`] against a SEC1-encoded P-256 key.
+pub fn verify_passkey_signature(device_key: &[u8], message: &[u8], signature: &[u8]) -> Result<()> {
+    let assertion = WebAuthnAssertion::from_bytes(signature)?;
+    let key = VerifyingKey::from_sec1_bytes(device_key)
+        .map_err(|_| anyhow!("Invalid passkey public key"))?;
+
+    let auth_data = &assertion.authenticator_data;
+    ensure!(
+        auth_data.len() >= MIN_AUTHENTICATOR_DATA_LEN,
+        "Authenticator data too short"
+    );
+    ensure!(
+        auth_data[..32] == Sha256::digest(RP_ID.as_bytes())[..],
+        "Assertion is for a different relying party"
+    );
+    ensure!(
+        auth_data[32] & FLAG_USER_PRESENT != 0,
+        "Assertion lacks user presence"
+    );
+
+    let client_data: serde_json::Value = serde_json::from_slice(&assertion.client_data_json)
+        .map_err(|_| anyhow!("Malformed clientDataJSON"))?;
+    if client_data.get("type").and_then(|t| t.as_str()) != Some("webauthn.get") {
+        bail!("clientDataJSON is not an assertion");
+    }
+    if client_data.get("challenge").and_then(|c| c.as_str()) != Some(&challenge_for(message)) {
+        bail!("Assertion challenge does not match the message");
+    }
+
+    let sig = Signature::from_der(&assertion.signature)
+        .map_err(|_| anyhow!("Invalid passkey signature encoding"))?;
+    ensure!(
+        sig.normalize_s().is_none(),
+        "Passkey signature is not in low-S form"
+    );
+    key.verify(&signed_data(&assertion), &sig)
+        .map_err(|_| anyhow!("Passkey signature verification failed"))
+}
+
+/// Produce an encoded assertion over `message` the way a platform
+/// authenticator would, for clients and tests that hold the device key.
+/// The signature is normalized to low-S, as clients must do before submitting.
+pub fn sign_passkey_assertion(key: &PasskeySigningKey, message: &[u8]) -> Vec<u8> {
+    let mut authenticator_data = vec![0u8; MIN_AUTHENTICATOR_DATA_LEN];
+    authenticator_data[..32].copy_from_slice(&Sha256::digest(RP_ID.as_bytes()));
+    authenticator_data[32] = FLAG_USER_PRESENT;
+    let client_data_json = serde_json::json!({
+        "type": "webauthn.get",
+        "challenge": challenge_for(message),
+        "origin": "https://lumina",
+    })
+    .to_string()
+    .into_bytes();
+
+    let mut assertion = WebAuthnAssertion {
+        authenticator_data,
+        client_data_json,
+        signature: Vec::new(),
+    };
+    let sig: Signature = key.sign(&signed_data(&assertion));
+    let sig = sig.normalize_s().unwrap_or(sig);
+    assertion.signature = sig.to_der().as_bytes().to_vec();
+    assertion.to_bytes()
+}
+
+fn signed_data(assertion: &WebAuthnAssertion) -> Vec<u8> {
+    let mut data = assertion.authenticator_data.clone();
+    data.extend_from_slice(&Sha256::digest(&assertion.client_data_json));
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn device() -> PasskeySigningKey {
+        PasskeySigningKey::from_slice(&[7u8; 32]).unwrap()
+    }
+
+    fn device_key() -> Vec<u8> {
+        device().verifying_key().to_sec1_bytes().to_vec()
+    }
+
+    #[test]
+    fn assertion_roundtrip_verifies() {
+        let signature = sign_passkey_assertion(&device(), b"tx");
+        assert!(verify_passkey_signature(&device_key(), b"tx", &signature).is_ok());
+        assert!(verify_passkey_signature(&device_key(), b"other", &signature).is_err());
+    }
+
+    #[test]
+    fn high_s_signature_is_rejected() {
+        let mut assertion =
+            WebAuthnAssertion::from_bytes(&sign_passkey_assertion(&device(), b"tx")).unwrap();
+        let sig = Signature::from_der(&assertion.signature).unwrap();
+        let (r, s) = sig.split_scalars();
+        let high_s = Signature::from_scalars(r, -s).unwrap();
+        assertion.signature = high_s.to_der().as_bytes().to_vec();
+
+        // Same curve point relation, so only the low-S rule rejects it
+        let key = VerifyingKey::from_sec1_bytes(&device_key()).unwrap();
+        assert!(key.verify(&signed_data(&assertion), &high_s).is_ok());
+        assert!(verify_passkey_signature(&device_key(), b"tx", &assertion.to_bytes()).is_err());
+    }
+
+    #[test]
+    fn assertion_for_another_relying_party_is_rejected() {
+        let mut assertion =
+            WebAuthnAssertion::from_bytes(&sign_passkey_assertion(&device(), b"tx")).unwrap();
+        assertion.authenticator_data[..32].copy_from_slice(&Sha256::digest(b"evil.example"));
+        let sig: Signature = device().sign(&signed_data(&assertion));
+        let sig = sig.normalize_s().unwrap_or(sig);
+        assertion.signature = sig.to_der().as_bytes().to_vec();
+        assert!(verify_passkey_signature(&device_key(), b"tx", &assertion.to_bytes()).is_err());
+    }
+}
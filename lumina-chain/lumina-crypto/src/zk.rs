@@ -5,7 +5,7 @@ use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisE
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_snark::SNARK;
 use bulletproofs::{BulletproofGens, PedersenGens, RangeProof};
-use curve25519_dalek_ng::ristretto::CompressedRistretto;
+use curve25519_dalek_ng::ristretto::{CompressedRistretto, RistrettoPoint};
 use curve25519_dalek_ng::scalar::Scalar;
 use merlin::Transcript;
 use rand::{rngs::StdRng, thread_rng, SeedableRng};
@@ -15,6 +15,7 @@ const MAX_RESERVES: usize = 100;
 const RANGE_BITS: usize = 64;
 const BULLETPROOF_DOMAIN: &[u8] = b"lumina-confidential-transfer-v1";
 const INSURANCE_BULLETPROOF_DOMAIN: &[u8] = b"lumina-insurance-claim-v1";
+const CONFIDENTIAL_SPEND_DOMAIN: &[u8] = b"lumina-confidential-spend-v1";
 
 fn alloc_u64_bits(
     cs: ConstraintSystemRef<Fr>,
@@ -259,8 +260,101 @@ pub fn verify_confidential_transfer(commitment: &[u8; 32], proof: &[u8]) -> bool
         .is_ok()
 }
 
-pub fn verify_confidential_proof(commitment: &[u8; 32], proof: &[u8]) -> bool {
-    verify_confidential_transfer(commitment, proof)
+/// Pedersen commitment `value·B + blinding·B_blinding`. Public amounts use a
+/// zero blinding factor.
+pub fn commit_value(value: u64, blinding: [u8; 32]) -> [u8; 32] {
+    PedersenGens::default()
+        .commit(Scalar::from(value), Scalar::from_bytes_mod_order(blinding))
+        .compress()
+        .to_bytes()
+}
+
+fn decompress(commitment: &[u8; 32]) -> Option<RistrettoPoint> {
+    CompressedRistretto(*commitment).decompress()
+}
+
+/// Homomorphic sum of two commitments; `None` if either is not a valid point.
+pub fn add_commitments(a: &[u8; 32], b: &[u8; 32]) -> Option<[u8; 32]> {
+    Some((decompress(a)? + decompress(b)?).compress().to_bytes())
+}
+
+/// Homomorphic difference of two commitments; `None` if either is not a valid point.
+pub fn sub_commitments(a: &[u8; 32], b: &[u8; 32]) -> Option<[u8; 32]> {
+    Some((decompress(a)? - decompress(b)?).compress().to_bytes())
+}
+
+/// Spend of a committed amount out of a confidential balance.
+#[derive(Debug, Clone)]
+pub struct ConfidentialSpend {
+    pub amount_commitment: [u8; 32],
+    /// Aggregated range proof over the amount and the remaining balance
+    pub proof: Vec<u8>,
+    /// Blinding factor of the balance left behind, needed for the next spend
+    pub remaining_blinding: [u8; 32],
+}
+
+/// Prove that spending `amount` out of a confidential balance of `balance`
+/// (committed with `balance_blinding`) leaves a non-negative remainder.
+/// Returns `None` if `amount` exceeds `balance`.
+pub fn prove_confidential_spend(
+    balance: u64,
+    balance_blinding: [u8; 32],
+    amount: u64,
+    amount_blinding: [u8; 32],
+) -> Option<ConfidentialSpend> {
+    let remaining = balance.checked_sub(amount)?;
+    let amount_blinding = Scalar::from_bytes_mod_order(amount_blinding);
+    let remaining_blinding = Scalar::from_bytes_mod_order(balance_blinding) - amount_blinding;
+
+    let bp_gens = BulletproofGens::new(RANGE_BITS, 2);
+    let pc_gens = PedersenGens::default();
+    let mut transcript = Transcript::new(CONFIDENTIAL_SPEND_DOMAIN);
+    let (proof, commitments) = RangeProof::prove_multiple(
+        &bp_gens,
+        &pc_gens,
+        &mut transcript,
+        &[amount, remaining],
+        &[amount_blinding, remaining_blinding],
+        RANGE_BITS,
+    )
+    .expect("confidential spend bulletproof generation");
+
+    Some(ConfidentialSpend {
+        amount_commitment: commitments[0].to_bytes(),
+        proof: proof.to_bytes(),
+        remaining_blinding: remaining_blinding.to_bytes(),
+    })
+}
+
+/// Verify a [`ConfidentialSpend`] proof: both the spent amount and the
+/// balance remaining after it lie in `[0, 2^64)`.
+pub fn verify_confidential_proof(
+    amount_commitment: &[u8; 32],
+    remaining_commitment: &[u8; 32],
+    proof: &[u8],
+) -> bool {
+    let range_proof = match RangeProof::from_bytes(proof) {
+        Ok(p) => p,
+        Err(_) => return false,
+    };
+
+    let commitments = [
+        CompressedRistretto(*amount_commitment),
+        CompressedRistretto(*remaining_commitment),
+    ];
+    let bp_gens = BulletproofGens::new(RANGE_BITS, 2);
+    let pc_gens = PedersenGens::default();
+    let mut transcript = Transcript::new(CONFIDENTIAL_SPEND_DOMAIN);
+
+    range_proof
+        .verify_multiple(
+            &bp_gens,
+            &pc_gens,
+            &mut transcript,
+            &commitments,
+            RANGE_BITS,
+        )
+        .is_ok()
 }
 
 pub fn verify_compliance_proof(tx_hash: &[u8; 32], proof: &[u8]) -> bool {
@@ -374,6 +468,25 @@ mod tests {
         assert!(!verify_confidential_transfer(&commitment, &tampered));
     }
 
+    #[test]
+    fn confidential_spend_proves_non_negative_remainder() {
+        let balance = commit_value(100, [5u8; 32]);
+        let spend = prove_confidential_spend(100, [5u8; 32], 40, [8u8; 32]).unwrap();
+        let remaining = sub_commitments(&balance, &spend.amount_commitment).unwrap();
+        assert_eq!(remaining, commit_value(60, spend.remaining_blinding));
+        assert!(verify_confidential_proof(
+            &spend.amount_commitment,
+            &remaining,
+            &spend.proof
+        ));
+        assert!(!verify_confidential_proof(
+            &spend.amount_commitment,
+            &balance,
+            &spend.proof
+        ));
+        assert!(prove_confidential_spend(100, [5u8; 32], 101, [8u8; 32]).is_none());
+    }
+
     #[test]
     fn insurance_claim_bulletproof_verifies() {
         let proof = create_insurance_loss_proof(1_234, [3u8; 32]);
@@ -0,0 +1,248 @@
+//! Gas schedule for native StablecoinInstructions.
+//!
+//! Costs are flat per instruction, plus a per-item component for variants that
+//! iterate over state (yield distribution, redemption queue, order batches).
+//! Proof verification dominates the schedule, so ZK-carrying variants are priced
+//! an order of magnitude above plain balance updates.
+//!
+//! Gas is metered as execution proceeds: a [`GasMeter`] is charged for each
+//! instruction against the state it is about to run on, so bundle steps pay for
+//! the state left behind by the steps before them.
+
+use lumina_types::error::ExecutionError;
+use lumina_types::instruction::StablecoinInstruction;
+use lumina_types::state::GlobalState;
+
+/// Flat cost charged to every transaction (signature check, nonce, fee accounting).
+pub const TX_BASE_GAS: u64 = 200;
+/// Lowest accepted gas price, so that no transaction executes for free.
+pub const MIN_GAS_PRICE: u64 = 1;
+
+/// Plain balance move between two accounts.
+pub const TRANSFER_GAS: u64 = 300;
+/// Single-account state update with no iteration and no proof.
+pub const SIMPLE_OP_GAS: u64 = 1_000;
+/// Registry writes (assets, validators, custodians, listings).
+pub const REGISTRY_OP_GAS: u64 = 5_000;
+/// Ed25519 verification of a guardian signature.
+pub const SIGNATURE_VERIFY_GAS: u64 = 3_000;
+/// Context-bound Groth16 envelope check (compliance, tax, credit, RWA, green energy).
+pub const BOUND_PROOF_GAS: u64 = 20_000;
+/// Bulletproof range proof verification.
+pub const BULLETPROOF_VERIFY_GAS: u64 = 40_000;
+/// Full Groth16 pairing check (reserve proofs).
+pub const GROTH16_VERIFY_GAS: u64 = 50_000;
+/// Per-item cost for instructions that walk a list held in state.
+pub const PER_ITEM_GAS: u64 = 200;
+
+/// Running gas count for one transaction, bounded by its gas limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GasMeter {
+    limit: u64,
+    used: u64,
+}
+
+impl GasMeter {
+    pub fn new(limit: u64) -> Self {
+        Self { limit, used: 0 }
+    }
+
+    /// A meter that never runs out, for execution paths that are not fee-bearing.
+    pub fn unlimited() -> Self {
+        Self::new(u64::MAX)
+    }
+
+    pub fn used(&self) -> u64 {
+        self.used
+    }
+
+    /// Consumes `amount`, or fails with `OutOfGas` leaving the meter untouched.
+    pub fn charge(&mut self, amount: u64) -> Result<(), ExecutionError> {
+        let required = self.used.saturating_add(amount);
+        if required > self.limit {
+            return Err(ExecutionError::OutOfGas {
+                required,
+                limit: self.limit,
+            });
+        }
+        self.used = required;
+        Ok(())
+    }
+}
+
+/// Gas consumed by `si` when executed against `state`, excluding [`TX_BASE_GAS`].
+///
+/// Iterating variants are priced against the state they will actually walk. Bundles
+/// are the exception: this is an estimate from the pre-state, while execution
+/// charges each bundled instruction against the state it runs on.
+pub fn instruction_gas(si: &StablecoinInstruction, state: &GlobalState) -> u64 {
+    match si {
+        StablecoinInstruction::Transfer { .. }
+        | StablecoinInstruction::Burn { .. }
+        | StablecoinInstruction::MintAsset { .. }
+        | StablecoinInstruction::BurnAsset { .. }
+        | StablecoinInstruction::TransferFrom { .. } => TRANSFER_GAS,
+
+        StablecoinInstruction::RegisterAsset { .. }
+        | StablecoinInstruction::RegisterValidator { .. }
+        | StablecoinInstruction::RegisterCustodian { .. }
+        | StablecoinInstruction::UploadComplianceCircuit { .. }
+        | StablecoinInstruction::CreatePasskeyAccount { .. }
+        | StablecoinInstruction::SwitchToPQSignature { .. }
+        | StablecoinInstruction::SwitchToPasskeySignature
+        | StablecoinInstruction::CreateMultisigAccount { .. }
+        | StablecoinInstruction::AddMultisigMember { .. }
+        | StablecoinInstruction::RemoveMultisigMember { .. }
+        | StablecoinInstruction::SetMultisigThreshold { .. }
+        | StablecoinInstruction::RegisterSessionKey { .. }
+        | StablecoinInstruction::RevokeSessionKey { .. }
+        | StablecoinInstruction::ConfigureAsset { .. }
+        | StablecoinInstruction::SetAuditorKey { .. }
+        | StablecoinInstruction::SetReceiveKey { .. }
+        | StablecoinInstruction::SetAssetFrozen { .. }
+        | StablecoinInstruction::SubmitProposal { .. } => REGISTRY_OP_GAS,
+
+        StablecoinInstruction::MintSenior { .. } | StablecoinInstruction::SubmitZkPoR { .. } => {
+            GROTH16_VERIFY_GAS
+        }
+
+        StablecoinInstruction::ConfidentialTransfer { .. }
+        | StablecoinInstruction::Unshield { .. }
+        | StablecoinInstruction::ClaimInsurance { .. } => BULLETPROOF_VERIFY_GAS,
+
+        StablecoinInstruction::ProveCompliance { .. }
+        | StablecoinInstruction::ZkTaxAttest { .. }
+        | StablecoinInstruction::MultiJurisdictionalCheck { .. }
+        | StablecoinInstruction::RegisterGreenValidator { .. }
+        | StablecoinInstruction::SubmitGreenProof { .. } => BOUND_PROOF_GAS,
+
+        // Falls back to a Groth16-verified MintSenior when the score path is rejected.
+        StablecoinInstruction::MintWithCreditScore { .. } => {
+            BOUND_PROOF_GAS.saturating_add(GROTH16_VERIFY_GAS)
+        }
+
+        StablecoinInstruction::ListRWA { .. } => BOUND_PROOF_GAS.saturating_add(REGISTRY_OP_GAS),
+
+        StablecoinInstruction::RecoverSocial {
+            guardian_signatures,
+            ..
+        } => SIMPLE_OP_GAS
+            .saturating_add(SIGNATURE_VERIFY_GAS.saturating_mul(guardian_signatures.len() as u64)),
+
+        // Estimate only: each bundled instruction is priced against the pre-state here
+        StablecoinInstruction::Bundle { instructions } => {
+            instructions.iter().fold(0u64, |acc, inner| {
+                acc.saturating_add(instruction_gas(inner, state))
+                    .saturating_add(PER_ITEM_GAS)
+            })
+        }
+
+        StablecoinInstruction::SubmitEvidence { .. } => {
+            SIMPLE_OP_GAS.saturating_add(SIGNATURE_VERIFY_GAS.saturating_mul(2))
+        }
+
+        StablecoinInstruction::UpdateRoleByAdmin {
+            admin_signatures, ..
+        } => SIMPLE_OP_GAS
+            .saturating_add(SIGNATURE_VERIFY_GAS.saturating_mul(admin_signatures.len() as u64)),
+
+        StablecoinInstruction::DistributeYield { .. } => {
+            let holders = state
+                .accounts
+                .values()
+                .filter(|a| a.ljun_balance > 0)
+                .count() as u64;
+            let items = holders.saturating_add(state.yield_positions.len() as u64);
+            REGISTRY_OP_GAS.saturating_add(PER_ITEM_GAS.saturating_mul(items))
+        }
+
+        StablecoinInstruction::FairRedeemQueue { batch_size } => {
+            let items = (*batch_size as u64).min(state.fair_redeem_queue.len() as u64);
+            SIMPLE_OP_GAS.saturating_add(PER_ITEM_GAS.saturating_mul(items))
+        }
+
+        StablecoinInstruction::CancelRedemption { .. } => SIMPLE_OP_GAS
+            .saturating_add(PER_ITEM_GAS.saturating_mul(state.fair_redeem_queue.len() as u64)),
+
+        // Every order carries a maker signature
+        StablecoinInstruction::ZeroSlipBatchMatch { orders } => SIMPLE_OP_GAS.saturating_add(
+            (PER_ITEM_GAS + SIGNATURE_VERIFY_GAS).saturating_mul(orders.len() as u64),
+        ),
+
+        StablecoinInstruction::GeoRebalance { .. }
+        | StablecoinInstruction::RotateReserves { .. } => {
+            SIMPLE_OP_GAS.saturating_add(PER_ITEM_GAS.saturating_mul(state.custodians.len() as u64))
+        }
+
+        StablecoinInstruction::Vote { .. } | StablecoinInstruction::ExecuteProposal { .. } => {
+            SIMPLE_OP_GAS.saturating_add(PER_ITEM_GAS.saturating_mul(state.validators.len() as u64))
+        }
+
+        // Seizing a vault and buying from its auction walk the collateral lot
+        StablecoinInstruction::LiquidateVault { owner } => {
+            let items = state.vaults.get(owner).map_or(0, |v| v.collateral.len()) as u64;
+            SIMPLE_OP_GAS.saturating_add(PER_ITEM_GAS.saturating_mul(items))
+        }
+
+        StablecoinInstruction::BidVaultAuction { auction_id, .. } => {
+            let items = state
+                .vault_auctions
+                .get(auction_id)
+                .map_or(0, |a| a.lot.len()) as u64;
+            SIMPLE_OP_GAS.saturating_add(PER_ITEM_GAS.saturating_mul(items))
+        }
+
+        StablecoinInstruction::ComputeHealthIndex => {
+            SIMPLE_OP_GAS.saturating_add(PER_ITEM_GAS.saturating_mul(state.validators.len() as u64))
+        }
+
+        StablecoinInstruction::MintJunior { .. }
+        | StablecoinInstruction::RedeemSenior { .. }
+        | StablecoinInstruction::RedeemJunior { .. }
+        | StablecoinInstruction::RebalanceTranches
+        | StablecoinInstruction::TriggerStabilizer
+        | StablecoinInstruction::RunCircuitBreaker { .. }
+        | StablecoinInstruction::UpdateOracle { .. }
+        | StablecoinInstruction::InstantFiatBridge { .. }
+        | StablecoinInstruction::DynamicHedge { .. }
+        | StablecoinInstruction::VelocityIncentive { .. }
+        | StablecoinInstruction::StreamPayment { .. }
+        | StablecoinInstruction::WithdrawStream { .. }
+        | StablecoinInstruction::CancelStream { .. }
+        | StablecoinInstruction::AddStake { .. }
+        | StablecoinInstruction::Unbond { .. }
+        | StablecoinInstruction::WithdrawStake { .. }
+        | StablecoinInstruction::Delegate { .. }
+        | StablecoinInstruction::Undelegate { .. }
+        | StablecoinInstruction::ClaimDelegatorRewards { .. }
+        | StablecoinInstruction::SetCommission { .. }
+        | StablecoinInstruction::ClaimVelocityReward { .. }
+        | StablecoinInstruction::FlashMint { .. }
+        | StablecoinInstruction::FlashBurn { .. }
+        | StablecoinInstruction::InstantRedeem { .. }
+        | StablecoinInstruction::WrapToYieldToken { .. }
+        | StablecoinInstruction::UnwrapYieldToken { .. }
+        | StablecoinInstruction::TransferYieldToken { .. }
+        | StablecoinInstruction::ClaimYield { .. }
+        | StablecoinInstruction::UseRWAAsCollateral { .. }
+        | StablecoinInstruction::RepayRWALoan { .. }
+        | StablecoinInstruction::UnpledgeRWA { .. }
+        | StablecoinInstruction::DelistRWA { .. }
+        | StablecoinInstruction::LiquidateRWA { .. }
+        | StablecoinInstruction::RevalueRWA { .. }
+        | StablecoinInstruction::DepositVaultCollateral { .. }
+        | StablecoinInstruction::WithdrawVaultCollateral { .. }
+        | StablecoinInstruction::DrawVaultDebt { .. }
+        | StablecoinInstruction::RepayVaultDebt { .. }
+        | StablecoinInstruction::Approve { .. }
+        | StablecoinInstruction::RevokeApproval { .. }
+        | StablecoinInstruction::Shield { .. }
+        | StablecoinInstruction::ApplyPendingBalance => SIMPLE_OP_GAS,
+    }
+}
+
+/// Estimated gas for a transaction carrying `si` against `state`, including
+/// [`TX_BASE_GAS`]. Exact for every instruction but bundles.
+pub fn transaction_gas(si: &StablecoinInstruction, state: &GlobalState) -> u64 {
+    TX_BASE_GAS.saturating_add(instruction_gas(si, state))
+}
@@ -0,0 +1,83 @@
+use crate::{execute_si, ExecutionContext};
+use lumina_types::error::ExecutionError;
+use lumina_types::instruction::{AssetType, StablecoinInstruction};
+use lumina_types::receipt::Event;
+
+fn allowance_event(owner: &[u8; 32], spender: &[u8; 32], asset: &AssetType, amount: u64) -> Event {
+    Event::AllowanceUpdated {
+        owner: *owner,
+        spender: *spender,
+        asset: asset.clone(),
+        amount,
+    }
+}
+
+pub fn approve(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    spender: &[u8; 32],
+    asset: &AssetType,
+    amount: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    if spender == sender {
+        return Err(ExecutionError::invalid_argument(
+            "Cannot approve yourself as spender",
+        ));
+    }
+    if let AssetType::Custom(ticker) = asset {
+        if ctx.state.asset_registry.get(ticker).is_none() {
+            return Err(ExecutionError::not_found("Asset"));
+        }
+    }
+    let account = ctx.state.accounts.entry(*sender).or_default();
+    account.set_allowance(spender, asset.ticker(), amount);
+    Ok(vec![allowance_event(sender, spender, asset, amount)])
+}
+
+pub fn revoke(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    spender: &[u8; 32],
+    asset: &AssetType,
+) -> Result<Vec<Event>, ExecutionError> {
+    let account = ctx.state.accounts.entry(*sender).or_default();
+    if account.allowance(spender, asset.ticker()) == 0 {
+        return Err(ExecutionError::not_found("Allowance"));
+    }
+    account.set_allowance(spender, asset.ticker(), 0);
+    Ok(vec![allowance_event(sender, spender, asset, 0)])
+}
+
+/// Spends the sender's allowance and performs an ordinary `Transfer` on the
+/// owner's behalf, so balance and freeze checks are exactly those of the owner
+/// sending directly.
+pub fn transfer_from(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    owner: &[u8; 32],
+    to: &[u8; 32],
+    asset: &AssetType,
+    amount: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    let account = ctx.state.accounts.entry(*owner).or_default();
+    let allowed = account.allowance(sender, asset.ticker());
+    if allowed < amount {
+        return Err(ExecutionError::Unauthorized {
+            reason: format!(
+                "Allowance exceeded: {} approved, {} requested",
+                allowed, amount
+            ),
+        });
+    }
+    let remaining = allowed - amount;
+    account.set_allowance(sender, asset.ticker(), remaining);
+
+    let transfer = StablecoinInstruction::Transfer {
+        to: *to,
+        amount,
+        asset: asset.clone(),
+    };
+    let mut events = execute_si(&transfer, owner, ctx)?;
+    events.push(allowance_event(owner, sender, asset, remaining));
+    Ok(events)
+}
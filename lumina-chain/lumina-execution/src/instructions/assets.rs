@@ -0,0 +1,223 @@
+use crate::{checked_add_u64, checked_sub_u64, ExecutionContext};
+use lumina_types::error::ExecutionError;
+use lumina_types::instruction::AssetType;
+use lumina_types::receipt::Event;
+use lumina_types::state::{AccountState, AssetInfo, GlobalState};
+
+/// Tickers of the native assets, which can never be registered as custom assets.
+const RESERVED_TICKERS: [&str; 3] = ["LUSD", "LJUN", "Lumina"];
+
+fn asset<'a>(state: &'a GlobalState, ticker: &str) -> Result<&'a AssetInfo, ExecutionError> {
+    state
+        .asset_registry
+        .get(ticker)
+        .ok_or_else(|| ExecutionError::not_found("Asset"))
+}
+
+fn asset_mut<'a>(
+    state: &'a mut GlobalState,
+    ticker: &str,
+) -> Result<&'a mut AssetInfo, ExecutionError> {
+    state
+        .asset_registry
+        .get_mut(ticker)
+        .ok_or_else(|| ExecutionError::not_found("Asset"))
+}
+
+/// The account's balance of `asset`.
+pub fn balance_mut<'a>(account: &'a mut AccountState, asset: &AssetType) -> &'a mut u64 {
+    match asset {
+        AssetType::LUSD => &mut account.lusd_balance,
+        AssetType::LJUN => &mut account.ljun_balance,
+        AssetType::Lumina => &mut account.lumina_balance,
+        AssetType::Custom(ticker) => account.custom_balances.entry(ticker.clone()).or_insert(0),
+    }
+}
+
+/// Rejects moves of an unregistered ticker or involving a frozen account.
+pub fn ensure_movable(
+    state: &GlobalState,
+    ticker: &str,
+    accounts: &[&[u8; 32]],
+) -> Result<(), ExecutionError> {
+    let info = asset(state, ticker)?;
+    if accounts.iter().any(|a| info.is_frozen(a)) {
+        return Err(ExecutionError::precondition(
+            "Account is frozen for this asset",
+        ));
+    }
+    Ok(())
+}
+
+/// Reduces tracked supply after a holder burns `amount`.
+pub fn record_burn(state: &mut GlobalState, ticker: &str, amount: u64) {
+    if let Some(info) = state.asset_registry.get_mut(ticker) {
+        info.total_supply = info.total_supply.saturating_sub(amount);
+    }
+}
+
+pub fn register(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    ticker: &str,
+    decimals: u8,
+) -> Result<Vec<Event>, ExecutionError> {
+    if ticker.is_empty() || ticker.len() > 16 {
+        return Err(ExecutionError::invalid_argument(
+            "Ticker must be 1-16 characters",
+        ));
+    }
+    if decimals > 18 {
+        return Err(ExecutionError::invalid_argument("Decimals must be 0-18"));
+    }
+    if RESERVED_TICKERS.contains(&ticker) || ctx.state.asset_registry.get(ticker).is_some() {
+        return Err(ExecutionError::precondition("Ticker is already registered"));
+    }
+
+    ctx.state.asset_registry.assets.insert(
+        ticker.to_string(),
+        AssetInfo {
+            issuer: *sender,
+            decimals,
+            total_supply: 0,
+            max_supply: None,
+            freeze_authority: None,
+            frozen: Default::default(),
+        },
+    );
+    // Price feed slot for the asset, filled in by the oracle
+    ctx.state
+        .oracle_prices
+        .entry(AssetType::Custom(ticker.to_string()).price_feed())
+        .or_insert(0);
+    Ok(vec![Event::AssetRegistered {
+        ticker: ticker.to_string(),
+        decimals,
+    }])
+}
+
+pub fn configure(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    ticker: &str,
+    max_supply: Option<u64>,
+    freeze_authority: Option<[u8; 32]>,
+) -> Result<Vec<Event>, ExecutionError> {
+    let info = asset_mut(ctx.state, ticker)?;
+    if info.issuer != *sender {
+        return Err(ExecutionError::unauthorized(
+            "Only the issuer can configure an asset",
+        ));
+    }
+    if max_supply.is_some_and(|cap| cap < info.total_supply) {
+        return Err(ExecutionError::invalid_argument(
+            "Max supply is below the current supply",
+        ));
+    }
+    info.max_supply = max_supply;
+    info.freeze_authority = freeze_authority;
+    Ok(vec![Event::AssetConfigured {
+        ticker: ticker.to_string(),
+        max_supply,
+        freeze_authority,
+    }])
+}
+
+pub fn mint(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    ticker: &str,
+    to: &[u8; 32],
+    amount: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    if amount == 0 {
+        return Err(ExecutionError::ZeroAmount);
+    }
+    ensure_movable(ctx.state, ticker, &[to])?;
+    let info = asset_mut(ctx.state, ticker)?;
+    if info.issuer != *sender {
+        return Err(ExecutionError::unauthorized("Only the issuer can mint"));
+    }
+    let supply = checked_add_u64(info.total_supply, amount, "Asset supply")?;
+    if info.max_supply.is_some_and(|cap| supply > cap) {
+        return Err(ExecutionError::precondition("Mint exceeds max supply"));
+    }
+    info.total_supply = supply;
+
+    let bal = ctx
+        .state
+        .accounts
+        .entry(*to)
+        .or_default()
+        .custom_balances
+        .entry(ticker.to_string())
+        .or_insert(0);
+    *bal = checked_add_u64(*bal, amount, "Custom asset balance")?;
+
+    Ok(vec![Event::Minted {
+        to: *to,
+        asset: AssetType::Custom(ticker.to_string()),
+        amount,
+    }])
+}
+
+pub fn burn(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    ticker: &str,
+    amount: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    if amount == 0 {
+        return Err(ExecutionError::ZeroAmount);
+    }
+    if asset(ctx.state, ticker)?.issuer != *sender {
+        return Err(ExecutionError::unauthorized("Only the issuer can burn"));
+    }
+
+    let bal = ctx
+        .state
+        .accounts
+        .entry(*sender)
+        .or_default()
+        .custom_balances
+        .entry(ticker.to_string())
+        .or_insert(0);
+    if *bal < amount {
+        return Err(ExecutionError::InsufficientBalance {
+            asset: AssetType::Custom(ticker.to_string()),
+        });
+    }
+    *bal = checked_sub_u64(*bal, amount, "Custom asset balance")?;
+    record_burn(ctx.state, ticker, amount);
+
+    Ok(vec![Event::Burned {
+        from: *sender,
+        asset: AssetType::Custom(ticker.to_string()),
+        amount,
+    }])
+}
+
+pub fn set_frozen(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    ticker: &str,
+    account: &[u8; 32],
+    frozen: bool,
+) -> Result<Vec<Event>, ExecutionError> {
+    let info = asset_mut(ctx.state, ticker)?;
+    if info.freeze_authority != Some(*sender) {
+        return Err(ExecutionError::unauthorized(
+            "Only the freeze authority can freeze accounts",
+        ));
+    }
+    if frozen {
+        info.frozen.insert(*account);
+    } else {
+        info.frozen.remove(account);
+    }
+    Ok(vec![Event::AssetFreezeUpdated {
+        ticker: ticker.to_string(),
+        account: *account,
+        frozen,
+    }])
+}
@@ -0,0 +1,278 @@
+use super::assets::{balance_mut, ensure_movable};
+use crate::{checked_add_u64, checked_sub_u64, transaction_key, ExecutionContext};
+use lumina_types::error::ExecutionError;
+use lumina_types::instruction::{AssetType, LimitOrder, OrderSide, SignedOrder, PRICE_SCALE};
+use lumina_types::receipt::{Event, ProofKind};
+use lumina_types::state::OrderFill;
+use std::cmp::Reverse;
+use std::collections::HashSet;
+
+/// Upper bound on orders in one batch.
+pub const MAX_BATCH_ORDERS: usize = 1000;
+
+fn debit(
+    ctx: &mut ExecutionContext,
+    account: &[u8; 32],
+    asset: &AssetType,
+    amount: u64,
+) -> Result<(), ExecutionError> {
+    let balance = balance_mut(ctx.state.accounts.entry(*account).or_default(), asset);
+    if *balance < amount {
+        return Err(ExecutionError::InsufficientBalance {
+            asset: asset.clone(),
+        });
+    }
+    *balance = checked_sub_u64(*balance, amount, "Order settlement")?;
+    Ok(())
+}
+
+fn credit(
+    ctx: &mut ExecutionContext,
+    account: &[u8; 32],
+    asset: &AssetType,
+    amount: u64,
+) -> Result<(), ExecutionError> {
+    let balance = balance_mut(ctx.state.accounts.entry(*account).or_default(), asset);
+    *balance = checked_add_u64(*balance, amount, "Order settlement")?;
+    Ok(())
+}
+
+/// Validated order with the base amount still open.
+struct OpenOrder<'a> {
+    id: [u8; 32],
+    order: &'a LimitOrder,
+    remaining: u64,
+}
+
+fn open_order<'a>(
+    ctx: &ExecutionContext,
+    signed: &'a SignedOrder,
+) -> Result<OpenOrder<'a>, ExecutionError> {
+    let order = &signed.order;
+    if order.quantity == 0 || order.limit_price == 0 {
+        return Err(ExecutionError::invalid_argument(
+            "Order quantity and price must be non-zero",
+        ));
+    }
+    if ctx.height >= order.expires_at {
+        return Err(ExecutionError::precondition("Order has expired"));
+    }
+
+    // Orders are signed with the maker's transaction key; multisig makers
+    // have no single key and cannot place orders
+    let maker = ctx
+        .state
+        .accounts
+        .get(&order.maker)
+        .cloned()
+        .unwrap_or_default();
+    if maker.multisig.is_some() {
+        return Err(ExecutionError::unauthorized(
+            "Multisig accounts cannot sign orders",
+        ));
+    }
+    let id = order.id();
+    if transaction_key(&maker, &order.maker)
+        .verify(&id, &signed.signature)
+        .is_err()
+    {
+        return Err(ExecutionError::InvalidProof {
+            kind: ProofKind::OrderSignature,
+        });
+    }
+
+    let filled = ctx.state.order_fills.get(&id).map_or(0, |f| f.filled);
+    let remaining = order.quantity.saturating_sub(filled);
+    if remaining == 0 {
+        return Err(ExecutionError::replay("Filled order"));
+    }
+    Ok(OpenOrder {
+        id,
+        order,
+        remaining,
+    })
+}
+
+/// Price maximizing matched volume; ties go to the smallest buy/sell
+/// imbalance, then to the lowest price. Returns `(price, volume)`.
+fn clearing_price(buys: &[OpenOrder], sells: &[OpenOrder]) -> (u64, u64) {
+    let mut candidates: Vec<u64> = buys
+        .iter()
+        .chain(sells)
+        .map(|o| o.order.limit_price)
+        .collect();
+    candidates.sort_unstable();
+    candidates.dedup();
+
+    let mut best = (0u64, 0u64, u64::MAX);
+    for price in candidates {
+        let demand: u64 = buys
+            .iter()
+            .filter(|o| o.order.limit_price >= price)
+            .fold(0, |acc, o| acc.saturating_add(o.remaining));
+        let supply: u64 = sells
+            .iter()
+            .filter(|o| o.order.limit_price <= price)
+            .fold(0, |acc, o| acc.saturating_add(o.remaining));
+        let volume = demand.min(supply);
+        let imbalance = demand.abs_diff(supply);
+        if volume > best.1 || (volume == best.1 && imbalance < best.2) {
+            best = (price, volume, imbalance);
+        }
+    }
+    (best.0, best.1)
+}
+
+/// Fills `orders`, already in priority order, up to `volume` in total.
+fn allocate(orders: &[OpenOrder], volume: u64) -> Vec<u64> {
+    let mut left = volume;
+    orders
+        .iter()
+        .map(|o| {
+            let fill = o.remaining.min(left);
+            left -= fill;
+            fill
+        })
+        .collect()
+}
+
+/// Runs a single-price batch auction. Every buy at or above the clearing
+/// price and every sell at or below it is eligible; the over-subscribed side
+/// fills by price priority, then by position in the batch. Buyers pay the
+/// quote value rounded up and sellers receive it rounded down; the rounding
+/// remainder goes to the submitter.
+pub fn settle(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    orders: &[SignedOrder],
+) -> Result<Vec<Event>, ExecutionError> {
+    if orders.is_empty() {
+        return Err(ExecutionError::invalid_argument("Empty order batch"));
+    }
+    if orders.len() > MAX_BATCH_ORDERS {
+        return Err(ExecutionError::invalid_argument(&format!(
+            "Batch too large: max {} orders",
+            MAX_BATCH_ORDERS
+        )));
+    }
+    let (base, quote) = (&orders[0].order.base, &orders[0].order.quote);
+    if base == quote {
+        return Err(ExecutionError::invalid_argument(
+            "Base and quote assets must differ",
+        ));
+    }
+    if orders
+        .iter()
+        .any(|o| o.order.base != *base || o.order.quote != *quote)
+    {
+        return Err(ExecutionError::invalid_argument(
+            "All orders in a batch must share one asset pair",
+        ));
+    }
+
+    let height = ctx.height;
+    ctx.state.order_fills.retain(|_, f| f.expires_at > height);
+
+    let mut seen = HashSet::with_capacity(orders.len());
+    let mut buys = Vec::new();
+    let mut sells = Vec::new();
+    for signed in orders {
+        let open = open_order(ctx, signed)?;
+        if !seen.insert(open.id) {
+            return Err(ExecutionError::invalid_argument("Duplicate order in batch"));
+        }
+        for asset in [base, quote] {
+            if let AssetType::Custom(ticker) = asset {
+                ensure_movable(ctx.state, ticker, &[&open.order.maker])?;
+            }
+        }
+        match open.order.side {
+            OrderSide::Buy => buys.push(open),
+            OrderSide::Sell => sells.push(open),
+        }
+    }
+
+    // Replays are caught per order by `order_fills`, so a partially filled
+    // order can be matched again in a later batch
+    let mut hasher = blake3::Hasher::new();
+    for signed in orders {
+        hasher.update(&signed.order.id());
+    }
+    let batch_id = *hasher.finalize().as_bytes();
+
+    let (price, volume) = clearing_price(&buys, &sells);
+    if volume == 0 {
+        return Err(ExecutionError::precondition(
+            "Orders in the batch do not cross",
+        ));
+    }
+
+    // Stable sorts keep batch position as the secondary priority
+    buys.retain(|o| o.order.limit_price >= price);
+    sells.retain(|o| o.order.limit_price <= price);
+    buys.sort_by_key(|o| Reverse(o.order.limit_price));
+    sells.sort_by_key(|o| o.order.limit_price);
+    let buy_fills = allocate(&buys, volume);
+    let sell_fills = allocate(&sells, volume);
+
+    let mut events = Vec::new();
+    let mut paid = 0u64;
+    let mut received = 0u64;
+    let fills = buys
+        .iter()
+        .zip(buy_fills)
+        .chain(sells.iter().zip(sell_fills));
+    for (open, base_amount) in fills {
+        if base_amount == 0 {
+            continue;
+        }
+        let maker = &open.order.maker;
+        let quote_amount = match open.order.side {
+            OrderSide::Buy => {
+                // Round up so buyers always cover what sellers receive
+                let scaled = (base_amount as u128) * (price as u128);
+                let value = scaled.div_ceil(PRICE_SCALE as u128);
+                let value =
+                    u64::try_from(value).map_err(|_| ExecutionError::overflow("Order value"))?;
+                debit(ctx, maker, quote, value)?;
+                credit(ctx, maker, base, base_amount)?;
+                paid = checked_add_u64(paid, value, "Batch quote")?;
+                value
+            }
+            OrderSide::Sell => {
+                let value = LimitOrder::quote_value(base_amount, price)
+                    .ok_or_else(|| ExecutionError::overflow("Order value"))?;
+                debit(ctx, maker, base, base_amount)?;
+                credit(ctx, maker, quote, value)?;
+                received = checked_add_u64(received, value, "Batch quote")?;
+                value
+            }
+        };
+
+        let fill = ctx.state.order_fills.entry(open.id).or_insert(OrderFill {
+            filled: 0,
+            expires_at: open.order.expires_at,
+        });
+        fill.filled = fill.filled.saturating_add(base_amount);
+        events.push(Event::OrderFilled {
+            order_id: open.id,
+            maker: *maker,
+            side: open.order.side,
+            base_amount,
+            quote_amount,
+            price,
+        });
+    }
+
+    let dust = paid.saturating_sub(received);
+    if dust > 0 {
+        credit(ctx, sender, quote, dust)?;
+    }
+    events.push(Event::BatchMatched {
+        batch_id,
+        order_count: orders.len() as u32,
+        clearing_price: price,
+        volume,
+    });
+    Ok(events)
+}
@@ -0,0 +1,258 @@
+use crate::{checked_add_u64, checked_sub_u64, ExecutionContext};
+use lumina_crypto::audit::AUDIT_NOTE_BYTES;
+use lumina_crypto::pq::KYBER768_PUBLIC_KEY_BYTES;
+use lumina_crypto::zk::{
+    add_commitments, commit_value, sub_commitments, verify_confidential_proof,
+};
+use lumina_types::error::ExecutionError;
+use lumina_types::instruction::AssetType;
+use lumina_types::receipt::{Event, ProofKind};
+use lumina_types::state::GlobalState;
+
+/// Commitment to the account's confidential balance; an account that never
+/// shielded holds a commitment to zero.
+fn balance_commitment(state: &GlobalState, account: &[u8; 32]) -> [u8; 32] {
+    state
+        .accounts
+        .get(account)
+        .and_then(|a| a.commitment)
+        .unwrap_or_else(|| commit_value(0, [0u8; 32]))
+}
+
+/// Commitment to the account's pending incoming transfers.
+fn pending_commitment(state: &GlobalState, account: &[u8; 32]) -> [u8; 32] {
+    state
+        .accounts
+        .get(account)
+        .and_then(|a| a.pending_commitment)
+        .unwrap_or_else(|| commit_value(0, [0u8; 32]))
+}
+
+fn malformed() -> ExecutionError {
+    ExecutionError::invalid_argument("Malformed Pedersen commitment")
+}
+
+/// Commitment left in `sender`'s balance after spending `amount_commitment`,
+/// once `proof` shows it is non-negative.
+fn spend(
+    state: &GlobalState,
+    sender: &[u8; 32],
+    amount_commitment: &[u8; 32],
+    proof: &[u8],
+) -> Result<[u8; 32], ExecutionError> {
+    let remaining = sub_commitments(&balance_commitment(state, sender), amount_commitment)
+        .ok_or_else(malformed)?;
+    if !verify_confidential_proof(amount_commitment, &remaining, proof) {
+        return Err(ExecutionError::InvalidProof {
+            kind: ProofKind::ConfidentialRange,
+        });
+    }
+    Ok(remaining)
+}
+
+pub fn shield(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    amount: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    if amount == 0 {
+        return Err(ExecutionError::ZeroAmount);
+    }
+    let commitment = add_commitments(
+        &balance_commitment(ctx.state, sender),
+        &commit_value(amount, [0u8; 32]),
+    )
+    .ok_or_else(malformed)?;
+
+    let account = ctx.state.accounts.entry(*sender).or_default();
+    if account.lusd_balance < amount {
+        return Err(ExecutionError::InsufficientBalance {
+            asset: AssetType::LUSD,
+        });
+    }
+    account.lusd_balance = checked_sub_u64(account.lusd_balance, amount, "Sender LUSD")?;
+    account.commitment = Some(commitment);
+    ctx.state.shielded_lusd_supply =
+        checked_add_u64(ctx.state.shielded_lusd_supply, amount, "Shielded LUSD")?;
+
+    Ok(vec![
+        Event::Shielded {
+            account: *sender,
+            amount,
+        },
+        Event::CommitmentUpdated {
+            account: *sender,
+            commitment,
+        },
+    ])
+}
+
+pub fn unshield(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    amount: u64,
+    proof: &[u8],
+) -> Result<Vec<Event>, ExecutionError> {
+    if amount == 0 {
+        return Err(ExecutionError::ZeroAmount);
+    }
+    let remaining = spend(ctx.state, sender, &commit_value(amount, [0u8; 32]), proof)?;
+    ctx.state.shielded_lusd_supply =
+        checked_sub_u64(ctx.state.shielded_lusd_supply, amount, "Shielded LUSD")?;
+
+    let account = ctx.state.accounts.entry(*sender).or_default();
+    account.lusd_balance = checked_add_u64(account.lusd_balance, amount, "Receiver LUSD")?;
+    account.commitment = Some(remaining);
+
+    Ok(vec![
+        Event::Unshielded {
+            account: *sender,
+            amount,
+        },
+        Event::CommitmentUpdated {
+            account: *sender,
+            commitment: remaining,
+        },
+    ])
+}
+
+pub fn transfer(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    to: &[u8; 32],
+    amount_commitment: &[u8; 32],
+    proof: &[u8],
+    recipient_note: &[u8],
+    audit_note: Option<&[u8]>,
+) -> Result<Vec<Event>, ExecutionError> {
+    if to == sender {
+        return Err(ExecutionError::invalid_argument(
+            "Cannot transfer to the sending account",
+        ));
+    }
+    // Without the opening the recipient could never spend what it receives
+    if ctx
+        .state
+        .accounts
+        .get(to)
+        .is_none_or(|a| a.receive_key.is_none())
+    {
+        return Err(ExecutionError::precondition(
+            "Recipient has no receive key for confidential transfers",
+        ));
+    }
+    if recipient_note.len() != AUDIT_NOTE_BYTES {
+        return Err(ExecutionError::invalid_argument("Malformed recipient note"));
+    }
+    // The chain cannot decrypt the note; it only enforces that an audited
+    // sender attaches one of the right shape
+    let audited = ctx
+        .state
+        .accounts
+        .get(sender)
+        .is_some_and(|a| a.auditor_key.is_some());
+    match audit_note {
+        Some(note) if note.len() != AUDIT_NOTE_BYTES => {
+            return Err(ExecutionError::invalid_argument("Malformed audit note"));
+        }
+        None if audited => {
+            return Err(ExecutionError::precondition(
+                "Sender has an auditor key; an audit note is required",
+            ));
+        }
+        _ => {}
+    }
+    let remaining = spend(ctx.state, sender, amount_commitment, proof)?;
+    let received = add_commitments(&pending_commitment(ctx.state, to), amount_commitment)
+        .ok_or_else(malformed)?;
+
+    ctx.state.accounts.entry(*sender).or_default().commitment = Some(remaining);
+    ctx.state
+        .accounts
+        .entry(*to)
+        .or_default()
+        .pending_commitment = Some(received);
+
+    Ok(vec![
+        Event::ConfidentialTransferred {
+            from: *sender,
+            to: *to,
+            amount_commitment: *amount_commitment,
+            recipient_note: recipient_note.to_vec(),
+            audit_note: audit_note.map(<[u8]>::to_vec),
+        },
+        Event::CommitmentUpdated {
+            account: *sender,
+            commitment: remaining,
+        },
+        Event::PendingCommitmentUpdated {
+            account: *to,
+            commitment: received,
+        },
+    ])
+}
+
+/// Folds pending incoming transfers into the sender's balance. Only the owner
+/// can do this, once it has opened every pending amount from its notes.
+pub fn apply_pending(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+) -> Result<Vec<Event>, ExecutionError> {
+    let Some(pending) = ctx
+        .state
+        .accounts
+        .get(sender)
+        .and_then(|a| a.pending_commitment)
+    else {
+        return Err(ExecutionError::precondition(
+            "No pending confidential transfers",
+        ));
+    };
+    let commitment =
+        add_commitments(&balance_commitment(ctx.state, sender), &pending).ok_or_else(malformed)?;
+
+    let account = ctx.state.accounts.entry(*sender).or_default();
+    account.commitment = Some(commitment);
+    account.pending_commitment = None;
+
+    Ok(vec![Event::CommitmentUpdated {
+        account: *sender,
+        commitment,
+    }])
+}
+
+fn check_kyber_key(key: Option<&[u8]>, role: &str) -> Result<(), ExecutionError> {
+    if key.is_some_and(|k| k.len() != KYBER768_PUBLIC_KEY_BYTES) {
+        return Err(ExecutionError::invalid_argument(&format!(
+            "{} key must be a Kyber768 public key",
+            role
+        )));
+    }
+    Ok(())
+}
+
+pub fn set_auditor_key(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    auditor_pubkey: Option<&[u8]>,
+) -> Result<Vec<Event>, ExecutionError> {
+    check_kyber_key(auditor_pubkey, "Auditor")?;
+    ctx.state.accounts.entry(*sender).or_default().auditor_key = auditor_pubkey.map(<[u8]>::to_vec);
+    Ok(vec![Event::AuditorKeyUpdated {
+        account: *sender,
+        registered: auditor_pubkey.is_some(),
+    }])
+}
+
+pub fn set_receive_key(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    receive_pubkey: Option<&[u8]>,
+) -> Result<Vec<Event>, ExecutionError> {
+    check_kyber_key(receive_pubkey, "Receive")?;
+    ctx.state.accounts.entry(*sender).or_default().receive_key = receive_pubkey.map(<[u8]>::to_vec);
+    Ok(vec![Event::ReceiveKeyUpdated {
+        account: *sender,
+        registered: receive_pubkey.is_some(),
+    }])
+}
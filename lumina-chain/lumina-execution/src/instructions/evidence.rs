@@ -0,0 +1,93 @@
+use super::staking::slash;
+use crate::{checked_add_u64, ExecutionContext};
+use lumina_crypto::signatures::verify_signature;
+use lumina_types::block::{DoubleSignEvidence, SignedVote, Vote};
+use lumina_types::error::ExecutionError;
+use lumina_types::receipt::{Event, ProofKind};
+use lumina_types::state::ValidatorStatus;
+
+fn verify_vote(validator: &[u8; 32], vote: &SignedVote) -> bool {
+    let message = Vote::signing_bytes(vote.height, &vote.block_hash);
+    verify_signature(validator, &message, &vote.signature).is_ok()
+}
+
+/// Verifies double-sign evidence, slashes the offender's stake into the
+/// insurance fund's Lumina balance and jails it.
+/// Each (validator, height) infraction is punished once, and only within
+/// `evidence_max_age_blocks` of the current height; older replay records are
+/// pruned since such evidence is rejected as expired anyway.
+pub fn submit(
+    ctx: &mut ExecutionContext,
+    evidence: &DoubleSignEvidence,
+) -> Result<Vec<Event>, ExecutionError> {
+    let DoubleSignEvidence {
+        validator: pubkey,
+        first,
+        second,
+    } = evidence;
+    if first.height != second.height || first.block_hash == second.block_hash {
+        return Err(ExecutionError::invalid_argument(
+            "Evidence votes must be for different blocks at the same height",
+        ));
+    }
+    let infraction_height = first.height;
+    if infraction_height > ctx.height {
+        return Err(ExecutionError::invalid_argument(
+            "Evidence is from a future height",
+        ));
+    }
+    let params = &ctx.state.protocol_params;
+    let oldest_accepted = ctx.height.saturating_sub(params.evidence_max_age_blocks);
+    if infraction_height < oldest_accepted {
+        return Err(ExecutionError::precondition("Evidence has expired"));
+    }
+    let (fraction_bps, jail_duration) = (params.slash_fraction_bps, params.jail_duration_blocks);
+
+    if ctx
+        .state
+        .slashed_infractions
+        .contains(&(infraction_height, *pubkey))
+    {
+        return Err(ExecutionError::replay("Double-sign evidence"));
+    }
+    if !verify_vote(pubkey, first) || !verify_vote(pubkey, second) {
+        return Err(ExecutionError::InvalidProof {
+            kind: ProofKind::DoubleSignEvidence,
+        });
+    }
+
+    let height = ctx.height;
+    let validator = ctx
+        .state
+        .validators
+        .iter_mut()
+        .find(|v| v.pubkey == *pubkey)
+        .ok_or_else(|| ExecutionError::not_found("Validator"))?;
+    let amount = slash(validator, fraction_bps, height);
+
+    // Jailing takes effect immediately rather than at the epoch boundary
+    let mut jailed_until = height.saturating_add(jail_duration);
+    if let ValidatorStatus::Jailed { until_height } = validator.status {
+        jailed_until = jailed_until.max(until_height);
+    }
+    validator.status = ValidatorStatus::Jailed {
+        until_height: jailed_until,
+    };
+    validator.power = 0;
+
+    let slashed = &mut ctx.state.slashed_infractions;
+    **slashed = slashed.split_off(&(oldest_accepted, [0u8; 32]));
+    slashed.insert((infraction_height, *pubkey));
+    ctx.state.insurance_fund_lumina =
+        checked_add_u64(ctx.state.insurance_fund_lumina, amount, "Insurance fund")?;
+
+    Ok(vec![
+        Event::ValidatorSlashed {
+            pubkey: *pubkey,
+            infraction_height,
+            amount,
+            jailed_until,
+        },
+        Event::InsuranceFundCredited { amount },
+    ])
+}
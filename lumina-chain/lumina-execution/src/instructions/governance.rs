@@ -0,0 +1,352 @@
+use super::roles::validate_multisig;
+use crate::ExecutionContext;
+use lumina_types::error::ExecutionError;
+use lumina_types::receipt::Event;
+use lumina_types::state::{
+    GlobalState, GovernanceParams, Proposal, ProposalAction, ProposalStatus, ProposalVote,
+    ProtocolParams, RwaClassParams,
+};
+use std::collections::BTreeMap;
+
+fn validator_power(state: &GlobalState, pubkey: &[u8; 32]) -> Option<u64> {
+    state
+        .validators
+        .iter()
+        .find(|v| v.pubkey == *pubkey)
+        .map(|v| v.power)
+}
+
+fn total_power(state: &GlobalState) -> u64 {
+    state
+        .validators
+        .iter()
+        .fold(0u64, |acc, v| acc.saturating_add(v.power))
+}
+
+/// `part / whole >= threshold_bps / 10_000`, computed without overflow.
+fn meets_bps(part: u64, whole: u64, threshold_bps: u64) -> bool {
+    (part as u128) * 10_000 >= (whole as u128) * (threshold_bps as u128)
+}
+
+fn validate_params(params: &GovernanceParams) -> Result<(), ExecutionError> {
+    if params.voting_period_blocks == 0 {
+        return Err(ExecutionError::invalid_argument(
+            "Voting period must be non-zero",
+        ));
+    }
+    if params.quorum_bps > 10_000 {
+        return Err(ExecutionError::invalid_argument(
+            "Quorum cannot exceed 100% (10000 bps)",
+        ));
+    }
+    if params.approval_threshold_bps == 0 || params.approval_threshold_bps > 10_000 {
+        return Err(ExecutionError::invalid_argument(
+            "Approval threshold must be 1-10000 bps",
+        ));
+    }
+    Ok(())
+}
+
+fn validate_protocol_params(params: &ProtocolParams) -> Result<(), ExecutionError> {
+    if params.mint_fee_bps > 10_000 {
+        return Err(ExecutionError::invalid_argument(
+            "Mint fee cannot exceed 100% (10000 bps)",
+        ));
+    }
+    if params.circuit_breaker_threshold_bps > params.redemption_queue_threshold_bps {
+        return Err(ExecutionError::invalid_argument(
+            "Circuit breaker threshold cannot exceed redemption queue threshold",
+        ));
+    }
+    if params.flash_mint_collateral_bps < 10_000 {
+        return Err(ExecutionError::invalid_argument(
+            "Flash mint collateral must be at least 100% (10000 bps)",
+        ));
+    }
+    if params.reserve_rotation_interval == 0 {
+        return Err(ExecutionError::invalid_argument(
+            "Reserve rotation interval must be non-zero",
+        ));
+    }
+    if params.unbonding_period_blocks == 0 {
+        return Err(ExecutionError::invalid_argument(
+            "Unbonding period must be non-zero",
+        ));
+    }
+    if params.slash_fraction_bps > 10_000 {
+        return Err(ExecutionError::invalid_argument(
+            "Slash fraction cannot exceed 100% (10000 bps)",
+        ));
+    }
+    // Evidence must land while the offender's stake is still bonded or unbonding
+    if params.evidence_max_age_blocks == 0
+        || params.evidence_max_age_blocks > params.unbonding_period_blocks
+    {
+        return Err(ExecutionError::invalid_argument(
+            "Evidence max age must be 1..=unbonding period",
+        ));
+    }
+    if params.vault_collateral_ratio_bps < 10_000 {
+        return Err(ExecutionError::invalid_argument(
+            "Vault collateral ratio must be at least 100% (10000 bps)",
+        ));
+    }
+    if params.vault_liquidation_penalty_bps > 10_000 {
+        return Err(ExecutionError::invalid_argument(
+            "Vault liquidation penalty cannot exceed 100% (10000 bps)",
+        ));
+    }
+    if params.vault_auction_start_bps == 0 || params.vault_auction_duration_blocks == 0 {
+        return Err(ExecutionError::invalid_argument(
+            "Vault auction start price and duration must be non-zero",
+        ));
+    }
+    let yield_total = params
+        .yield_junior_bps
+        .saturating_add(params.yield_pool_bps)
+        .saturating_add(params.yield_insurance_bps);
+    if yield_total != 10_000 {
+        return Err(ExecutionError::invalid_argument(
+            "Yield split must sum to 10000 bps",
+        ));
+    }
+    Ok(())
+}
+
+fn validate_rwa_class_params(params: &RwaClassParams) -> Result<(), ExecutionError> {
+    if params.haircut_bps > 10_000 || params.max_ltv_bps > 10_000 {
+        return Err(ExecutionError::invalid_argument(
+            "RWA haircut and LTV cannot exceed 100% (10000 bps)",
+        ));
+    }
+    if params.max_valuation_age == 0 {
+        return Err(ExecutionError::invalid_argument(
+            "RWA valuation age limit must be non-zero",
+        ));
+    }
+    Ok(())
+}
+
+fn validate_action(action: &ProposalAction) -> Result<(), ExecutionError> {
+    match action {
+        ProposalAction::UpdateGovernanceParams(params) => validate_params(params),
+        ProposalAction::UpdateProtocolParams(params) => validate_protocol_params(params),
+        ProposalAction::SetAdminMultisig { members, threshold } => {
+            validate_multisig(members, *threshold)
+        }
+        ProposalAction::SetRwaClassParams { params, .. } => validate_rwa_class_params(params),
+        ProposalAction::SetCircuitBreaker { .. }
+        | ProposalAction::SetCreditOracle { .. }
+        | ProposalAction::SetRole { .. } => Ok(()),
+    }
+}
+
+fn apply_action(state: &mut GlobalState, action: &ProposalAction) -> Result<(), ExecutionError> {
+    validate_action(action)?;
+    match action {
+        ProposalAction::UpdateGovernanceParams(params) => {
+            state.governance_params = params.clone();
+        }
+        ProposalAction::UpdateProtocolParams(params) => {
+            state.protocol_params = params.clone();
+        }
+        ProposalAction::SetRole {
+            role,
+            account,
+            granted,
+        } => {
+            state.set_role(*role, *account, *granted);
+        }
+        ProposalAction::SetAdminMultisig { members, threshold } => {
+            state.admin_multisig.members = members.clone();
+            state.admin_multisig.threshold = *threshold;
+            // Invalidate signatures collected for the previous member set
+            state.admin_multisig.nonce = state.admin_multisig.nonce.saturating_add(1);
+        }
+        ProposalAction::SetRwaClassParams { class, params } => {
+            state.rwa_class_params.insert(*class, params.clone());
+        }
+        ProposalAction::SetCircuitBreaker { active } => {
+            state.circuit_breaker_active = *active;
+        }
+        ProposalAction::SetCreditOracle { oracle, trusted } => {
+            let listed = state.trusted_credit_oracles.contains(oracle);
+            if *trusted && !listed {
+                state.trusted_credit_oracles.push(*oracle);
+            } else if !*trusted {
+                state.trusted_credit_oracles.retain(|o| o != oracle);
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn submit(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    action: &ProposalAction,
+) -> Result<Vec<Event>, ExecutionError> {
+    if validator_power(ctx.state, sender).unwrap_or(0) == 0 {
+        return Err(ExecutionError::unauthorized(
+            "Only validators can submit proposals",
+        ));
+    }
+    validate_action(action)?;
+
+    let proposal_id = ctx.state.next_proposal_id;
+    ctx.state.next_proposal_id = proposal_id
+        .checked_add(1)
+        .ok_or_else(|| ExecutionError::overflow("Proposal id"))?;
+    let voting_end_height = ctx
+        .height
+        .saturating_add(ctx.state.governance_params.voting_period_blocks);
+
+    ctx.state
+        .active_proposals_by_end
+        .entry(voting_end_height)
+        .or_default()
+        .insert(proposal_id);
+    ctx.state.proposals.insert(
+        proposal_id,
+        Proposal {
+            id: proposal_id,
+            proposer: *sender,
+            action: action.clone(),
+            submitted_height: ctx.height,
+            voting_end_height,
+            votes: BTreeMap::new(),
+            status: ProposalStatus::Active,
+        },
+    );
+
+    Ok(vec![Event::ProposalSubmitted {
+        proposal_id,
+        proposer: *sender,
+        voting_end_height,
+    }])
+}
+
+pub fn vote(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    proposal_id: u64,
+    approve: bool,
+) -> Result<Vec<Event>, ExecutionError> {
+    let power = validator_power(ctx.state, sender)
+        .ok_or_else(|| ExecutionError::unauthorized("Only validators can vote"))?;
+
+    let proposal = ctx
+        .state
+        .proposals
+        .get_mut(&proposal_id)
+        .ok_or_else(|| ExecutionError::not_found("Proposal"))?;
+    if proposal.status != ProposalStatus::Active || ctx.height >= proposal.voting_end_height {
+        return Err(ExecutionError::precondition(
+            "Proposal is not open for voting",
+        ));
+    }
+
+    // Re-voting replaces the earlier ballot and refreshes the power snapshot
+    proposal
+        .votes
+        .insert(*sender, ProposalVote { approve, power });
+
+    Ok(vec![Event::VoteCast {
+        voter: *sender,
+        proposal_id,
+        approve,
+    }])
+}
+
+/// Executes an approved proposal once voting has closed, or early once approving
+/// power alone clears the threshold against the whole validator set.
+pub fn execute(ctx: &mut ExecutionContext, proposal_id: u64) -> Result<Vec<Event>, ExecutionError> {
+    let proposal = ctx
+        .state
+        .proposals
+        .get(&proposal_id)
+        .ok_or_else(|| ExecutionError::not_found("Proposal"))?;
+    if proposal.status != ProposalStatus::Active {
+        return Err(ExecutionError::precondition(
+            "Proposal has already been finalized",
+        ));
+    }
+
+    if ctx.height < proposal.voting_end_height {
+        let (yes, _) = proposal.tally();
+        let total = total_power(ctx.state);
+        let threshold = ctx.state.governance_params.approval_threshold_bps;
+        if total == 0 || !meets_bps(yes, total, threshold) {
+            return Err(ExecutionError::precondition(
+                "Proposal voting is still open",
+            ));
+        }
+    }
+
+    Ok(finalize(ctx.state, proposal_id))
+}
+
+/// Tallies every proposal whose voting period has ended at `ctx.height` and
+/// applies the approved ones. Called from `end_block`; only the expiring
+/// entries of the end-height index are visited.
+pub fn finalize_expired(ctx: &mut ExecutionContext) -> Vec<Event> {
+    let expired: Vec<u64> = ctx
+        .state
+        .active_proposals_by_end
+        .range(..=ctx.height)
+        .flat_map(|(_, ids)| ids.iter().copied())
+        .collect();
+
+    let mut events = Vec::new();
+    for proposal_id in expired {
+        events.extend(finalize(ctx.state, proposal_id));
+    }
+    events
+}
+
+fn finalize(state: &mut GlobalState, proposal_id: u64) -> Vec<Event> {
+    let Some(proposal) = state.proposals.get(&proposal_id) else {
+        return Vec::new();
+    };
+    let end_height = proposal.voting_end_height;
+    if let Some(ids) = state.active_proposals_by_end.get_mut(&end_height) {
+        ids.remove(&proposal_id);
+        if ids.is_empty() {
+            state.active_proposals_by_end.remove(&end_height);
+        }
+    }
+    let Some(proposal) = state.proposals.get(&proposal_id) else {
+        return Vec::new();
+    };
+    let (yes_power, no_power) = proposal.tally();
+    let voted = yes_power.saturating_add(no_power);
+    let params = &state.governance_params;
+    let approved = voted > 0
+        && meets_bps(voted, total_power(state), params.quorum_bps)
+        && meets_bps(yes_power, voted, params.approval_threshold_bps);
+
+    let mut events = vec![Event::ProposalFinalized {
+        proposal_id,
+        approved,
+        yes_power,
+        no_power,
+    }];
+
+    let status = if approved {
+        let action = proposal.action.clone();
+        match apply_action(state, &action) {
+            Ok(()) => {
+                events.push(Event::ProposalExecuted { proposal_id });
+                ProposalStatus::Executed
+            }
+            Err(error) => ProposalStatus::Failed { error },
+        }
+    } else {
+        ProposalStatus::Rejected
+    };
+
+    if let Some(proposal) = state.proposals.get_mut(&proposal_id) {
+        proposal.status = status;
+    }
+    events
+}
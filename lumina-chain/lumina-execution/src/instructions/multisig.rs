@@ -0,0 +1,157 @@
+use crate::ExecutionContext;
+use lumina_crypto::signatures::PublicKey;
+use lumina_types::error::ExecutionError;
+use lumina_types::receipt::Event;
+use lumina_types::state::{MultisigMember, MultisigPolicy};
+
+/// Upper bound on members of one multisig account.
+pub const MAX_MULTISIG_MEMBERS: usize = 16;
+
+fn member_key(member: &MultisigMember) -> PublicKey {
+    match member {
+        MultisigMember::Ed25519(pubkey) => PublicKey::Ed25519(*pubkey),
+        MultisigMember::PostQuantum(pubkey) => PublicKey::PostQuantum(pubkey.clone()),
+    }
+}
+
+fn validate(policy: &MultisigPolicy) -> Result<(), ExecutionError> {
+    if policy.members.is_empty() || policy.members.len() > MAX_MULTISIG_MEMBERS {
+        return Err(ExecutionError::InvalidArgument {
+            reason: format!("Multisig must have 1..={} members", MAX_MULTISIG_MEMBERS),
+        });
+    }
+    if policy.members.iter().any(|m| match m {
+        MultisigMember::Ed25519(_) => false,
+        MultisigMember::PostQuantum(pubkey) => pubkey.is_empty(),
+    }) {
+        return Err(ExecutionError::invalid_argument(
+            "PQ public key cannot be empty",
+        ));
+    }
+    for (i, member) in policy.members.iter().enumerate() {
+        if policy.members[..i].contains(member) {
+            return Err(ExecutionError::invalid_argument(
+                "Duplicate multisig member",
+            ));
+        }
+    }
+    if policy.threshold == 0 || policy.threshold as usize > policy.members.len() {
+        return Err(ExecutionError::invalid_argument(
+            "Multisig threshold must be 1..=member count",
+        ));
+    }
+    Ok(())
+}
+
+/// Checks that at least `threshold` distinct members signed `message`.
+/// `signatures` is the bundle packed by `Transaction::set_multisig_signatures`.
+pub fn verify(
+    policy: &MultisigPolicy,
+    message: &[u8],
+    signatures: &[Vec<u8>],
+) -> Result<(), ExecutionError> {
+    let mut approved = vec![false; policy.members.len()];
+    for sig in signatures {
+        let signer = policy
+            .members
+            .iter()
+            .enumerate()
+            .position(|(i, m)| !approved[i] && member_key(m).verify(message, sig).is_ok());
+        if let Some(i) = signer {
+            approved[i] = true;
+        }
+    }
+    let count = approved.iter().filter(|a| **a).count();
+    if count < policy.threshold as usize {
+        return Err(ExecutionError::InvalidSignature);
+    }
+    Ok(())
+}
+
+pub fn create(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    members: &[MultisigMember],
+    threshold: u32,
+) -> Result<Vec<Event>, ExecutionError> {
+    let policy = MultisigPolicy {
+        members: members.to_vec(),
+        threshold,
+    };
+    validate(&policy)?;
+    let account = ctx.state.accounts.entry(*sender).or_default();
+    if account.multisig.is_some() {
+        return Err(ExecutionError::precondition(
+            "Account is already a multisig; update its members instead",
+        ));
+    }
+    account.multisig = Some(policy);
+    Ok(vec![Event::MultisigAccountCreated {
+        account: *sender,
+        member_count: members.len() as u32,
+        threshold,
+    }])
+}
+
+/// Applies `change` to the sender's signer set. Because the sender is the
+/// multisig itself, the transaction carrying the change was already approved
+/// by the current threshold.
+fn update(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    change: impl FnOnce(&mut MultisigPolicy) -> Result<(), ExecutionError>,
+) -> Result<Vec<Event>, ExecutionError> {
+    let account = ctx.state.accounts.entry(*sender).or_default();
+    let mut policy = account
+        .multisig
+        .clone()
+        .ok_or_else(|| ExecutionError::precondition("Account is not a multisig"))?;
+    change(&mut policy)?;
+    validate(&policy)?;
+
+    let event = Event::MultisigUpdated {
+        account: *sender,
+        member_count: policy.members.len() as u32,
+        threshold: policy.threshold,
+    };
+    account.multisig = Some(policy);
+    Ok(vec![event])
+}
+
+pub fn add_member(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    member: &MultisigMember,
+) -> Result<Vec<Event>, ExecutionError> {
+    update(ctx, sender, |policy| {
+        policy.members.push(member.clone());
+        Ok(())
+    })
+}
+
+pub fn remove_member(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    member: &MultisigMember,
+) -> Result<Vec<Event>, ExecutionError> {
+    update(ctx, sender, |policy| {
+        let position = policy
+            .members
+            .iter()
+            .position(|m| m == member)
+            .ok_or_else(|| ExecutionError::not_found("Multisig member"))?;
+        policy.members.remove(position);
+        Ok(())
+    })
+}
+
+pub fn set_threshold(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    threshold: u32,
+) -> Result<Vec<Event>, ExecutionError> {
+    update(ctx, sender, |policy| {
+        policy.threshold = threshold;
+        Ok(())
+    })
+}
@@ -1,24 +1,54 @@
-use anyhow::{bail, Result};
 use lumina_crypto::signatures::verify_signature;
+use lumina_crypto::signatures::webauthn::is_valid_device_key;
+use lumina_types::error::ExecutionError;
 use lumina_types::state::AccountState;
 use std::collections::HashSet;
 
+/// Registers a passkey device key and social recovery guardians.
+pub fn register(
+    account: &mut AccountState,
+    device_key: &[u8],
+    guardians: &[[u8; 32]],
+) -> Result<(), ExecutionError> {
+    if device_key.is_empty() || device_key.iter().all(|&b| b == 0) {
+        return Err(ExecutionError::invalid_argument("Invalid device key"));
+    }
+    if guardians.len() < 2 || guardians.len() > 10 {
+        return Err(ExecutionError::invalid_argument(
+            "Must have 2-10 guardians for social recovery",
+        ));
+    }
+    // A passkey-authorized account must not be moved onto a key it can't sign with
+    if account.passkey_auth && !is_valid_device_key(device_key) {
+        return Err(ExecutionError::invalid_argument(
+            "Device key is not a P-256 public key",
+        ));
+    }
+    account.passkey_device_key = Some(device_key.to_vec());
+    account.guardians = guardians.to_vec();
+    Ok(())
+}
+
 pub fn recover_social(
     account: &mut AccountState,
     new_device_key: &[u8],
     guardian_signatures: &[Vec<u8>],
-) -> Result<()> {
+) -> Result<(), ExecutionError> {
     if account.guardians.is_empty() {
-        bail!("Account has no guardians configured");
+        return Err(ExecutionError::precondition(
+            "Account has no guardians configured",
+        ));
     }
 
     let threshold = (account.guardians.len() / 2) + 1;
     if guardian_signatures.len() < threshold {
-        bail!(
-            "Insufficient guardian signatures: need {}, got {}",
-            threshold,
-            guardian_signatures.len()
-        );
+        return Err(ExecutionError::Unauthorized {
+            reason: format!(
+                "Insufficient guardian signatures: need {}, got {}",
+                threshold,
+                guardian_signatures.len()
+            ),
+        });
     }
 
     let mut used_guardians = HashSet::<[u8; 32]>::new();
@@ -42,9 +72,30 @@ pub fn recover_social(
     }
 
     if verified_count < threshold {
-        bail!("Guardian signature verification failed");
+        return Err(ExecutionError::InvalidSignature);
+    }
+    // A passkey-authorized account must not be rotated onto a key it can't sign with
+    if account.passkey_auth && !is_valid_device_key(new_device_key) {
+        return Err(ExecutionError::invalid_argument(
+            "Device key is not a P-256 public key",
+        ));
     }
 
     account.passkey_device_key = Some(new_device_key.to_vec());
     Ok(())
 }
+
+/// Selects the registered passkey as the account's transaction key.
+pub fn switch_to_passkey(account: &mut AccountState) -> Result<(), ExecutionError> {
+    let device_key = account
+        .passkey_device_key
+        .as_deref()
+        .ok_or_else(|| ExecutionError::precondition("Account has no passkey registered"))?;
+    if !is_valid_device_key(device_key) {
+        return Err(ExecutionError::invalid_argument(
+            "Device key is not a P-256 public key",
+        ));
+    }
+    account.passkey_auth = true;
+    Ok(())
+}
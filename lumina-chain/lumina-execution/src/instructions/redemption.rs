@@ -0,0 +1,110 @@
+use crate::{bps_of, checked_add_u64, checked_sub_u64, recalculate_ratios, ExecutionContext};
+use lumina_types::error::ExecutionError;
+use lumina_types::receipt::Event;
+use lumina_types::state::RedemptionRequest;
+
+/// Debits `amount` LUSD from the sender into the fair redemption queue.
+pub fn enqueue(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    amount: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    let acct = ctx.state.accounts.entry(*sender).or_default();
+    acct.lusd_balance = checked_sub_u64(acct.lusd_balance, amount, "LUSD balance")?;
+
+    let request_id = ctx.state.next_redemption_id;
+    ctx.state.next_redemption_id = request_id
+        .checked_add(1)
+        .ok_or_else(|| ExecutionError::overflow("Redemption id"))?;
+    ctx.state.fair_redeem_queue.push_back(RedemptionRequest {
+        request_id,
+        address: *sender,
+        amount,
+        timestamp: ctx.timestamp,
+        filled: 0,
+    });
+
+    Ok(vec![Event::RedemptionQueued {
+        request_id,
+        account: *sender,
+        amount,
+    }])
+}
+
+/// Settles up to `batch_size` requests from the head of the queue. Each receives
+/// the same fraction of its outstanding amount, equal to the current reserve
+/// ratio (capped at 100%). Settled LUSD is burned and the matching collateral is
+/// released to the requester, as for an instant redemption; partially filled
+/// requests rejoin the back of the queue so later requesters are not starved.
+pub fn process(ctx: &mut ExecutionContext, batch_size: u32) -> Result<Vec<Event>, ExecutionError> {
+    let fill_bps = (ctx.state.reserve_ratio.clamp(0.0, 1.0) * 10_000.0) as u64;
+    if fill_bps == 0 && !ctx.state.fair_redeem_queue.is_empty() {
+        return Err(ExecutionError::precondition(
+            "No reserves available to settle redemptions",
+        ));
+    }
+
+    let to_process = std::cmp::min(batch_size as usize, ctx.state.fair_redeem_queue.len());
+    let mut events = Vec::with_capacity(to_process * 2);
+    for _ in 0..to_process {
+        let Some(mut req) = ctx.state.fair_redeem_queue.pop_front() else {
+            break;
+        };
+        let outstanding = req.remaining();
+        // Always make progress so dust amounts eventually clear
+        let fill = bps_of(outstanding, fill_bps).max(1).min(outstanding);
+        req.filled = req.filled.saturating_add(fill);
+
+        ctx.state.total_lusd_supply = ctx.state.total_lusd_supply.saturating_sub(fill);
+        ctx.state.stabilization_pool_balance =
+            ctx.state.stabilization_pool_balance.saturating_sub(fill);
+
+        events.push(Event::RedemptionSettled {
+            request_id: req.request_id,
+            account: req.address,
+            amount: fill,
+            remaining: req.remaining(),
+        });
+        events.push(Event::CollateralReleased {
+            account: req.address,
+            amount: fill,
+        });
+        if req.remaining() > 0 {
+            ctx.state.fair_redeem_queue.push_back(req);
+        }
+    }
+    recalculate_ratios(ctx);
+    Ok(events)
+}
+
+/// Removes the sender's queued request and refunds its unfilled LUSD.
+pub fn cancel(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    request_id: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    let position = ctx
+        .state
+        .redemption_position(request_id)
+        .ok_or_else(|| ExecutionError::not_found("Redemption request"))?;
+    if ctx.state.fair_redeem_queue[position].address != *sender {
+        return Err(ExecutionError::unauthorized(
+            "Only the requester can cancel a redemption",
+        ));
+    }
+    let refunded = ctx
+        .state
+        .fair_redeem_queue
+        .remove(position)
+        .map(|r| r.remaining())
+        .unwrap_or(0);
+
+    let acct = ctx.state.accounts.entry(*sender).or_default();
+    acct.lusd_balance = checked_add_u64(acct.lusd_balance, refunded, "LUSD balance")?;
+
+    Ok(vec![Event::RedemptionCancelled {
+        request_id,
+        account: *sender,
+        refunded,
+    }])
+}
@@ -0,0 +1,91 @@
+use crate::ExecutionContext;
+use lumina_crypto::signatures::verify_signature;
+use lumina_types::error::ExecutionError;
+use lumina_types::instruction::StablecoinInstruction;
+use lumina_types::receipt::Event;
+use lumina_types::state::Role;
+use std::collections::HashSet;
+
+/// Role the sender must hold to execute `si`, if any.
+pub fn required_role(si: &StablecoinInstruction) -> Option<Role> {
+    match si {
+        StablecoinInstruction::RunCircuitBreaker { .. } => Some(Role::Guardian),
+        StablecoinInstruction::UpdateOracle { .. } => Some(Role::Oracle),
+        StablecoinInstruction::UploadComplianceCircuit { .. } => Some(Role::ComplianceAdmin),
+        StablecoinInstruction::DistributeYield { .. }
+        | StablecoinInstruction::VelocityIncentive { .. }
+        | StablecoinInstruction::GeoRebalance { .. }
+        | StablecoinInstruction::DynamicHedge { .. } => Some(Role::Treasury),
+        StablecoinInstruction::RevalueRWA { .. } => Some(Role::RwaAttestor),
+        _ => None,
+    }
+}
+
+pub fn validate_multisig(members: &[[u8; 32]], threshold: u32) -> Result<(), ExecutionError> {
+    let unique: HashSet<&[u8; 32]> = members.iter().collect();
+    if unique.len() != members.len() {
+        return Err(ExecutionError::invalid_argument(
+            "Duplicate admin multisig member",
+        ));
+    }
+    if members.is_empty() {
+        // An empty member set disables the multisig path
+        return Ok(());
+    }
+    if threshold == 0 || threshold as usize > members.len() {
+        return Err(ExecutionError::invalid_argument(
+            "Admin threshold must be 1..=member count",
+        ));
+    }
+    Ok(())
+}
+
+pub fn update_by_admin(
+    ctx: &mut ExecutionContext,
+    role: Role,
+    account: &[u8; 32],
+    granted: bool,
+    admin_signatures: &[Vec<u8>],
+) -> Result<Vec<Event>, ExecutionError> {
+    let multisig = &ctx.state.admin_multisig;
+    if multisig.members.is_empty() {
+        return Err(ExecutionError::precondition(
+            "Admin multisig is not configured",
+        ));
+    }
+
+    let message = multisig.role_update_message(role, account, granted);
+    let mut approved = HashSet::<[u8; 32]>::new();
+    for sig in admin_signatures {
+        let signer = multisig
+            .members
+            .iter()
+            .find(|m| !approved.contains(*m) && verify_signature(m, &message, sig).is_ok());
+        if let Some(member) = signer {
+            approved.insert(*member);
+        }
+    }
+    if approved.len() < multisig.threshold as usize {
+        return Err(ExecutionError::Unauthorized {
+            reason: format!(
+                "Insufficient admin signatures: need {}, got {}",
+                multisig.threshold,
+                approved.len()
+            ),
+        });
+    }
+
+    ctx.state.admin_multisig.nonce = ctx
+        .state
+        .admin_multisig
+        .nonce
+        .checked_add(1)
+        .ok_or_else(|| ExecutionError::overflow("Admin nonce"))?;
+    ctx.state.set_role(role, *account, granted);
+
+    Ok(vec![Event::RoleUpdated {
+        role,
+        account: *account,
+        granted,
+    }])
+}
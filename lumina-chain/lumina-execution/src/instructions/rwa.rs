@@ -0,0 +1,352 @@
+use crate::{checked_add_u64, checked_sub_u64, recalculate_ratios, ExecutionContext};
+use lumina_crypto::zk::verify_rwa_attestation;
+use lumina_types::error::ExecutionError;
+use lumina_types::instruction::AssetType;
+use lumina_types::receipt::{Event, ProofKind};
+use lumina_types::state::{GlobalState, RWAListing, RwaAssetClass, RwaClassParams};
+
+fn listing_mut(state: &mut GlobalState, rwa_id: u64) -> Result<&mut RWAListing, ExecutionError> {
+    state
+        .rwa_listings
+        .get_mut(&rwa_id)
+        .ok_or_else(|| ExecutionError::not_found("RWA asset"))
+}
+
+fn owned_listing_mut<'a>(
+    state: &'a mut GlobalState,
+    sender: &[u8; 32],
+    rwa_id: u64,
+) -> Result<&'a mut RWAListing, ExecutionError> {
+    let listing = listing_mut(state, rwa_id)?;
+    if listing.owner != *sender {
+        return Err(ExecutionError::unauthorized(
+            "Only the RWA owner can manage this listing",
+        ));
+    }
+    Ok(listing)
+}
+
+/// Removes a settled or delisted listing along with its maturity index entry.
+fn remove_listing(state: &mut GlobalState, rwa_id: u64) -> Option<RWAListing> {
+    let listing = state.rwa_listings.remove(&rwa_id)?;
+    if let Some(date) = listing.maturity_date {
+        if let Some(ids) = state.rwa_maturities.get_mut(&date) {
+            ids.remove(&rwa_id);
+            if ids.is_empty() {
+                state.rwa_maturities.remove(&date);
+            }
+        }
+    }
+    Some(listing)
+}
+
+/// Risk parameters of the listing's asset class.
+fn class_params(state: &GlobalState, rwa_id: u64) -> Result<RwaClassParams, ExecutionError> {
+    state
+        .rwa_listings
+        .get(&rwa_id)
+        .map(|listing| state.rwa_class_params(listing.asset_class))
+        .ok_or_else(|| ExecutionError::not_found("RWA asset"))
+}
+
+fn is_past_maturity(listing: &RWAListing, timestamp: u64) -> bool {
+    listing.maturity_date.is_some_and(|date| timestamp >= date)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn list(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    asset_description: &str,
+    asset_class: RwaAssetClass,
+    attested_value: u64,
+    attestation_proof: &[u8],
+    maturity_date: Option<u64>,
+    collateral_eligibility: bool,
+) -> Result<Vec<Event>, ExecutionError> {
+    if asset_description.is_empty() {
+        return Err(ExecutionError::invalid_argument(
+            "Asset description must be non-empty",
+        ));
+    }
+    if attested_value == 0 {
+        return Err(ExecutionError::ZeroAmount);
+    }
+    if !verify_rwa_attestation(attestation_proof, attested_value) {
+        return Err(ExecutionError::InvalidProof {
+            kind: ProofKind::RwaAttestation,
+        });
+    }
+
+    let rwa_id = ctx.state.next_rwa_id;
+    ctx.state.next_rwa_id = ctx
+        .state
+        .next_rwa_id
+        .checked_add(1)
+        .ok_or_else(|| ExecutionError::overflow("RWA id"))?;
+
+    ctx.state.rwa_listings.insert(
+        rwa_id,
+        RWAListing {
+            owner: *sender,
+            asset_description: asset_description.to_string(),
+            asset_class,
+            attestation_proof: attestation_proof.to_vec(),
+            attested_value,
+            valued_at: ctx.timestamp,
+            maturity_date,
+            collateral_eligibility,
+            pledged_amount: 0,
+            debt: 0,
+        },
+    );
+    if let Some(date) = maturity_date {
+        ctx.state
+            .rwa_maturities
+            .entry(date)
+            .or_default()
+            .insert(rwa_id);
+    }
+    Ok(vec![Event::RwaListed {
+        rwa_id,
+        owner: *sender,
+        attested_value,
+    }])
+}
+
+pub fn pledge(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    rwa_id: u64,
+    amount_to_pledge: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    if amount_to_pledge == 0 {
+        return Err(ExecutionError::ZeroAmount);
+    }
+    let timestamp = ctx.timestamp;
+    let class_params = class_params(ctx.state, rwa_id)?;
+    let listing = owned_listing_mut(ctx.state, sender, rwa_id)?;
+
+    if is_past_maturity(listing, timestamp) {
+        return Err(ExecutionError::precondition("RWA has reached maturity"));
+    }
+    if !listing.collateral_eligibility {
+        return Err(ExecutionError::precondition(
+            "RWA listing not eligible as collateral",
+        ));
+    }
+    if timestamp.saturating_sub(listing.valued_at) > class_params.max_valuation_age {
+        return Err(ExecutionError::precondition(
+            "RWA valuation is stale; awaiting revaluation",
+        ));
+    }
+
+    let remaining_capacity = class_params
+        .max_debt(listing.attested_value)
+        .saturating_sub(listing.pledged_amount);
+    if amount_to_pledge > remaining_capacity {
+        return Err(ExecutionError::precondition(
+            "Pledge exceeds RWA remaining collateral capacity",
+        ));
+    }
+
+    listing.pledged_amount = checked_add_u64(listing.pledged_amount, amount_to_pledge, "Pledge")?;
+    listing.debt = checked_add_u64(listing.debt, amount_to_pledge, "RWA debt")?;
+
+    let account = ctx.state.accounts.entry(*sender).or_default();
+    account.lusd_balance = checked_add_u64(account.lusd_balance, amount_to_pledge, "Balance")?;
+    ctx.state.total_lusd_supply =
+        checked_add_u64(ctx.state.total_lusd_supply, amount_to_pledge, "Supply")?;
+    ctx.state.stabilization_pool_balance = checked_add_u64(
+        ctx.state.stabilization_pool_balance,
+        amount_to_pledge,
+        "Pool",
+    )?;
+
+    recalculate_ratios(ctx);
+    Ok(vec![
+        Event::RwaPledged {
+            rwa_id,
+            account: *sender,
+            amount: amount_to_pledge,
+        },
+        Event::Minted {
+            to: *sender,
+            asset: AssetType::LUSD,
+            amount: amount_to_pledge,
+        },
+    ])
+}
+
+pub fn repay(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    rwa_id: u64,
+    amount: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    if amount == 0 {
+        return Err(ExecutionError::ZeroAmount);
+    }
+    let listing = listing_mut(ctx.state, rwa_id)?;
+    let remaining_debt = listing.debt.checked_sub(amount).ok_or_else(|| {
+        ExecutionError::invalid_argument("Repayment exceeds outstanding RWA debt")
+    })?;
+    let account = ctx.state.accounts.entry(*sender).or_default();
+    if account.lusd_balance < amount {
+        return Err(ExecutionError::InsufficientBalance {
+            asset: AssetType::LUSD,
+        });
+    }
+    account.lusd_balance = checked_sub_u64(account.lusd_balance, amount, "Balance")?;
+    listing_mut(ctx.state, rwa_id)?.debt = remaining_debt;
+    ctx.state.total_lusd_supply = checked_sub_u64(ctx.state.total_lusd_supply, amount, "Supply")?;
+    ctx.state.stabilization_pool_balance =
+        ctx.state.stabilization_pool_balance.saturating_sub(amount);
+
+    recalculate_ratios(ctx);
+    Ok(vec![
+        Event::RwaLoanRepaid {
+            rwa_id,
+            account: *sender,
+            amount,
+            remaining_debt,
+        },
+        Event::Burned {
+            from: *sender,
+            asset: AssetType::LUSD,
+            amount,
+        },
+    ])
+}
+
+pub fn unpledge(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    rwa_id: u64,
+    amount: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    if amount == 0 {
+        return Err(ExecutionError::ZeroAmount);
+    }
+    let listing = owned_listing_mut(ctx.state, sender, rwa_id)?;
+    let pledged_amount = checked_sub_u64(listing.pledged_amount, amount, "Pledge")?;
+    if pledged_amount < listing.debt {
+        return Err(ExecutionError::precondition(
+            "Pledge still backs outstanding debt; repay first",
+        ));
+    }
+    listing.pledged_amount = pledged_amount;
+
+    Ok(vec![Event::RwaUnpledged {
+        rwa_id,
+        amount,
+        pledged_amount,
+    }])
+}
+
+pub fn delist(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    rwa_id: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    let listing = owned_listing_mut(ctx.state, sender, rwa_id)?;
+    if listing.pledged_amount > 0 || listing.debt > 0 {
+        return Err(ExecutionError::precondition(
+            "Repay and unpledge the RWA before delisting",
+        ));
+    }
+    remove_listing(ctx.state, rwa_id);
+    Ok(vec![Event::RwaDelisted { rwa_id }])
+}
+
+/// Writes off the listing's debt and hands its claim to the insurance fund,
+/// recorded in the emitted event; the listing itself is removed. The fund
+/// replaces as much of the lost reserve backing as it can; the rest comes out
+/// of the stabilization pool.
+fn write_off(ctx: &mut ExecutionContext, rwa_id: u64) -> Result<Event, ExecutionError> {
+    let listing =
+        remove_listing(ctx.state, rwa_id).ok_or_else(|| ExecutionError::not_found("RWA asset"))?;
+    let debt = listing.debt;
+
+    let insurance_covered = debt.min(ctx.state.insurance_fund_balance);
+    ctx.state.insurance_fund_balance -= insurance_covered;
+    ctx.state.stabilization_pool_balance = ctx
+        .state
+        .stabilization_pool_balance
+        .saturating_sub(debt - insurance_covered);
+    recalculate_ratios(ctx);
+
+    Ok(Event::RwaLiquidated {
+        rwa_id,
+        owner: listing.owner,
+        attested_value: listing.attested_value,
+        debt,
+        insurance_covered,
+    })
+}
+
+pub fn liquidate(ctx: &mut ExecutionContext, rwa_id: u64) -> Result<Vec<Event>, ExecutionError> {
+    let timestamp = ctx.timestamp;
+    let class_params = class_params(ctx.state, rwa_id)?;
+    let listing = listing_mut(ctx.state, rwa_id)?;
+    if listing.debt == 0 {
+        return Err(ExecutionError::precondition(
+            "RWA has no outstanding debt to liquidate",
+        ));
+    }
+    let defaulted = is_past_maturity(listing, timestamp)
+        || listing.debt > class_params.collateral_value(listing.attested_value);
+    if !defaulted {
+        return Err(ExecutionError::precondition(
+            "RWA pledge is in good standing",
+        ));
+    }
+    Ok(vec![write_off(ctx, rwa_id)?])
+}
+
+pub fn revalue(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    rwa_id: u64,
+    attested_value: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    if attested_value == 0 {
+        return Err(ExecutionError::ZeroAmount);
+    }
+    let timestamp = ctx.timestamp;
+    let listing = listing_mut(ctx.state, rwa_id)?;
+    listing.attested_value = attested_value;
+    listing.valued_at = timestamp;
+
+    Ok(vec![Event::RwaRevalued {
+        rwa_id,
+        attestor: *sender,
+        attested_value,
+    }])
+}
+
+/// Settles listings whose maturity date has passed. Called from `end_block`:
+/// fully repaid listings mature, the rest are liquidated. Either way the
+/// listing is removed.
+pub fn process_maturities(ctx: &mut ExecutionContext) -> Vec<Event> {
+    let due: Vec<u64> = ctx
+        .state
+        .rwa_maturities
+        .range(..=ctx.timestamp)
+        .flat_map(|(_, ids)| ids.iter().copied())
+        .collect();
+
+    let mut events = Vec::new();
+    for rwa_id in due {
+        let Some(listing) = ctx.state.rwa_listings.get(&rwa_id) else {
+            continue;
+        };
+        if listing.debt == 0 {
+            remove_listing(ctx.state, rwa_id);
+            events.push(Event::RwaMatured { rwa_id });
+        } else if let Ok(event) = write_off(ctx, rwa_id) {
+            events.push(event);
+        }
+    }
+    events
+}
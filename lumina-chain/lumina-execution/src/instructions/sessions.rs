@@ -0,0 +1,151 @@
+use crate::ExecutionContext;
+use lumina_types::error::ExecutionError;
+use lumina_types::instruction::StablecoinInstruction;
+use lumina_types::receipt::Event;
+use lumina_types::state::{AccountState, SessionKey, SessionPermission};
+
+/// Upper bound on live session keys per account.
+pub const MAX_SESSION_KEYS: usize = 8;
+
+/// Amount `si` spends under a session key, or `None` if `permissions` don't cover it.
+fn permitted_spend(permissions: &[SessionPermission], si: &StablecoinInstruction) -> Option<u64> {
+    permissions.iter().find_map(|p| match (p, si) {
+        (
+            SessionPermission::Transfer { asset },
+            StablecoinInstruction::Transfer {
+                amount, asset: a, ..
+            },
+        ) if asset == a => Some(*amount),
+        (
+            SessionPermission::StreamPayment,
+            StablecoinInstruction::StreamPayment {
+                amount_per_sec,
+                duration,
+                ..
+            },
+        ) => Some(amount_per_sec.saturating_mul(*duration)),
+        _ => None,
+    })
+}
+
+/// Checks that session key `pubkey` may sign `instructions` at `height` and
+/// returns the amount they spend against its budget, including the `max_fee`
+/// the transaction prepays. Nothing is recorded.
+pub fn authorize(
+    account: &AccountState,
+    pubkey: &[u8; 32],
+    instructions: &[StablecoinInstruction],
+    max_fee: u64,
+    height: u64,
+) -> Result<u64, ExecutionError> {
+    let key = account
+        .session_keys
+        .iter()
+        .find(|k| k.pubkey == *pubkey)
+        .ok_or_else(|| ExecutionError::unauthorized("Unknown session key"))?;
+    if height >= key.expires_at {
+        return Err(ExecutionError::unauthorized("Session key has expired"));
+    }
+
+    let mut spend = max_fee;
+    for si in instructions {
+        let amount = permitted_spend(&key.permissions, si).ok_or_else(|| {
+            ExecutionError::unauthorized("Instruction is outside the session key's scope")
+        })?;
+        spend = spend.saturating_add(amount);
+    }
+    if key.spent_at(height).saturating_add(spend) > key.spend_limit {
+        return Err(ExecutionError::unauthorized(
+            "Session key spending limit exceeded for this period",
+        ));
+    }
+    Ok(spend)
+}
+
+/// Charges `spend` to the session key's budget, starting a new period if the
+/// previous one has elapsed.
+pub fn record_spend(account: &mut AccountState, pubkey: &[u8; 32], spend: u64, height: u64) {
+    let Some(key) = account
+        .session_keys
+        .iter_mut()
+        .find(|k| k.pubkey == *pubkey)
+    else {
+        return;
+    };
+    let elapsed = height.saturating_sub(key.period_start);
+    if elapsed >= key.period_blocks {
+        key.period_start = height - elapsed % key.period_blocks;
+        key.spent_in_period = 0;
+    }
+    key.spent_in_period = key.spent_in_period.saturating_add(spend);
+}
+
+pub fn register(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    pubkey: &[u8; 32],
+    permissions: &[SessionPermission],
+    spend_limit: u64,
+    period_blocks: u64,
+    expires_at: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    if pubkey == sender {
+        return Err(ExecutionError::invalid_argument(
+            "Session key must differ from the account key",
+        ));
+    }
+    if permissions.is_empty() || period_blocks == 0 {
+        return Err(ExecutionError::invalid_argument(
+            "Session key needs permissions and a non-zero period",
+        ));
+    }
+    if expires_at <= ctx.height {
+        return Err(ExecutionError::invalid_argument(
+            "Session key expiry must be in the future",
+        ));
+    }
+
+    let height = ctx.height;
+    let account = ctx.state.accounts.entry(*sender).or_default();
+    account
+        .session_keys
+        .retain(|k| k.pubkey != *pubkey && k.expires_at > height);
+    if account.session_keys.len() >= MAX_SESSION_KEYS {
+        return Err(ExecutionError::InvalidArgument {
+            reason: format!("At most {} session keys per account", MAX_SESSION_KEYS),
+        });
+    }
+    account.session_keys.push(SessionKey {
+        pubkey: *pubkey,
+        permissions: permissions.to_vec(),
+        spend_limit,
+        period_blocks,
+        expires_at,
+        period_start: height,
+        spent_in_period: 0,
+    });
+
+    Ok(vec![Event::SessionKeyRegistered {
+        account: *sender,
+        pubkey: *pubkey,
+        expires_at,
+    }])
+}
+
+pub fn revoke(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    pubkey: &[u8; 32],
+) -> Result<Vec<Event>, ExecutionError> {
+    let account = ctx.state.accounts.entry(*sender).or_default();
+    let position = account
+        .session_keys
+        .iter()
+        .position(|k| k.pubkey == *pubkey)
+        .ok_or_else(|| ExecutionError::not_found("Session key"))?;
+    account.session_keys.remove(position);
+    Ok(vec![Event::SessionKeyRevoked {
+        account: *sender,
+        pubkey: *pubkey,
+    }])
+}
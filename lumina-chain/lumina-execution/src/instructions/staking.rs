@@ -0,0 +1,464 @@
+use crate::{bps_of, checked_add_u64, ExecutionContext};
+use lumina_types::error::ExecutionError;
+use lumina_types::instruction::AssetType;
+use lumina_types::receipt::Event;
+use lumina_types::state::{
+    Delegation, UnbondingEntry, ValidatorState, ValidatorStatus, REWARD_SCALE,
+};
+
+/// Epoch length in blocks. Validator power changes and velocity reward epochs
+/// roll over at multiples of this height.
+pub const EPOCH_LENGTH: u64 = 8640; // ~1 day at 10s/block
+
+fn debit_lumina(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    amount: u64,
+) -> Result<(), ExecutionError> {
+    let account = ctx.state.accounts.entry(*sender).or_default();
+    if account.lumina_balance < amount {
+        return Err(ExecutionError::InsufficientBalance {
+            asset: AssetType::Lumina,
+        });
+    }
+    account.lumina_balance = account.lumina_balance.saturating_sub(amount);
+    Ok(())
+}
+
+/// Looks up a validator the sender operates.
+fn operated_validator<'a>(
+    ctx: &'a mut ExecutionContext,
+    sender: &[u8; 32],
+    pubkey: &[u8; 32],
+) -> Result<&'a mut ValidatorState, ExecutionError> {
+    let validator = ctx
+        .state
+        .validators
+        .iter_mut()
+        .find(|v| v.pubkey == *pubkey)
+        .ok_or_else(|| ExecutionError::not_found("Validator"))?;
+    if validator.operator != *sender {
+        return Err(ExecutionError::unauthorized(
+            "Only the validator operator can manage its stake",
+        ));
+    }
+    Ok(validator)
+}
+
+/// Registers a new validator. It joins the active set with power at the next
+/// epoch boundary.
+pub fn register(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    pubkey: &[u8; 32],
+    stake: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    if stake == 0 {
+        return Err(ExecutionError::ZeroAmount);
+    }
+    if ctx.state.validators.iter().any(|v| v.pubkey == *pubkey) {
+        return Err(ExecutionError::invalid_argument(
+            "Validator pubkey is already registered",
+        ));
+    }
+    debit_lumina(ctx, sender, stake)?;
+
+    ctx.state.validators.push(ValidatorState {
+        pubkey: *pubkey,
+        operator: *sender,
+        stake,
+        power: 0,
+        is_green: false,
+        energy_proof: None,
+        status: ValidatorStatus::Active,
+        unbonding: Vec::new(),
+        ..Default::default()
+    });
+    Ok(vec![Event::ValidatorRegistered {
+        pubkey: *pubkey,
+        stake,
+    }])
+}
+
+pub fn add_stake(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    pubkey: &[u8; 32],
+    amount: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    if amount == 0 {
+        return Err(ExecutionError::ZeroAmount);
+    }
+    if operated_validator(ctx, sender, pubkey)?.status == ValidatorStatus::Unbonding {
+        return Err(ExecutionError::precondition(
+            "Validator is unbonding; register again after withdrawal",
+        ));
+    }
+    debit_lumina(ctx, sender, amount)?;
+
+    let validator = operated_validator(ctx, sender, pubkey)?;
+    validator.stake = checked_add_u64(validator.stake, amount, "Validator stake")?;
+    Ok(vec![Event::StakeAdded {
+        pubkey: *pubkey,
+        amount,
+        total_stake: validator.stake,
+    }])
+}
+
+/// Moves `amount` of bonded stake into an unbonding entry. Unbonding the full
+/// stake takes the validator out of the set at the next epoch boundary.
+pub fn unbond(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    pubkey: &[u8; 32],
+    amount: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    if amount == 0 {
+        return Err(ExecutionError::ZeroAmount);
+    }
+    let release_height = ctx
+        .height
+        .saturating_add(ctx.state.protocol_params.unbonding_period_blocks);
+
+    let validator = operated_validator(ctx, sender, pubkey)?;
+    if amount > validator.stake {
+        return Err(ExecutionError::invalid_argument(
+            "Unbond amount exceeds bonded stake",
+        ));
+    }
+    validator.stake = validator.stake.saturating_sub(amount);
+    validator.unbonding.push(UnbondingEntry {
+        amount,
+        release_height,
+    });
+    // A jailed validator stays jailed until release even with no stake left
+    if validator.stake == 0 && validator.status == ValidatorStatus::Active {
+        validator.status = ValidatorStatus::Unbonding;
+    }
+
+    Ok(vec![Event::StakeUnbonding {
+        pubkey: *pubkey,
+        amount,
+        release_height,
+    }])
+}
+
+/// Returns every matured unbonding entry the sender holds on this validator,
+/// both as operator and as delegator.
+pub fn withdraw(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    pubkey: &[u8; 32],
+) -> Result<Vec<Event>, ExecutionError> {
+    let height = ctx.height;
+    let validator = ctx
+        .state
+        .validators
+        .iter_mut()
+        .find(|v| v.pubkey == *pubkey)
+        .ok_or_else(|| ExecutionError::not_found("Validator"))?;
+
+    let mut amount = 0u64;
+    if validator.operator == *sender {
+        amount = amount.saturating_add(take_matured(&mut validator.unbonding, height));
+    }
+    if let Some(delegation) = validator.delegations.get_mut(sender) {
+        amount = amount.saturating_add(take_matured(&mut delegation.unbonding, height));
+        if *delegation == Delegation::default() {
+            validator.delegations.remove(sender);
+        }
+    }
+    if amount == 0 {
+        return Err(ExecutionError::precondition(
+            "No unbonded stake has matured yet",
+        ));
+    }
+
+    let account = ctx.state.accounts.entry(*sender).or_default();
+    account.lumina_balance = checked_add_u64(account.lumina_balance, amount, "Lumina balance")?;
+    Ok(vec![Event::StakeWithdrawn {
+        pubkey: *pubkey,
+        account: *sender,
+        amount,
+    }])
+}
+
+fn take_matured(entries: &mut Vec<UnbondingEntry>, height: u64) -> u64 {
+    let mut amount = 0u64;
+    entries.retain(|e| {
+        let matured = e.release_height <= height;
+        if matured {
+            amount = amount.saturating_add(e.amount);
+        }
+        !matured
+    });
+    amount
+}
+
+pub fn set_commission(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    pubkey: &[u8; 32],
+    commission_bps: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    if commission_bps > 10_000 {
+        return Err(ExecutionError::invalid_argument(
+            "Commission cannot exceed 100% (10000 bps)",
+        ));
+    }
+    operated_validator(ctx, sender, pubkey)?.commission_bps = commission_bps;
+    Ok(vec![Event::CommissionUpdated {
+        pubkey: *pubkey,
+        commission_bps,
+    }])
+}
+
+/// Folds rewards accrued since the last settlement into `pending_rewards` and
+/// resets the debt for the delegation's new `amount`.
+fn settle(delegation: &mut Delegation, reward_per_share: u128, new_amount: u64) {
+    delegation.pending_rewards = delegation.claimable(reward_per_share);
+    delegation.amount = new_amount;
+    delegation.reward_debt = (new_amount as u128).saturating_mul(reward_per_share);
+}
+
+/// Removes `fraction_bps` of a validator's bonded stake, its delegations and
+/// any unbonding entries that are still locked at `height`. Returns the total
+/// amount removed.
+pub fn slash(validator: &mut ValidatorState, fraction_bps: u64, height: u64) -> u64 {
+    fn slash_locked(entries: &mut [UnbondingEntry], fraction_bps: u64, height: u64) -> u64 {
+        let mut slashed = 0u64;
+        for entry in entries.iter_mut().filter(|e| e.release_height > height) {
+            let cut = bps_of(entry.amount, fraction_bps);
+            entry.amount = entry.amount.saturating_sub(cut);
+            slashed = slashed.saturating_add(cut);
+        }
+        slashed
+    }
+
+    let self_cut = bps_of(validator.stake, fraction_bps);
+    validator.stake = validator.stake.saturating_sub(self_cut);
+    let mut slashed =
+        self_cut.saturating_add(slash_locked(&mut validator.unbonding, fraction_bps, height));
+
+    let rps = validator.reward_per_share;
+    let mut delegated_cut = 0u64;
+    for delegation in validator.delegations.values_mut() {
+        let cut = bps_of(delegation.amount, fraction_bps);
+        settle(delegation, rps, delegation.amount.saturating_sub(cut));
+        delegated_cut = delegated_cut.saturating_add(cut);
+        slashed = slashed.saturating_add(slash_locked(
+            &mut delegation.unbonding,
+            fraction_bps,
+            height,
+        ));
+    }
+    validator.delegated = validator.delegated.saturating_sub(delegated_cut);
+    slashed.saturating_add(delegated_cut)
+}
+
+/// Bonds the sender's Lumina to an active validator. The added power takes
+/// effect at the next epoch boundary.
+pub fn delegate(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    pubkey: &[u8; 32],
+    amount: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    if amount == 0 {
+        return Err(ExecutionError::ZeroAmount);
+    }
+    let validator = ctx
+        .state
+        .validators
+        .iter()
+        .find(|v| v.pubkey == *pubkey)
+        .ok_or_else(|| ExecutionError::not_found("Validator"))?;
+    if validator.status != ValidatorStatus::Active {
+        return Err(ExecutionError::precondition(
+            "Validator is not accepting delegations",
+        ));
+    }
+    debit_lumina(ctx, sender, amount)?;
+
+    let validator = ctx
+        .state
+        .validators
+        .iter_mut()
+        .find(|v| v.pubkey == *pubkey)
+        .ok_or_else(|| ExecutionError::not_found("Validator"))?;
+    validator.delegated = checked_add_u64(validator.delegated, amount, "Delegated stake")?;
+    let rps = validator.reward_per_share;
+    let delegation = validator.delegations.entry(*sender).or_default();
+    let new_amount = checked_add_u64(delegation.amount, amount, "Delegation")?;
+    settle(delegation, rps, new_amount);
+
+    Ok(vec![Event::Delegated {
+        delegator: *sender,
+        pubkey: *pubkey,
+        amount,
+    }])
+}
+
+pub fn undelegate(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    pubkey: &[u8; 32],
+    amount: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    if amount == 0 {
+        return Err(ExecutionError::ZeroAmount);
+    }
+    let release_height = ctx
+        .height
+        .saturating_add(ctx.state.protocol_params.unbonding_period_blocks);
+    let validator = ctx
+        .state
+        .validators
+        .iter_mut()
+        .find(|v| v.pubkey == *pubkey)
+        .ok_or_else(|| ExecutionError::not_found("Validator"))?;
+    let rps = validator.reward_per_share;
+    let delegation = validator
+        .delegations
+        .get_mut(sender)
+        .ok_or_else(|| ExecutionError::not_found("Delegation"))?;
+    if amount > delegation.amount {
+        return Err(ExecutionError::invalid_argument(
+            "Undelegate amount exceeds delegation",
+        ));
+    }
+    let new_amount = delegation.amount.saturating_sub(amount);
+    settle(delegation, rps, new_amount);
+    delegation.unbonding.push(UnbondingEntry {
+        amount,
+        release_height,
+    });
+    validator.delegated = validator.delegated.saturating_sub(amount);
+
+    Ok(vec![Event::Undelegated {
+        delegator: *sender,
+        pubkey: *pubkey,
+        amount,
+        release_height,
+    }])
+}
+
+pub fn claim_rewards(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    pubkey: &[u8; 32],
+) -> Result<Vec<Event>, ExecutionError> {
+    let validator = ctx
+        .state
+        .validators
+        .iter_mut()
+        .find(|v| v.pubkey == *pubkey)
+        .ok_or_else(|| ExecutionError::not_found("Validator"))?;
+    let rps = validator.reward_per_share;
+    let delegation = validator
+        .delegations
+        .get_mut(sender)
+        .ok_or_else(|| ExecutionError::not_found("Delegation"))?;
+    settle(delegation, rps, delegation.amount);
+    let amount = std::mem::take(&mut delegation.pending_rewards);
+    if amount == 0 {
+        return Err(ExecutionError::ZeroAmount);
+    }
+    if *delegation == Delegation::default() {
+        validator.delegations.remove(sender);
+    }
+
+    let account = ctx.state.accounts.entry(*sender).or_default();
+    account.lumina_balance = checked_add_u64(account.lumina_balance, amount, "Lumina balance")?;
+    Ok(vec![Event::DelegatorRewardsClaimed {
+        delegator: *sender,
+        pubkey: *pubkey,
+        amount,
+    }])
+}
+
+/// Pays the accumulated `gas_fee_pool` to validators in proportion to their
+/// epoch power. Each validator's operator receives the commission plus the
+/// self-bonded share; the delegated share is added to the validator's
+/// per-share accumulator and claimed by delegators individually.
+fn distribute_rewards(ctx: &mut ExecutionContext) -> Option<Event> {
+    let pool = ctx.state.gas_fee_pool;
+    let total_power = ctx
+        .state
+        .validators
+        .iter()
+        .fold(0u64, |acc, v| acc.saturating_add(v.power));
+    if pool == 0 || total_power == 0 {
+        return None;
+    }
+
+    let mut distributed = 0u64;
+    let mut operator_payouts = Vec::new();
+    for v in ctx.state.validators.iter_mut().filter(|v| v.power > 0) {
+        let share = ((pool as u128) * (v.power as u128) / (total_power as u128)) as u64;
+        let commission = bps_of(share, v.commission_bps);
+        let bonded = v.stake.saturating_add(v.delegated);
+        let delegator_part = if bonded == 0 {
+            0
+        } else {
+            ((share.saturating_sub(commission) as u128) * (v.delegated as u128) / (bonded as u128))
+                as u64
+        };
+        if delegator_part > 0 {
+            v.reward_per_share = v.reward_per_share.saturating_add(
+                (delegator_part as u128).saturating_mul(REWARD_SCALE) / (v.delegated as u128),
+            );
+        }
+        operator_payouts.push((v.operator, share.saturating_sub(delegator_part)));
+        distributed = distributed.saturating_add(share);
+    }
+    for (operator, amount) in operator_payouts {
+        let account = ctx.state.accounts.entry(operator).or_default();
+        account.lumina_balance = account.lumina_balance.saturating_add(amount);
+    }
+    ctx.state.gas_fee_pool = pool.saturating_sub(distributed);
+
+    Some(Event::StakingRewardsDistributed {
+        epoch: ctx.state.current_epoch,
+        amount: distributed,
+    })
+}
+
+/// Epoch rollover, run from `end_block` at multiples of [`EPOCH_LENGTH`].
+/// Pays out the epoch's rewards, applies pending stake changes to voting
+/// power, releases expired jails and drops validators that have fully exited.
+pub fn apply_epoch(ctx: &mut ExecutionContext) -> Vec<Event> {
+    if ctx.height == 0 || !ctx.height.is_multiple_of(EPOCH_LENGTH) {
+        return Vec::new();
+    }
+    let mut events: Vec<Event> = distribute_rewards(ctx).into_iter().collect();
+    ctx.state.current_epoch = ctx.state.current_epoch.saturating_add(1);
+
+    let height = ctx.height;
+    ctx.state.validators.retain(|v| {
+        let serving_jail =
+            matches!(v.status, ValidatorStatus::Jailed { until_height } if until_height > height);
+        v.stake > 0 || !v.unbonding.is_empty() || !v.delegations.is_empty() || serving_jail
+    });
+    let mut active_validators = 0u32;
+    let mut total_power = 0u64;
+    for v in ctx.state.validators.iter_mut() {
+        if let ValidatorStatus::Jailed { until_height } = v.status {
+            if until_height <= height {
+                v.status = ValidatorStatus::Active;
+            }
+        }
+        v.power = v.target_power();
+        if v.power > 0 {
+            active_validators = active_validators.saturating_add(1);
+            total_power = total_power.saturating_add(v.power);
+        }
+    }
+
+    events.push(Event::ValidatorSetUpdated {
+        epoch: ctx.state.current_epoch,
+        active_validators,
+        total_power,
+    });
+    events
+}
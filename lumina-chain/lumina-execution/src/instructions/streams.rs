@@ -0,0 +1,176 @@
+use crate::{checked_add_u64, ExecutionContext};
+use lumina_types::error::ExecutionError;
+use lumina_types::instruction::AssetType;
+use lumina_types::receipt::Event;
+use lumina_types::state::StreamState;
+
+pub fn open(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    to: &[u8; 32],
+    amount_per_sec: u64,
+    duration: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    if amount_per_sec == 0 || duration == 0 {
+        return Err(ExecutionError::invalid_argument(
+            "Stream amount and duration must be non-zero",
+        ));
+    }
+    let total_stream = amount_per_sec
+        .checked_mul(duration)
+        .ok_or_else(|| ExecutionError::overflow("Stream total"))?;
+    let end_timestamp = ctx
+        .timestamp
+        .checked_add(duration)
+        .ok_or_else(|| ExecutionError::overflow("Stream end"))?;
+    let account = ctx.state.accounts.entry(*sender).or_default();
+    if account.lusd_balance < total_stream {
+        return Err(ExecutionError::InsufficientBalance {
+            asset: AssetType::LUSD,
+        });
+    }
+    account.lusd_balance = account.lusd_balance.saturating_sub(total_stream);
+
+    let stream_id = ctx.state.next_stream_id;
+    ctx.state.next_stream_id = stream_id
+        .checked_add(1)
+        .ok_or_else(|| ExecutionError::overflow("Stream id"))?;
+
+    let stream = StreamState {
+        stream_id,
+        recipient: *to,
+        amount_per_sec,
+        start_timestamp: ctx.timestamp,
+        end_timestamp,
+        withdrawn: 0,
+    };
+    ctx.state
+        .accounts
+        .entry(*sender)
+        .or_default()
+        .active_streams
+        .push(stream);
+    ctx.state
+        .accounts
+        .entry(*to)
+        .or_default()
+        .incoming_streams
+        .push(stream_id);
+    ctx.state.stream_senders.insert(stream_id, *sender);
+
+    Ok(vec![Event::StreamOpened {
+        stream_id,
+        from: *sender,
+        to: *to,
+        amount_per_sec,
+        end_timestamp,
+    }])
+}
+
+/// Credits the recipient with everything vested so far. A stream that has run to
+/// its end and been fully withdrawn is settled and removed.
+pub fn withdraw(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    stream_id: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    let stream = find_stream(ctx, stream_id)?;
+    if stream.recipient != *sender {
+        return Err(ExecutionError::unauthorized(
+            "Only the stream recipient can withdraw",
+        ));
+    }
+    let amount = stream.withdrawable(ctx.timestamp);
+    if amount == 0 {
+        return Err(ExecutionError::ZeroAmount);
+    }
+
+    let recipient = ctx.state.accounts.entry(*sender).or_default();
+    recipient.lusd_balance = checked_add_u64(recipient.lusd_balance, amount, "LUSD balance")?;
+
+    let withdrawn = stream.withdrawn.saturating_add(amount);
+    if withdrawn >= stream.total() {
+        remove_stream(ctx, stream_id);
+    } else if let Some(s) = stream_mut(ctx, stream_id) {
+        s.withdrawn = withdrawn;
+    }
+
+    Ok(vec![Event::StreamWithdrawn {
+        stream_id,
+        recipient: *sender,
+        amount,
+    }])
+}
+
+/// Closes a stream early: the recipient receives what has vested and not yet
+/// been withdrawn, and the unvested remainder returns to the sender.
+pub fn cancel(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    stream_id: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    if ctx.state.stream_senders.get(&stream_id) != Some(sender) {
+        return Err(ExecutionError::unauthorized(
+            "Only the stream sender can cancel",
+        ));
+    }
+    let stream = find_stream(ctx, stream_id)?;
+    let paid_to_recipient = stream.withdrawable(ctx.timestamp);
+    let refunded_to_sender = stream.total().saturating_sub(stream.vested(ctx.timestamp));
+
+    let recipient = ctx.state.accounts.entry(stream.recipient).or_default();
+    recipient.lusd_balance =
+        checked_add_u64(recipient.lusd_balance, paid_to_recipient, "LUSD balance")?;
+    let owner = ctx.state.accounts.entry(*sender).or_default();
+    owner.lusd_balance = checked_add_u64(owner.lusd_balance, refunded_to_sender, "LUSD balance")?;
+    remove_stream(ctx, stream_id);
+
+    Ok(vec![Event::StreamClosed {
+        stream_id,
+        paid_to_recipient,
+        refunded_to_sender,
+    }])
+}
+
+fn find_stream(ctx: &ExecutionContext, stream_id: u64) -> Result<StreamState, ExecutionError> {
+    ctx.state
+        .stream_senders
+        .get(&stream_id)
+        .and_then(|owner| ctx.state.accounts.get(owner))
+        .and_then(|acct| {
+            acct.active_streams
+                .iter()
+                .find(|s| s.stream_id == stream_id)
+        })
+        .cloned()
+        .ok_or_else(|| ExecutionError::not_found("Stream"))
+}
+
+fn stream_mut<'a>(ctx: &'a mut ExecutionContext, stream_id: u64) -> Option<&'a mut StreamState> {
+    let owner = *ctx.state.stream_senders.get(&stream_id)?;
+    ctx.state
+        .accounts
+        .get_mut(&owner)?
+        .active_streams
+        .iter_mut()
+        .find(|s| s.stream_id == stream_id)
+}
+
+fn remove_stream(ctx: &mut ExecutionContext, stream_id: u64) {
+    let Some(owner) = ctx.state.stream_senders.remove(&stream_id) else {
+        return;
+    };
+    let mut recipient = None;
+    if let Some(acct) = ctx.state.accounts.get_mut(&owner) {
+        if let Some(pos) = acct
+            .active_streams
+            .iter()
+            .position(|s| s.stream_id == stream_id)
+        {
+            recipient = Some(acct.active_streams.remove(pos).recipient);
+        }
+    }
+    if let Some(acct) = recipient.and_then(|r| ctx.state.accounts.get_mut(&r)) {
+        acct.incoming_streams.retain(|id| *id != stream_id);
+    }
+}
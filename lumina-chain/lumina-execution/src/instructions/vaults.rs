@@ -0,0 +1,423 @@
+use super::assets::{balance_mut, ensure_movable};
+use crate::{bps_of, checked_add_u64, checked_sub_u64, recalculate_ratios, ExecutionContext};
+use lumina_types::error::ExecutionError;
+use lumina_types::instruction::{AssetType, PRICE_SCALE};
+use lumina_types::receipt::Event;
+use lumina_types::state::{GlobalState, Vault, VaultAuction};
+use std::collections::BTreeMap;
+
+/// USD price of `asset` from its [`AssetType::price_feed`]. Collateral the
+/// oracle doesn't price can't be valued, so it is rejected rather than
+/// counted as worthless.
+fn oracle_price(state: &GlobalState, asset: &AssetType) -> Result<u64, ExecutionError> {
+    state
+        .oracle_prices
+        .get(&asset.price_feed())
+        .copied()
+        .filter(|&price| price > 0)
+        .ok_or_else(|| ExecutionError::precondition("Collateral asset has no oracle price"))
+}
+
+/// LUSD value of `collateral` at current oracle prices.
+pub fn collateral_value(
+    state: &GlobalState,
+    collateral: &BTreeMap<String, u64>,
+) -> Result<u64, ExecutionError> {
+    let mut value = 0u128;
+    for (ticker, amount) in collateral {
+        let price = oracle_price(state, &AssetType::from_ticker(ticker))?;
+        value += (*amount as u128) * (price as u128) / (PRICE_SCALE as u128);
+    }
+    Ok(u64::try_from(value).unwrap_or(u64::MAX))
+}
+
+/// Whether the vault's collateral covers its debt at the collateral ratio.
+pub fn is_safe(state: &GlobalState, vault: &Vault) -> Result<bool, ExecutionError> {
+    let value = collateral_value(state, &vault.collateral)? as u128;
+    let ratio = state.protocol_params.vault_collateral_ratio_bps as u128;
+    Ok(value * 10_000 >= (vault.debt as u128) * ratio)
+}
+
+/// Asking price for the whole remaining lot at `height`.
+fn asking_price(auction: &VaultAuction, height: u64, duration: u64) -> u64 {
+    let left = duration.saturating_sub(height.saturating_sub(auction.start_height));
+    ((auction.start_price as u128) * (left as u128) / (duration.max(1) as u128)) as u64
+}
+
+fn vault(state: &GlobalState, owner: &[u8; 32]) -> Result<Vault, ExecutionError> {
+    state
+        .vaults
+        .get(owner)
+        .cloned()
+        .ok_or_else(|| ExecutionError::not_found("Vault"))
+}
+
+fn debit(
+    ctx: &mut ExecutionContext,
+    account: &[u8; 32],
+    asset: &AssetType,
+    amount: u64,
+) -> Result<(), ExecutionError> {
+    let balance = balance_mut(ctx.state.accounts.entry(*account).or_default(), asset);
+    if *balance < amount {
+        return Err(ExecutionError::InsufficientBalance {
+            asset: asset.clone(),
+        });
+    }
+    *balance = checked_sub_u64(*balance, amount, "Balance")?;
+    Ok(())
+}
+
+fn credit(
+    ctx: &mut ExecutionContext,
+    account: &[u8; 32],
+    asset: &AssetType,
+    amount: u64,
+) -> Result<(), ExecutionError> {
+    let balance = balance_mut(ctx.state.accounts.entry(*account).or_default(), asset);
+    *balance = checked_add_u64(*balance, amount, "Balance")?;
+    Ok(())
+}
+
+pub fn deposit(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    asset: &AssetType,
+    amount: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    if amount == 0 {
+        return Err(ExecutionError::ZeroAmount);
+    }
+    match asset {
+        AssetType::LUSD => {
+            return Err(ExecutionError::invalid_argument(
+                "LUSD cannot be vault collateral",
+            ));
+        }
+        AssetType::Custom(ticker) => ensure_movable(ctx.state, ticker, &[sender])?,
+        AssetType::LJUN | AssetType::Lumina => {}
+    }
+    oracle_price(ctx.state, asset)?;
+    debit(ctx, sender, asset, amount)?;
+    let locked = ctx
+        .state
+        .vaults
+        .entry(*sender)
+        .or_default()
+        .collateral
+        .entry(asset.ticker().to_string())
+        .or_insert(0);
+    *locked = checked_add_u64(*locked, amount, "Vault collateral")?;
+
+    Ok(vec![Event::VaultCollateralDeposited {
+        account: *sender,
+        asset: asset.clone(),
+        amount,
+    }])
+}
+
+pub fn withdraw(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    asset: &AssetType,
+    amount: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    if amount == 0 {
+        return Err(ExecutionError::ZeroAmount);
+    }
+    let mut vault = vault(ctx.state, sender)?;
+    let ticker = asset.ticker();
+    let locked = vault.collateral.get(ticker).copied().unwrap_or(0);
+    if locked < amount {
+        return Err(ExecutionError::InsufficientBalance {
+            asset: asset.clone(),
+        });
+    }
+    if locked == amount {
+        vault.collateral.remove(ticker);
+    } else {
+        vault.collateral.insert(ticker.to_string(), locked - amount);
+    }
+    if !is_safe(ctx.state, &vault)? {
+        return Err(ExecutionError::precondition(
+            "Withdrawal would leave the vault below the collateral ratio",
+        ));
+    }
+    if let AssetType::Custom(ticker) = asset {
+        ensure_movable(ctx.state, ticker, &[sender])?;
+    }
+
+    if vault.collateral.is_empty() && vault.debt == 0 {
+        ctx.state.vaults.remove(sender);
+    } else {
+        ctx.state.vaults.insert(*sender, vault);
+    }
+    credit(ctx, sender, asset, amount)?;
+
+    Ok(vec![Event::VaultCollateralWithdrawn {
+        account: *sender,
+        asset: asset.clone(),
+        amount,
+    }])
+}
+
+pub fn draw(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    amount: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    if amount == 0 {
+        return Err(ExecutionError::ZeroAmount);
+    }
+    if ctx.state.circuit_breaker_active {
+        return Err(ExecutionError::CircuitBreakerActive);
+    }
+    let mut vault = vault(ctx.state, sender)?;
+    vault.debt = checked_add_u64(vault.debt, amount, "Vault debt")?;
+    if !is_safe(ctx.state, &vault)? {
+        return Err(ExecutionError::precondition(
+            "Draw would leave the vault below the collateral ratio",
+        ));
+    }
+    let debt = vault.debt;
+    ctx.state.vaults.insert(*sender, vault);
+
+    // As with RWA pledges, LUSD drawn against a vault counts toward reserves
+    // for as long as the vault backs it
+    credit(ctx, sender, &AssetType::LUSD, amount)?;
+    ctx.state.total_lusd_supply = checked_add_u64(ctx.state.total_lusd_supply, amount, "Supply")?;
+    ctx.state.stabilization_pool_balance =
+        checked_add_u64(ctx.state.stabilization_pool_balance, amount, "Pool")?;
+
+    recalculate_ratios(ctx);
+    Ok(vec![
+        Event::VaultDebtDrawn {
+            account: *sender,
+            amount,
+            debt,
+        },
+        Event::Minted {
+            to: *sender,
+            asset: AssetType::LUSD,
+            amount,
+        },
+    ])
+}
+
+pub fn repay(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    amount: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    if amount == 0 {
+        return Err(ExecutionError::ZeroAmount);
+    }
+    let mut vault = vault(ctx.state, sender)?;
+    let remaining_debt = vault
+        .debt
+        .checked_sub(amount)
+        .ok_or_else(|| ExecutionError::invalid_argument("Repayment exceeds vault debt"))?;
+    debit(ctx, sender, &AssetType::LUSD, amount)?;
+    vault.debt = remaining_debt;
+    ctx.state.vaults.insert(*sender, vault);
+    ctx.state.total_lusd_supply = checked_sub_u64(ctx.state.total_lusd_supply, amount, "Supply")?;
+    ctx.state.stabilization_pool_balance =
+        ctx.state.stabilization_pool_balance.saturating_sub(amount);
+
+    recalculate_ratios(ctx);
+    Ok(vec![
+        Event::VaultDebtRepaid {
+            account: *sender,
+            amount,
+            remaining_debt,
+        },
+        Event::Burned {
+            from: *sender,
+            asset: AssetType::LUSD,
+            amount,
+        },
+    ])
+}
+
+pub fn liquidate(
+    ctx: &mut ExecutionContext,
+    owner: &[u8; 32],
+) -> Result<Vec<Event>, ExecutionError> {
+    let vault = vault(ctx.state, owner)?;
+    if vault.debt == 0 || is_safe(ctx.state, &vault)? {
+        return Err(ExecutionError::precondition(
+            "Vault is above the collateral ratio",
+        ));
+    }
+    let params = &ctx.state.protocol_params;
+    let penalty = bps_of(vault.debt, params.vault_liquidation_penalty_bps);
+    let start_price = bps_of(
+        collateral_value(ctx.state, &vault.collateral)?,
+        params.vault_auction_start_bps,
+    );
+
+    let auction_id = ctx.state.next_vault_auction_id;
+    ctx.state.next_vault_auction_id = auction_id
+        .checked_add(1)
+        .ok_or_else(|| ExecutionError::overflow("Vault auction id"))?;
+    ctx.state.vaults.remove(owner);
+    ctx.state.vault_auctions.insert(
+        auction_id,
+        VaultAuction {
+            owner: *owner,
+            lot: vault.collateral,
+            debt: vault.debt,
+            penalty,
+            start_price,
+            start_height: ctx.height,
+        },
+    );
+
+    Ok(vec![Event::VaultLiquidated {
+        owner: *owner,
+        auction_id,
+        debt: vault.debt,
+        penalty,
+        start_price,
+    }])
+}
+
+/// Hands unsold collateral back to the owner and writes off debt the auction
+/// did not raise, drawing on the insurance fund before the stabilization pool.
+fn close(
+    ctx: &mut ExecutionContext,
+    auction_id: u64,
+    auction: VaultAuction,
+) -> Result<Event, ExecutionError> {
+    for (ticker, amount) in &auction.lot {
+        credit(
+            ctx,
+            &auction.owner,
+            &AssetType::from_ticker(ticker),
+            *amount,
+        )?;
+    }
+    let shortfall = auction.debt;
+    let insurance_covered = shortfall.min(ctx.state.insurance_fund_balance);
+    ctx.state.insurance_fund_balance -= insurance_covered;
+    ctx.state.stabilization_pool_balance = ctx
+        .state
+        .stabilization_pool_balance
+        .saturating_sub(shortfall - insurance_covered);
+    ctx.state.vault_auctions.remove(&auction_id);
+
+    Ok(Event::VaultAuctionClosed {
+        auction_id,
+        shortfall,
+        insurance_covered,
+    })
+}
+
+/// Buys `paid / price` of every asset in the lot, where `paid` is capped by
+/// the bid, the amount still owed and the asking price itself. Proceeds burn
+/// against the debt first, then fund the penalty. The auction closes once
+/// everything owed is raised or the lot is sold out. An auction whose price
+/// has decayed to zero unsold restarts from the current oracle value.
+pub fn bid(
+    ctx: &mut ExecutionContext,
+    bidder: &[u8; 32],
+    auction_id: u64,
+    max_payment: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    if max_payment == 0 {
+        return Err(ExecutionError::ZeroAmount);
+    }
+    let mut auction = ctx
+        .state
+        .vault_auctions
+        .get(&auction_id)
+        .cloned()
+        .ok_or_else(|| ExecutionError::not_found("Vault auction"))?;
+    let height = ctx.height;
+    let duration = ctx.state.protocol_params.vault_auction_duration_blocks;
+
+    let mut events = Vec::new();
+    if height.saturating_sub(auction.start_height) >= duration {
+        auction.start_price = bps_of(
+            collateral_value(ctx.state, &auction.lot)?,
+            ctx.state.protocol_params.vault_auction_start_bps,
+        );
+        auction.start_height = height;
+        events.push(Event::VaultAuctionRestarted {
+            auction_id,
+            start_price: auction.start_price,
+        });
+    }
+    let price = asking_price(&auction, height, duration);
+    if price == 0 {
+        return Err(ExecutionError::precondition(
+            "Vault auction lot has no value at current prices",
+        ));
+    }
+
+    let owed = checked_add_u64(auction.debt, auction.penalty, "Vault auction")?;
+    let paid = max_payment.min(owed).min(price);
+    let mut bought = Vec::new();
+    for (ticker, amount) in auction.lot.iter_mut() {
+        let share = if paid == price {
+            *amount
+        } else {
+            ((*amount as u128) * (paid as u128) / (price as u128)) as u64
+        };
+        if share > 0 {
+            *amount -= share;
+            bought.push((AssetType::from_ticker(ticker), share));
+        }
+    }
+    auction.lot.retain(|_, amount| *amount > 0);
+    if bought.is_empty() {
+        return Err(ExecutionError::precondition(
+            "Payment too small to buy any collateral",
+        ));
+    }
+    for (asset, _) in &bought {
+        if let AssetType::Custom(ticker) = asset {
+            ensure_movable(ctx.state, ticker, &[bidder])?;
+        }
+    }
+
+    debit(ctx, bidder, &AssetType::LUSD, paid)?;
+    for (asset, share) in &bought {
+        credit(ctx, bidder, asset, *share)?;
+    }
+    let to_debt = paid.min(auction.debt);
+    let to_penalty = paid - to_debt;
+    auction.debt -= to_debt;
+    auction.penalty = auction.penalty.saturating_sub(to_penalty);
+    ctx.state.total_lusd_supply = checked_sub_u64(ctx.state.total_lusd_supply, paid, "Supply")?;
+    ctx.state.stabilization_pool_balance =
+        ctx.state.stabilization_pool_balance.saturating_sub(to_debt);
+    ctx.state.insurance_fund_balance = checked_add_u64(
+        ctx.state.insurance_fund_balance,
+        to_penalty,
+        "Insurance fund",
+    )?;
+
+    events.push(Event::VaultAuctionBid {
+        auction_id,
+        bidder: *bidder,
+        paid,
+        price,
+    });
+    events.push(Event::Burned {
+        from: *bidder,
+        asset: AssetType::LUSD,
+        amount: paid,
+    });
+    if to_penalty > 0 {
+        events.push(Event::InsuranceFundCredited { amount: to_penalty });
+    }
+
+    if (auction.debt == 0 && auction.penalty == 0) || auction.lot.is_empty() {
+        events.push(close(ctx, auction_id, auction)?);
+    } else {
+        ctx.state.vault_auctions.insert(auction_id, auction);
+    }
+    recalculate_ratios(ctx);
+    Ok(events)
+}
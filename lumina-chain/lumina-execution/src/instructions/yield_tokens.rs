@@ -0,0 +1,221 @@
+use crate::{checked_add_u64, checked_sub_u64, recalculate_ratios, ExecutionContext};
+use lumina_types::error::ExecutionError;
+use lumina_types::instruction::{AssetType, YieldTokenSide};
+use lumina_types::receipt::Event;
+use lumina_types::state::{GlobalState, YieldPosition};
+
+fn position_mut(
+    state: &mut GlobalState,
+    token_id: u64,
+) -> Result<&mut YieldPosition, ExecutionError> {
+    state
+        .yield_positions
+        .get_mut(&token_id)
+        .ok_or_else(|| ExecutionError::not_found("Yield position"))
+}
+
+/// Credits `principal` plus `yield_paid` LUSD to `account`, minting the yield.
+fn pay_out(
+    ctx: &mut ExecutionContext,
+    account: &[u8; 32],
+    principal: u64,
+    yield_paid: u64,
+) -> Result<(), ExecutionError> {
+    let total = checked_add_u64(principal, yield_paid, "Yield payout")?;
+    let acct = ctx.state.accounts.entry(*account).or_default();
+    acct.lusd_balance = checked_add_u64(acct.lusd_balance, total, "LUSD balance")?;
+    ctx.state.total_lusd_supply =
+        checked_add_u64(ctx.state.total_lusd_supply, yield_paid, "LUSD supply")?;
+    recalculate_ratios(ctx);
+    Ok(())
+}
+
+/// Drops the position once both of its sides have been redeemed.
+fn retire_if_spent(state: &mut GlobalState, token_id: u64) {
+    if state
+        .yield_positions
+        .get(&token_id)
+        .is_some_and(|p| p.principal_owner.is_none() && p.yield_owner.is_none())
+    {
+        state.yield_positions.remove(&token_id);
+    }
+}
+
+/// Carves the share of `junior_share` owed to unmatured positions out of a
+/// yield distribution and credits it to them by principal. Wrapped principal
+/// ranks alongside LJUN supply, so positions receive
+/// `junior_share * wrapped / (ljun_supply + wrapped)`.
+///
+/// Returns the amount credited and the number of positions it went to.
+pub fn accrue(
+    state: &mut GlobalState,
+    height: u64,
+    junior_share: u64,
+) -> Result<(u64, u32), ExecutionError> {
+    let open = |p: &YieldPosition| p.maturity_height > height && p.yield_owner.is_some();
+    let wrapped: u128 = state
+        .yield_positions
+        .values()
+        .filter(|p| open(p))
+        .map(|p| p.principal as u128)
+        .sum();
+    if wrapped == 0 || junior_share == 0 {
+        return Ok((0, 0));
+    }
+    let share = junior_share as u128 * wrapped / (state.total_ljun_supply as u128 + wrapped);
+
+    let mut credited = 0u64;
+    let mut positions = 0u32;
+    for position in state.yield_positions.values_mut().filter(|p| open(p)) {
+        // Floors to at most `share`, which is itself at most `junior_share`
+        let portion = (share * position.principal as u128 / wrapped) as u64;
+        position.accrued_yield = checked_add_u64(position.accrued_yield, portion, "Accrued yield")?;
+        credited = checked_add_u64(credited, portion, "Accrued yield")?;
+        positions += 1;
+    }
+    Ok((credited, positions))
+}
+
+pub fn wrap(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    amount: u64,
+    maturity_blocks: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    if amount == 0 || maturity_blocks == 0 {
+        return Err(ExecutionError::invalid_argument(
+            "Amount and maturity must be non-zero",
+        ));
+    }
+    let account = ctx.state.accounts.entry(*sender).or_default();
+    if account.lusd_balance < amount {
+        return Err(ExecutionError::InsufficientBalance {
+            asset: AssetType::LUSD,
+        });
+    }
+    account.lusd_balance = checked_sub_u64(account.lusd_balance, amount, "LUSD balance")?;
+
+    let token_id = ctx.state.next_yield_token_id;
+    ctx.state.next_yield_token_id = ctx
+        .state
+        .next_yield_token_id
+        .checked_add(1)
+        .ok_or_else(|| ExecutionError::overflow("Token ID"))?;
+
+    let maturity_height = ctx.height.saturating_add(maturity_blocks);
+    ctx.state.yield_positions.insert(
+        token_id,
+        YieldPosition {
+            token_id,
+            principal: amount,
+            maturity_height,
+            issued_height: ctx.height,
+            principal_owner: Some(*sender),
+            yield_owner: Some(*sender),
+            accrued_yield: 0,
+        },
+    );
+    Ok(vec![Event::YieldTokenWrapped {
+        account: *sender,
+        token_id,
+        principal: amount,
+        maturity_height,
+    }])
+}
+
+/// Redeems the principal side at maturity. A sender holding both sides may
+/// recombine them at any time and also collects the yield accrued so far.
+pub fn unwrap(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    token_id: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    let height = ctx.height;
+    let position = position_mut(ctx.state, token_id)?;
+    if position.principal_owner != Some(*sender) {
+        return Err(ExecutionError::unauthorized(
+            "Sender does not hold the principal side",
+        ));
+    }
+    let recombined = position.yield_owner == Some(*sender);
+    if !recombined && height < position.maturity_height {
+        return Err(ExecutionError::precondition(
+            "Yield token has not reached maturity",
+        ));
+    }
+
+    let principal = position.principal;
+    position.principal_owner = None;
+    let yield_paid = if recombined {
+        position.yield_owner = None;
+        std::mem::take(&mut position.accrued_yield)
+    } else {
+        0
+    };
+    retire_if_spent(ctx.state, token_id);
+    pay_out(ctx, sender, principal, yield_paid)?;
+
+    Ok(vec![Event::YieldTokenUnwrapped {
+        account: *sender,
+        token_id,
+        principal,
+        yield_paid,
+    }])
+}
+
+pub fn claim_yield(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    token_id: u64,
+) -> Result<Vec<Event>, ExecutionError> {
+    let height = ctx.height;
+    let position = position_mut(ctx.state, token_id)?;
+    if position.yield_owner != Some(*sender) {
+        return Err(ExecutionError::unauthorized(
+            "Sender does not hold the yield side",
+        ));
+    }
+    if height < position.maturity_height {
+        return Err(ExecutionError::precondition(
+            "Yield token has not reached maturity",
+        ));
+    }
+
+    position.yield_owner = None;
+    let amount = std::mem::take(&mut position.accrued_yield);
+    retire_if_spent(ctx.state, token_id);
+    pay_out(ctx, sender, 0, amount)?;
+
+    Ok(vec![Event::YieldClaimed {
+        account: *sender,
+        token_id,
+        amount,
+    }])
+}
+
+pub fn transfer(
+    ctx: &mut ExecutionContext,
+    sender: &[u8; 32],
+    token_id: u64,
+    side: YieldTokenSide,
+    to: &[u8; 32],
+) -> Result<Vec<Event>, ExecutionError> {
+    let position = position_mut(ctx.state, token_id)?;
+    let owner = match side {
+        YieldTokenSide::Principal => &mut position.principal_owner,
+        YieldTokenSide::Yield => &mut position.yield_owner,
+    };
+    if *owner != Some(*sender) {
+        return Err(ExecutionError::unauthorized(
+            "Sender does not hold this side of the position",
+        ));
+    }
+    *owner = Some(*to);
+
+    Ok(vec![Event::YieldTokenTransferred {
+        token_id,
+        side,
+        from: *sender,
+        to: *to,
+    }])
+}
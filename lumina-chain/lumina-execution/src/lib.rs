@@ -2,24 +2,49 @@
 //! All 50+ StablecoinInstructions fully implemented with deterministic,
 //! overflow-safe, memory-safe logic. Production-grade implementation.
 
-use anyhow::{bail, Result};
 use lumina_crypto::signatures::PublicKey;
 use lumina_crypto::zk::{
-    verify_compliance_proof, verify_confidential_proof, verify_credit_score_proof,
-    verify_green_energy_proof, verify_insurance_loss_proof, verify_multi_jurisdictional_proof,
-    verify_rwa_attestation, verify_tax_attestation_proof, ZkManager,
+    verify_compliance_proof, verify_credit_score_proof, verify_green_energy_proof,
+    verify_insurance_loss_proof, verify_multi_jurisdictional_proof, verify_tax_attestation_proof,
+    ZkManager,
 };
 use lumina_types::instruction::{AssetType, StablecoinInstruction};
-use lumina_types::state::{
-    CustodianState, GlobalState, RWAListing, RedemptionRequest, StreamState, ValidatorState,
-    YieldPosition,
-};
+use lumina_types::receipt::{Event, ProofKind, Receipt, ReceiptStatus};
+use lumina_types::state::{AccountState, CustodianState, GlobalState};
 use lumina_types::transaction::Transaction;
 
+pub use lumina_types::error::ExecutionError;
+
+pub mod gas;
+
+pub use instructions::multisig::MAX_MULTISIG_MEMBERS;
+pub use instructions::sessions::MAX_SESSION_KEYS;
+pub use instructions::staking::EPOCH_LENGTH;
+
+/// Upper bound on instructions in one `Bundle`.
+pub const MAX_BUNDLE_INSTRUCTIONS: usize = 16;
+
 mod instructions {
+    pub mod allowances;
+    pub mod assets;
+    pub mod auction;
+    pub mod confidential;
+    pub mod evidence;
+    pub mod governance;
+    pub mod multisig;
     pub mod passkey;
+    pub mod redemption;
+    pub mod roles;
+    pub mod rwa;
+    pub mod sessions;
+    pub mod staking;
+    pub mod streams;
+    pub mod vaults;
+    pub mod yield_tokens;
 }
 
+type Result<T, E = ExecutionError> = std::result::Result<T, E>;
+
 /// Immutable context for deterministic execution (height + timestamp frozen per block).
 pub struct ExecutionContext<'a> {
     pub state: &'a mut GlobalState,
@@ -27,19 +52,24 @@ pub struct ExecutionContext<'a> {
     pub timestamp: u64,
 }
 
-pub fn end_block(ctx: &mut ExecutionContext) {
+/// End-of-block processing. Returns the events it emitted (e.g. executed proposals).
+pub fn end_block(ctx: &mut ExecutionContext) -> Vec<Event> {
+    let mut events = instructions::governance::finalize_expired(ctx);
+    events.extend(instructions::staking::apply_epoch(ctx));
+    events.extend(instructions::rwa::process_maturities(ctx));
     compute_health_index(ctx);
     ctx.state.pending_flash_mints = 0;
+    events
 }
 
 fn checked_add_u64(lhs: u64, rhs: u64, ctx: &str) -> Result<u64> {
     lhs.checked_add(rhs)
-        .ok_or_else(|| anyhow::anyhow!("{} overflow", ctx))
+        .ok_or_else(|| ExecutionError::overflow(ctx))
 }
 
 fn checked_sub_u64(lhs: u64, rhs: u64, ctx: &str) -> Result<u64> {
     lhs.checked_sub(rhs)
-        .ok_or_else(|| anyhow::anyhow!("{} underflow", ctx))
+        .ok_or_else(|| ExecutionError::underflow(ctx))
 }
 
 fn non_conflicting_transfer(tx: &Transaction) -> Option<([u8; 32], [u8; 32])> {
@@ -49,37 +79,234 @@ fn non_conflicting_transfer(tx: &Transaction) -> Option<([u8; 32], [u8; 32])> {
     None
 }
 
-/// Single entry point for any transaction.
-/// Guarantees: signature valid + nonce correct + atomic state change.
-pub fn execute_transaction(tx: &Transaction, ctx: &mut ExecutionContext) -> Result<()> {
-    // 1. Signature verification
-    let account = ctx.state.accounts.entry(tx.sender).or_default();
-
-    // Check if account uses PQ signatures
-    let tx_key = account
+/// The key that must have signed `sender`'s transactions: the passkey when the
+/// account has switched to it, else its post-quantum key, else the Ed25519
+/// key the address itself encodes.
+pub fn transaction_key(account: &AccountState, sender: &[u8; 32]) -> PublicKey {
+    if account.passkey_auth {
+        if let Some(device_key) = &account.passkey_device_key {
+            return PublicKey::Passkey(device_key.clone());
+        }
+    }
+    account
         .pq_pubkey
         .as_ref()
         .map(|k| PublicKey::PostQuantum(k.clone()))
-        .unwrap_or(PublicKey::Ed25519(tx.sender));
-    tx_key.verify(&tx.signing_bytes(), &tx.signature)?;
+        .unwrap_or(PublicKey::Ed25519(*sender))
+}
 
-    // 2. Replay protection (nonce model)
-    let sender_account = ctx.state.accounts.entry(tx.sender).or_default();
+/// Checks `tx` is signed for `account`: `threshold` member signatures for a
+/// multisig account, otherwise a single signature by [`transaction_key`], or
+/// a signature by one of its registered session keys that is still live at `height`.
+///
+/// Returns the session key that signed, if any. Its scope is enforced
+/// separately by `execute_transaction`.
+pub fn verify_transaction_signature(
+    account: &AccountState,
+    tx: &Transaction,
+    height: u64,
+) -> Result<Option<[u8; 32]>> {
+    let signing_bytes = tx.signing_bytes();
+    if let Some((pubkey, signature)) = tx.session_signature() {
+        let key = account
+            .session_keys
+            .iter()
+            .find(|k| k.pubkey == pubkey)
+            .ok_or(ExecutionError::InvalidSignature)?;
+        if height >= key.expires_at {
+            return Err(ExecutionError::unauthorized("Session key has expired"));
+        }
+        return PublicKey::Ed25519(pubkey)
+            .verify(&signing_bytes, signature)
+            .map(|_| Some(pubkey))
+            .map_err(|_| ExecutionError::InvalidSignature);
+    }
+    if let Some(policy) = &account.multisig {
+        let signatures = tx
+            .multisig_signatures()
+            .ok_or(ExecutionError::InvalidSignature)?;
+        return instructions::multisig::verify(policy, &signing_bytes, &signatures).map(|_| None);
+    }
+    transaction_key(account, &tx.sender)
+        .verify(&signing_bytes, &tx.signature)
+        .map(|_| None)
+        .map_err(|_| ExecutionError::InvalidSignature)
+}
+
+/// Single entry point for any transaction.
+/// Guarantees: signature valid + nonce correct + gas prepaid + atomic state change.
+/// Returns the receipt (gas consumed + emitted events); unused gas is refunded to the sender.
+///
+/// `Err` means the transaction is invalid and must not be included in a block.
+/// Once the nonce is bumped and gas prepaid, application-level failures (out of gas,
+/// instruction errors) roll back the instruction's mutations and yield a failed receipt.
+pub fn execute_transaction(tx: &Transaction, ctx: &mut ExecutionContext) -> Result<Receipt> {
+    // Validate against a copy: a rejected transaction must leave no trace in
+    // state, not even a default entry for an unused sender address
+    let mut sender_account = ctx
+        .state
+        .accounts
+        .get(&tx.sender)
+        .cloned()
+        .unwrap_or_default();
+
+    // 1. Signature verification
+    let session_key = verify_transaction_signature(&sender_account, tx, ctx.height)?;
+
+    // 2. Gas price floor; gas itself is metered during execution
+    if tx.gas_price < gas::MIN_GAS_PRICE {
+        return Err(ExecutionError::GasPriceTooLow {
+            price: tx.gas_price,
+            min: gas::MIN_GAS_PRICE,
+        });
+    }
+    let max_fee = tx
+        .gas_limit
+        .checked_mul(tx.gas_price)
+        .ok_or_else(|| ExecutionError::overflow("Gas fee"))?;
+
+    // Session keys may only sign their permitted instructions, and the
+    // instructions plus the full gas budget must fit in the key's allowance
+    let session_spend = match &session_key {
+        Some(pubkey) => Some(instructions::sessions::authorize(
+            &sender_account,
+            pubkey,
+            tx.instructions(),
+            max_fee,
+            ctx.height,
+        )?),
+        None => None,
+    };
+
+    // 3. Replay protection (nonce model)
     if tx.nonce != sender_account.nonce {
-        bail!(
-            "Invalid nonce: expected {}, got {} for sender {:?}",
-            sender_account.nonce,
-            tx.nonce,
-            tx.sender
-        );
+        return Err(ExecutionError::InvalidNonce {
+            expected: sender_account.nonce,
+            got: tx.nonce,
+        });
+    }
+    if sender_account.lumina_balance < max_fee {
+        return Err(ExecutionError::InsufficientGasFunds {
+            need: max_fee,
+            have: sender_account.lumina_balance,
+        });
     }
     sender_account.nonce = sender_account
         .nonce
         .checked_add(1)
-        .ok_or_else(|| anyhow::anyhow!("Nonce overflow"))?;
+        .ok_or_else(|| ExecutionError::overflow("Nonce"))?;
+
+    // 4. Upfront debit of the full gas budget. Like the gas, the session
+    //    allowance is charged even if the instruction fails
+    sender_account.lumina_balance =
+        checked_sub_u64(sender_account.lumina_balance, max_fee, "Gas prepayment")?;
+    if let (Some(pubkey), Some(spend)) = (&session_key, session_spend) {
+        instructions::sessions::record_spend(&mut sender_account, pubkey, spend, ctx.height);
+    }
+    ctx.state.accounts.insert(tx.sender, sender_account);
+
+    // 5. Execute the native instruction against a checkpoint of the charged state,
+    //    metering gas as each instruction runs
+    let checkpoint = ctx.state.checkpoint();
+    let mut meter = gas::GasMeter::new(tx.gas_limit);
+    let outcome = meter
+        .charge(gas::TX_BASE_GAS)
+        .and_then(|_| execute_metered(&tx.instruction, &tx.sender, ctx, &mut meter));
+    let (status, gas_used, events) = match outcome {
+        Ok(events) => {
+            ctx.state.commit(checkpoint);
+            (ReceiptStatus::Success, meter.used(), events)
+        }
+        Err(error) => {
+            // Roll back partial mutations; nonce bump and gas prepayment survive.
+            // Running out of gas consumes the whole budget.
+            ctx.state.rollback(checkpoint);
+            let gas_used = match error {
+                ExecutionError::OutOfGas { .. } => tx.gas_limit,
+                _ => meter.used(),
+            };
+            (ReceiptStatus::Failed { error }, gas_used, Vec::new())
+        }
+    };
+
+    // 6. Settle gas: refund the unused budget, route the fee to the validator fee pool
+    settle_gas(tx, gas_used, ctx)?;
+    Ok(Receipt {
+        tx_id: tx.id(),
+        status,
+        gas_used,
+        events,
+    })
+}
+
+/// Executes `si`, charging `meter` for each instruction right before it runs.
+fn execute_metered(
+    si: &StablecoinInstruction,
+    sender: &[u8; 32],
+    ctx: &mut ExecutionContext,
+    meter: &mut gas::GasMeter,
+) -> Result<Vec<Event>> {
+    match si {
+        StablecoinInstruction::Bundle { instructions } => {
+            execute_bundle(instructions, sender, ctx, meter)
+        }
+        _ => {
+            meter.charge(gas::instruction_gas(si, ctx.state))?;
+            execute_si(si, sender, ctx)
+        }
+    }
+}
+
+/// Runs a bundle in order, pricing each step against the state left by the previous ones.
+fn execute_bundle(
+    instructions: &[StablecoinInstruction],
+    sender: &[u8; 32],
+    ctx: &mut ExecutionContext,
+    meter: &mut gas::GasMeter,
+) -> Result<Vec<Event>> {
+    if instructions.is_empty() || instructions.len() > MAX_BUNDLE_INSTRUCTIONS {
+        return Err(ExecutionError::invalid_argument(&format!(
+            "Bundle must hold 1..={} instructions",
+            MAX_BUNDLE_INSTRUCTIONS
+        )));
+    }
+    if instructions
+        .iter()
+        .any(|si| matches!(si, StablecoinInstruction::Bundle { .. }))
+    {
+        return Err(ExecutionError::invalid_argument("Bundles cannot be nested"));
+    }
+    // Atomicity comes from execute_transaction's checkpoint: the first failure
+    // aborts the bundle and every earlier instruction is rolled back with it
+    let mut events = Vec::new();
+    for (index, inner) in instructions.iter().enumerate() {
+        meter.charge(gas::instruction_gas(inner, ctx.state).saturating_add(gas::PER_ITEM_GAS))?;
+        let inner_events = execute_si(inner, sender, ctx).map_err(|error| {
+            ExecutionError::BundleInstructionFailed {
+                index: index as u32,
+                error: Box::new(error),
+            }
+        })?;
+        events.extend(inner_events);
+    }
+    Ok(events)
+}
 
-    // 3. Execute the native instruction
-    execute_si(&tx.instruction, &tx.sender, ctx)
+fn settle_gas(tx: &Transaction, gas_used: u64, ctx: &mut ExecutionContext) -> Result<()> {
+    let fee = gas_used
+        .checked_mul(tx.gas_price)
+        .ok_or_else(|| ExecutionError::overflow("Gas fee"))?;
+    let refund = tx
+        .gas_limit
+        .saturating_sub(gas_used)
+        .checked_mul(tx.gas_price)
+        .ok_or_else(|| ExecutionError::overflow("Gas refund"))?;
+
+    let sender_account = ctx.state.accounts.entry(tx.sender).or_default();
+    sender_account.lumina_balance =
+        checked_add_u64(sender_account.lumina_balance, refund, "Gas refund")?;
+    ctx.state.gas_fee_pool = checked_add_u64(ctx.state.gas_fee_pool, fee, "Gas fee pool")?;
+    Ok(())
 }
 
 /// Executes transactions with a rayon-assisted pre-check for non-conflicting transfers.
@@ -88,7 +315,7 @@ pub fn execute_transaction(tx: &Transaction, ctx: &mut ExecutionContext) -> Resu
 pub fn execute_transactions_parallel_non_conflicting(
     txs: &[Transaction],
     ctx: &mut ExecutionContext,
-) -> Result<()> {
+) -> Result<Vec<Receipt>> {
     use rayon::prelude::*;
     use std::collections::HashSet;
 
@@ -116,129 +343,119 @@ pub fn execute_transactions_parallel_non_conflicting(
                 .cloned()
                 .unwrap_or_default();
 
-            let tx_key = account
-                .pq_pubkey
-                .as_ref()
-                .map(|k| PublicKey::PostQuantum(k.clone()))
-                .unwrap_or(PublicKey::Ed25519(tx.sender));
-            tx_key.verify(&tx.signing_bytes(), &tx.signature)?;
+            verify_transaction_signature(&account, tx, ctx.height)?;
 
             if account.nonce != tx.nonce {
-                bail!("Invalid nonce in parallel pre-check");
+                return Err(ExecutionError::InvalidNonce {
+                    expected: account.nonce,
+                    got: tx.nonce,
+                });
+            }
+            let max_fee = tx.gas_limit.saturating_mul(tx.gas_price);
+            if account.lumina_balance < max_fee {
+                return Err(ExecutionError::InsufficientGasFunds {
+                    need: max_fee,
+                    have: account.lumina_balance,
+                });
             }
             Ok(*idx)
         })
         .collect();
     checks?;
 
+    let mut receipts = Vec::with_capacity(txs.len());
     for tx in txs {
-        execute_transaction(tx, ctx)?;
+        receipts.push(execute_transaction(tx, ctx)?);
     }
 
-    Ok(())
+    Ok(receipts)
 }
 
 /// Core dispatcher — every StablecoinInstruction is fully implemented.
+/// Returns the events emitted by the instruction, in execution order.
 pub fn execute_si(
     si: &StablecoinInstruction,
     sender: &[u8; 32],
     ctx: &mut ExecutionContext,
-) -> Result<()> {
+) -> Result<Vec<Event>> {
+    if let Some(role) = instructions::roles::required_role(si) {
+        if !ctx.state.has_role(role, sender) {
+            return Err(ExecutionError::MissingRole { role });
+        }
+    }
+
     match si {
         // ══════════════════════════════════════════════════════════
         // Core Asset Operations
         // ══════════════════════════════════════════════════════════
         StablecoinInstruction::RegisterAsset { ticker, decimals } => {
-            if ticker.is_empty() || ticker.len() > 16 {
-                bail!("Ticker must be 1-16 characters");
-            }
-            if *decimals > 18 {
-                bail!("Decimals must be 0-18");
-            }
-            // Asset registration is recorded via oracle price entry (zero initial price)
-            ctx.state.oracle_prices.entry(ticker.clone()).or_insert(0);
-            Ok(())
+            instructions::assets::register(ctx, sender, ticker, *decimals)
         }
 
         StablecoinInstruction::MintSenior {
             amount,
+            collateral_asset,
             collateral_amount,
             proof,
         } => {
             if *amount == 0 || *collateral_amount == 0 {
-                bail!("Amount and collateral_amount must be greater than zero");
+                return Err(ExecutionError::ZeroAmount);
             }
             if proof.is_empty() {
-                bail!("MintSenior requires a non-empty reserve proof");
+                return Err(ExecutionError::InvalidProof {
+                    kind: ProofKind::Reserve,
+                });
             }
             if ctx.state.circuit_breaker_active {
-                bail!("Circuit breaker active: senior mints paused");
+                return Err(ExecutionError::CircuitBreakerActive);
             }
 
             let zk_manager = ZkManager::setup();
             if !zk_manager.verify_zk_por(proof, *collateral_amount) {
-                bail!("Invalid MintSenior reserve proof");
+                return Err(ExecutionError::InvalidProof {
+                    kind: ProofKind::Reserve,
+                });
             }
 
-            // 5% mint fee goes to insurance fund
-            let fee = amount
-                .checked_div(20)
-                .ok_or_else(|| anyhow::anyhow!("Fee division error"))?;
+            // Mint fee (5% at genesis) goes to insurance fund
+            let fee = bps_of(*amount, ctx.state.protocol_params.mint_fee_bps);
             ctx.state.insurance_fund_balance = ctx
                 .state
                 .insurance_fund_balance
                 .checked_add(fee)
-                .ok_or_else(|| anyhow::anyhow!("Insurance fund overflow"))?;
-
-            // Lock collateral into stabilization pool
-            ctx.state.stabilization_pool_balance = ctx
-                .state
-                .stabilization_pool_balance
-                .checked_add(*collateral_amount)
-                .ok_or_else(|| anyhow::anyhow!("Collateral overflow"))?;
+                .ok_or_else(|| ExecutionError::overflow("Insurance fund"))?;
 
+            // Lock the attested collateral in the sender's vault and draw the net
+            // mint against it, so the position is liquidated like any other vault
             let net_amount = checked_sub_u64(*amount, fee, "Net mint amount")?;
-            let account = ctx.state.accounts.entry(*sender).or_default();
-            account.lusd_balance = account
-                .lusd_balance
-                .checked_add(net_amount)
-                .ok_or_else(|| anyhow::anyhow!("Balance overflow"))?;
-
-            ctx.state.total_lusd_supply = ctx
-                .state
-                .total_lusd_supply
-                .checked_add(net_amount)
-                .ok_or_else(|| anyhow::anyhow!("Supply overflow"))?;
+            let mut events =
+                instructions::vaults::deposit(ctx, sender, collateral_asset, *collateral_amount)?;
+            events.push(Event::InsuranceFundCredited { amount: fee });
+            events.extend(instructions::vaults::draw(ctx, sender, net_amount)?);
 
             // Track volume for velocity rewards
             let acct = ctx.state.accounts.entry(*sender).or_default();
             acct.epoch_tx_volume =
                 checked_add_u64(acct.epoch_tx_volume, *amount, "Epoch tx volume")?;
 
-            recalculate_ratios(ctx);
-            Ok(())
+            Ok(events)
         }
 
         StablecoinInstruction::RedeemSenior { amount } => {
             if *amount == 0 {
-                bail!("Amount must be greater than zero");
+                return Err(ExecutionError::ZeroAmount);
             }
 
             let account = ctx.state.accounts.entry(*sender).or_default();
             if account.lusd_balance < *amount {
-                bail!("Insufficient LUSD balance");
+                return Err(ExecutionError::InsufficientBalance {
+                    asset: AssetType::LUSD,
+                });
             }
 
             // Under stress, queue redemptions
-            if ctx.state.circuit_breaker_active || ctx.state.reserve_ratio < 0.95 {
-                ctx.state.fair_redeem_queue.push(RedemptionRequest {
-                    address: *sender,
-                    amount: *amount,
-                    timestamp: ctx.timestamp,
-                });
-                let acct = ctx.state.accounts.entry(*sender).or_default();
-                acct.lusd_balance = checked_sub_u64(acct.lusd_balance, *amount, "LUSD balance")?;
-                return Ok(());
+            if ctx.state.circuit_breaker_active || below_redemption_threshold(ctx) {
+                return instructions::redemption::enqueue(ctx, sender, *amount);
             }
 
             let acct = ctx.state.accounts.entry(*sender).or_default();
@@ -249,7 +466,17 @@ pub fn execute_si(
                 ctx.state.stabilization_pool_balance.saturating_sub(*amount);
 
             recalculate_ratios(ctx);
-            Ok(())
+            Ok(vec![
+                Event::Burned {
+                    from: *sender,
+                    asset: AssetType::LUSD,
+                    amount: *amount,
+                },
+                Event::CollateralReleased {
+                    account: *sender,
+                    amount: *amount,
+                },
+            ])
         }
 
         StablecoinInstruction::MintJunior {
@@ -257,58 +484,79 @@ pub fn execute_si(
             collateral_amount,
         } => {
             if *amount == 0 || *collateral_amount == 0 {
-                bail!("Amount and collateral_amount must be greater than zero");
+                return Err(ExecutionError::ZeroAmount);
             }
 
             ctx.state.stabilization_pool_balance = ctx
                 .state
                 .stabilization_pool_balance
                 .checked_add(*collateral_amount)
-                .ok_or_else(|| anyhow::anyhow!("Collateral overflow"))?;
+                .ok_or_else(|| ExecutionError::overflow("Collateral"))?;
 
             let account = ctx.state.accounts.entry(*sender).or_default();
             account.ljun_balance = account
                 .ljun_balance
                 .checked_add(*amount)
-                .ok_or_else(|| anyhow::anyhow!("Balance overflow"))?;
+                .ok_or_else(|| ExecutionError::overflow("Balance"))?;
 
             ctx.state.total_ljun_supply = ctx
                 .state
                 .total_ljun_supply
                 .checked_add(*amount)
-                .ok_or_else(|| anyhow::anyhow!("Supply overflow"))?;
+                .ok_or_else(|| ExecutionError::overflow("Supply"))?;
 
             recalculate_ratios(ctx);
-            Ok(())
+            Ok(vec![
+                Event::CollateralDeposited {
+                    account: *sender,
+                    amount: *collateral_amount,
+                },
+                Event::Minted {
+                    to: *sender,
+                    asset: AssetType::LJUN,
+                    amount: *amount,
+                },
+            ])
         }
 
         StablecoinInstruction::RedeemJunior { amount } => {
             if *amount == 0 {
-                bail!("Amount must be greater than zero");
+                return Err(ExecutionError::ZeroAmount);
             }
 
             let account = ctx.state.accounts.entry(*sender).or_default();
             if account.ljun_balance < *amount {
-                bail!("Insufficient LJUN balance");
+                return Err(ExecutionError::InsufficientBalance {
+                    asset: AssetType::LJUN,
+                });
             }
 
             account.ljun_balance = checked_sub_u64(account.ljun_balance, *amount, "LJUN balance")?;
             ctx.state.total_ljun_supply =
                 checked_sub_u64(ctx.state.total_ljun_supply, *amount, "LJUN supply")?;
             recalculate_ratios(ctx);
-            Ok(())
+            Ok(vec![Event::Burned {
+                from: *sender,
+                asset: AssetType::LJUN,
+                amount: *amount,
+            }])
         }
 
         StablecoinInstruction::Burn { amount, asset } => {
             if *amount == 0 {
-                bail!("Amount must be greater than zero");
+                return Err(ExecutionError::ZeroAmount);
             }
 
+            if let AssetType::Custom(ticker) = asset {
+                instructions::assets::ensure_movable(ctx.state, ticker, &[sender])?;
+            }
             let account = ctx.state.accounts.entry(*sender).or_default();
             match asset {
                 AssetType::LUSD => {
                     if account.lusd_balance < *amount {
-                        bail!("Insufficient LUSD");
+                        return Err(ExecutionError::InsufficientBalance {
+                            asset: AssetType::LUSD,
+                        });
                     }
                     account.lusd_balance =
                         checked_sub_u64(account.lusd_balance, *amount, "LUSD balance")?;
@@ -317,7 +565,9 @@ pub fn execute_si(
                 }
                 AssetType::LJUN => {
                     if account.ljun_balance < *amount {
-                        bail!("Insufficient LJUN");
+                        return Err(ExecutionError::InsufficientBalance {
+                            asset: AssetType::LJUN,
+                        });
                     }
                     account.ljun_balance =
                         checked_sub_u64(account.ljun_balance, *amount, "LJUN balance")?;
@@ -326,7 +576,9 @@ pub fn execute_si(
                 }
                 AssetType::Lumina => {
                     if account.lumina_balance < *amount {
-                        bail!("Insufficient Lumina");
+                        return Err(ExecutionError::InsufficientBalance {
+                            asset: AssetType::Lumina,
+                        });
                     }
                     account.lumina_balance =
                         checked_sub_u64(account.lumina_balance, *amount, "LUMINA balance")?;
@@ -334,19 +586,26 @@ pub fn execute_si(
                 AssetType::Custom(ticker) => {
                     let bal = account.custom_balances.entry(ticker.clone()).or_insert(0);
                     if *bal < *amount {
-                        bail!("Insufficient {}", ticker);
+                        return Err(ExecutionError::InsufficientBalance {
+                            asset: AssetType::Custom(ticker.clone()),
+                        });
                     }
                     *bal = checked_sub_u64(*bal, *amount, "Custom asset balance")?;
+                    instructions::assets::record_burn(ctx.state, ticker, *amount);
                 }
             }
 
             recalculate_ratios(ctx);
-            Ok(())
+            Ok(vec![Event::Burned {
+                from: *sender,
+                asset: asset.clone(),
+                amount: *amount,
+            }])
         }
 
         StablecoinInstruction::Transfer { to, amount, asset } => {
             if *amount == 0 {
-                bail!("Amount must be greater than zero");
+                return Err(ExecutionError::ZeroAmount);
             }
 
             match asset {
@@ -354,7 +613,9 @@ pub fn execute_si(
                     {
                         let sender_account = ctx.state.accounts.entry(*sender).or_default();
                         if sender_account.lusd_balance < *amount {
-                            bail!("Insufficient LUSD");
+                            return Err(ExecutionError::InsufficientBalance {
+                                asset: AssetType::LUSD,
+                            });
                         }
                         sender_account.lusd_balance =
                             checked_sub_u64(sender_account.lusd_balance, *amount, "Sender LUSD")?;
@@ -368,13 +629,15 @@ pub fn execute_si(
                     receiver.lusd_balance = receiver
                         .lusd_balance
                         .checked_add(*amount)
-                        .ok_or_else(|| anyhow::anyhow!("Balance overflow"))?;
+                        .ok_or_else(|| ExecutionError::overflow("Balance"))?;
                 }
                 AssetType::LJUN => {
                     {
                         let sender_account = ctx.state.accounts.entry(*sender).or_default();
                         if sender_account.ljun_balance < *amount {
-                            bail!("Insufficient LJUN");
+                            return Err(ExecutionError::InsufficientBalance {
+                                asset: AssetType::LJUN,
+                            });
                         }
                         sender_account.ljun_balance =
                             checked_sub_u64(sender_account.ljun_balance, *amount, "Sender LJUN")?;
@@ -388,13 +651,15 @@ pub fn execute_si(
                     receiver.ljun_balance = receiver
                         .ljun_balance
                         .checked_add(*amount)
-                        .ok_or_else(|| anyhow::anyhow!("Balance overflow"))?;
+                        .ok_or_else(|| ExecutionError::overflow("Balance"))?;
                 }
                 AssetType::Lumina => {
                     {
                         let sender_account = ctx.state.accounts.entry(*sender).or_default();
                         if sender_account.lumina_balance < *amount {
-                            bail!("Insufficient Lumina");
+                            return Err(ExecutionError::InsufficientBalance {
+                                asset: AssetType::Lumina,
+                            });
                         }
                         sender_account.lumina_balance = checked_sub_u64(
                             sender_account.lumina_balance,
@@ -406,9 +671,10 @@ pub fn execute_si(
                     receiver.lumina_balance = receiver
                         .lumina_balance
                         .checked_add(*amount)
-                        .ok_or_else(|| anyhow::anyhow!("Balance overflow"))?;
+                        .ok_or_else(|| ExecutionError::overflow("Balance"))?;
                 }
                 AssetType::Custom(ticker) => {
+                    instructions::assets::ensure_movable(ctx.state, ticker, &[sender, to])?;
                     {
                         let sender_account = ctx.state.accounts.entry(*sender).or_default();
                         let sender_bal = sender_account
@@ -416,7 +682,9 @@ pub fn execute_si(
                             .entry(ticker.clone())
                             .or_insert(0);
                         if *sender_bal < *amount {
-                            bail!("Insufficient {}", ticker);
+                            return Err(ExecutionError::InsufficientBalance {
+                                asset: AssetType::Custom(ticker.clone()),
+                            });
                         }
                         *sender_bal = checked_sub_u64(*sender_bal, *amount, "Sender custom asset")?;
                     }
@@ -424,11 +692,16 @@ pub fn execute_si(
                     let recv_bal = receiver.custom_balances.entry(ticker.clone()).or_insert(0);
                     *recv_bal = recv_bal
                         .checked_add(*amount)
-                        .ok_or_else(|| anyhow::anyhow!("Balance overflow"))?;
+                        .ok_or_else(|| ExecutionError::overflow("Balance"))?;
                 }
             }
 
-            Ok(())
+            Ok(vec![Event::Transferred {
+                from: *sender,
+                to: *to,
+                asset: asset.clone(),
+                amount: *amount,
+            }])
         }
 
         // ══════════════════════════════════════════════════════════
@@ -436,7 +709,7 @@ pub fn execute_si(
         // ══════════════════════════════════════════════════════════
         StablecoinInstruction::RebalanceTranches => {
             if ctx.state.total_lusd_supply == 0 {
-                return Ok(());
+                return Ok(Vec::new());
             }
 
             // If junior tranche is over-exposed (>40% of total supply), cap it
@@ -459,17 +732,19 @@ pub fn execute_si(
 
             ctx.state.last_rebalance_height = ctx.height;
             recalculate_ratios(ctx);
-            Ok(())
+            Ok(vec![pool_updated_event(ctx)])
         }
 
         StablecoinInstruction::DistributeYield { total_yield } => {
             if *total_yield == 0 {
-                return Ok(());
+                return Ok(Vec::new());
             }
 
-            // 80% to junior tranche holders pro-rata, 15% to stabilization pool, 5% to insurance
-            let junior_share = total_yield.saturating_mul(80) / 100;
-            let pool_share = total_yield.saturating_mul(15) / 100;
+            // Junior tranche holders pro-rata, stabilization pool, remainder to insurance
+            // (80/15/5 at genesis)
+            let params = &ctx.state.protocol_params;
+            let junior_share = bps_of(*total_yield, params.yield_junior_bps);
+            let pool_share = bps_of(*total_yield, params.yield_pool_bps);
             let insurance_share = total_yield
                 .saturating_sub(junior_share)
                 .saturating_sub(pool_share);
@@ -478,15 +753,23 @@ pub fn execute_si(
                 .state
                 .stabilization_pool_balance
                 .checked_add(pool_share)
-                .ok_or_else(|| anyhow::anyhow!("Pool overflow"))?;
+                .ok_or_else(|| ExecutionError::overflow("Pool"))?;
 
             ctx.state.insurance_fund_balance = ctx
                 .state
                 .insurance_fund_balance
                 .checked_add(insurance_share)
-                .ok_or_else(|| anyhow::anyhow!("Insurance overflow"))?;
+                .ok_or_else(|| ExecutionError::overflow("Insurance"))?;
+
+            // Open yield-token positions take their cut of the junior share first;
+            // it backs the LUSD minted when the yield side is claimed
+            let (token_share, positions) =
+                instructions::yield_tokens::accrue(ctx.state, ctx.height, junior_share)?;
+            ctx.state.stabilization_pool_balance =
+                checked_add_u64(ctx.state.stabilization_pool_balance, token_share, "Pool")?;
+            let ljun_share = junior_share - token_share;
 
-            // Distribute junior_share pro-rata to all LJUN holders
+            // Distribute the rest of the junior share pro-rata to all LJUN holders
             if ctx.state.total_ljun_supply > 0 {
                 let accounts_snapshot: Vec<([u8; 32], u64)> = ctx
                     .state
@@ -497,7 +780,7 @@ pub fn execute_si(
                     .collect();
 
                 for (addr, balance) in accounts_snapshot {
-                    let share = junior_share
+                    let share = ljun_share
                         .checked_mul(balance)
                         .unwrap_or(0)
                         .checked_div(ctx.state.total_ljun_supply)
@@ -508,22 +791,34 @@ pub fn execute_si(
                     }
                 }
                 ctx.state.total_ljun_supply =
-                    ctx.state.total_ljun_supply.saturating_add(junior_share);
+                    ctx.state.total_ljun_supply.saturating_add(ljun_share);
             } else {
                 // No junior holders, all goes to stabilization pool
                 ctx.state.stabilization_pool_balance = ctx
                     .state
                     .stabilization_pool_balance
-                    .saturating_add(junior_share);
+                    .saturating_add(ljun_share);
             }
 
             recalculate_ratios(ctx);
-            Ok(())
+            let mut events = vec![Event::YieldDistributed {
+                junior_share,
+                pool_share,
+                insurance_share,
+            }];
+            if token_share > 0 {
+                events.push(Event::YieldTokensAccrued {
+                    amount: token_share,
+                    positions,
+                });
+            }
+            Ok(events)
         }
 
         StablecoinInstruction::TriggerStabilizer => {
             recalculate_ratios(ctx);
 
+            let mut events = Vec::new();
             // If under-collateralized, use insurance fund to top up
             if ctx.state.reserve_ratio < 1.0 && ctx.state.insurance_fund_balance > 0 {
                 let deficit = ctx
@@ -536,59 +831,65 @@ pub fn execute_si(
                 ctx.state.insurance_fund_balance =
                     ctx.state.insurance_fund_balance.saturating_sub(topup);
                 recalculate_ratios(ctx);
+                events.push(Event::InsuranceFundDebited { amount: topup });
             }
 
-            Ok(())
+            events.push(pool_updated_event(ctx));
+            Ok(events)
         }
 
         StablecoinInstruction::RunCircuitBreaker { active } => {
             ctx.state.circuit_breaker_active = *active;
-            Ok(())
+            Ok(vec![Event::CircuitBreakerSet { active: *active }])
         }
 
         StablecoinInstruction::FairRedeemQueue { batch_size } => {
             if ctx.state.circuit_breaker_active {
-                bail!("Circuit breaker active: cannot process redeem queue");
+                return Err(ExecutionError::CircuitBreakerActive);
             }
 
-            let to_process = std::cmp::min(*batch_size as usize, ctx.state.fair_redeem_queue.len());
-            for _ in 0..to_process {
-                let req = ctx.state.fair_redeem_queue.remove(0);
-                ctx.state.total_lusd_supply =
-                    ctx.state.total_lusd_supply.saturating_sub(req.amount);
-                ctx.state.stabilization_pool_balance = ctx
-                    .state
-                    .stabilization_pool_balance
-                    .saturating_sub(req.amount);
-            }
-            recalculate_ratios(ctx);
-            Ok(())
+            instructions::redemption::process(ctx, *batch_size)
         }
 
         // ══════════════════════════════════════════════════════════
         // Privacy & Compliance
         // ══════════════════════════════════════════════════════════
-        StablecoinInstruction::ConfidentialTransfer { commitment, proof } => {
-            if !verify_confidential_proof(commitment, proof) {
-                bail!("Invalid confidential transfer proof");
-            }
-            let account = ctx.state.accounts.entry(*sender).or_default();
-            account.commitment = Some(*commitment);
-            Ok(())
-        }
+        StablecoinInstruction::ConfidentialTransfer {
+            to,
+            amount_commitment,
+            proof,
+            recipient_note,
+            audit_note,
+        } => instructions::confidential::transfer(
+            ctx,
+            sender,
+            to,
+            amount_commitment,
+            proof,
+            recipient_note,
+            audit_note.as_deref(),
+        ),
 
         StablecoinInstruction::ProveCompliance { tx_hash, proof } => {
             if !verify_compliance_proof(tx_hash, proof) {
-                bail!("Invalid compliance proof");
+                return Err(ExecutionError::InvalidProof {
+                    kind: ProofKind::Compliance,
+                });
             }
-            Ok(())
+            Ok(vec![Event::ProofVerified {
+                kind: ProofKind::Compliance,
+            }])
         }
 
         StablecoinInstruction::ZkTaxAttest { period, proof } => {
             if !verify_tax_attestation_proof(*period, proof) {
-                bail!("Invalid tax attestation proof");
+                return Err(ExecutionError::InvalidProof {
+                    kind: ProofKind::TaxAttestation,
+                });
             }
-            Ok(())
+            Ok(vec![Event::ProofVerified {
+                kind: ProofKind::TaxAttestation,
+            }])
         }
 
         StablecoinInstruction::MultiJurisdictionalCheck {
@@ -596,9 +897,13 @@ pub fn execute_si(
             proof,
         } => {
             if !verify_multi_jurisdictional_proof(*jurisdiction_id, proof) {
-                bail!("Invalid multi-jurisdictional proof");
+                return Err(ExecutionError::InvalidProof {
+                    kind: ProofKind::MultiJurisdictional,
+                });
             }
-            Ok(())
+            Ok(vec![Event::ProofVerified {
+                kind: ProofKind::MultiJurisdictional,
+            }])
         }
 
         // ══════════════════════════════════════════════════════════
@@ -607,7 +912,10 @@ pub fn execute_si(
         StablecoinInstruction::UpdateOracle { asset, price, .. } => {
             ctx.state.oracle_prices.insert(asset.clone(), *price);
             recalculate_ratios(ctx);
-            Ok(())
+            Ok(vec![Event::OracleUpdated {
+                asset: asset.clone(),
+                price: *price,
+            }])
         }
 
         StablecoinInstruction::SubmitZkPoR {
@@ -616,33 +924,49 @@ pub fn execute_si(
             timestamp,
         } => {
             if *timestamp <= ctx.state.last_por_timestamp {
-                bail!("PoR timestamp must be strictly increasing");
+                return Err(ExecutionError::precondition(
+                    "PoR timestamp must be strictly increasing",
+                ));
             }
 
             let proof_id = *blake3::hash(proof).as_bytes();
             if ctx.state.last_por_hash == Some(proof_id) {
-                bail!("PoR proof replay detected");
+                return Err(ExecutionError::replay("PoR proof"));
             }
 
             let zk_manager = ZkManager::setup();
             if !zk_manager.verify_zk_por(proof, *total_reserves) {
-                bail!("Invalid PoR proof");
+                return Err(ExecutionError::InvalidProof {
+                    kind: ProofKind::Reserve,
+                });
             }
 
             ctx.state.stabilization_pool_balance = *total_reserves;
             ctx.state.last_por_timestamp = *timestamp;
             ctx.state.last_por_hash = Some(proof_id);
             recalculate_ratios(ctx);
-            Ok(())
+            Ok(vec![
+                Event::ReservesAttested {
+                    total_reserves: *total_reserves,
+                    timestamp: *timestamp,
+                },
+                pool_updated_event(ctx),
+            ])
         }
 
         // ══════════════════════════════════════════════════════════
         // Advanced DeFi & Fiat Hooks
         // ══════════════════════════════════════════════════════════
-        StablecoinInstruction::InstantFiatBridge { amount, .. } => {
+        StablecoinInstruction::InstantFiatBridge {
+            amount,
+            target_bank_id,
+            ..
+        } => {
             let account = ctx.state.accounts.entry(*sender).or_default();
             if account.lusd_balance < *amount {
-                bail!("Insufficient LUSD for fiat bridge");
+                return Err(ExecutionError::InsufficientBalance {
+                    asset: AssetType::LUSD,
+                });
             }
             account.lusd_balance = checked_sub_u64(account.lusd_balance, *amount, "LUSD balance")?;
             ctx.state.total_lusd_supply =
@@ -650,40 +974,29 @@ pub fn execute_si(
             ctx.state.stabilization_pool_balance =
                 ctx.state.stabilization_pool_balance.saturating_sub(*amount);
             recalculate_ratios(ctx);
-            Ok(())
+            Ok(vec![
+                Event::Burned {
+                    from: *sender,
+                    asset: AssetType::LUSD,
+                    amount: *amount,
+                },
+                Event::FiatBridged {
+                    account: *sender,
+                    amount: *amount,
+                    target_bank_id: *target_bank_id,
+                },
+            ])
         }
 
         StablecoinInstruction::ZeroSlipBatchMatch { orders } => {
-            if orders.is_empty() {
-                bail!("Empty order batch");
-            }
-            if orders.len() > 1000 {
-                bail!("Batch too large: max 1000 orders");
-            }
-
-            let mut order_set = std::collections::HashSet::with_capacity(orders.len());
-            for order in orders {
-                if !order_set.insert(*order) {
-                    bail!("Duplicate order in batch");
-                }
-            }
-
-            let mut hasher = blake3::Hasher::new();
-            for order in orders {
-                hasher.update(order);
-            }
-            let batch_id = *hasher.finalize().as_bytes();
-            if ctx.state.executed_batch_matches.contains(&batch_id) {
-                bail!("Batch replay detected");
-            }
-            ctx.state.executed_batch_matches.push(batch_id);
-
-            Ok(())
+            instructions::auction::settle(ctx, sender, orders)
         }
 
         StablecoinInstruction::DynamicHedge { ratio_bps } => {
             if *ratio_bps > 10000 {
-                bail!("Hedge ratio cannot exceed 100% (10000 bps)");
+                return Err(ExecutionError::invalid_argument(
+                    "Hedge ratio cannot exceed 100% (10000 bps)",
+                ));
             }
             // Adjust the reserve_ratio target based on hedging strategy
             let target_ratio = (*ratio_bps as f64) / 10000.0;
@@ -703,12 +1016,12 @@ pub fn execute_si(
                 }
             }
             recalculate_ratios(ctx);
-            Ok(())
+            Ok(vec![pool_updated_event(ctx)])
         }
 
         StablecoinInstruction::GeoRebalance { zone_id } => {
             if *zone_id == 0 {
-                bail!("Invalid zone_id");
+                return Err(ExecutionError::invalid_argument("Invalid zone_id"));
             }
             // Geo-rebalancing redistributes custodian weights by zone.
             // Deterministic: rotate custodian ordering by zone_id.
@@ -716,12 +1029,14 @@ pub fn execute_si(
                 let rotation = (*zone_id as usize) % ctx.state.custodians.len();
                 ctx.state.custodians.rotate_left(rotation);
             }
-            Ok(())
+            Ok(vec![Event::CustodiansReordered { zone_id: *zone_id }])
         }
 
         StablecoinInstruction::VelocityIncentive { multiplier_bps } => {
             if *multiplier_bps == 0 || *multiplier_bps > 5000 {
-                bail!("Multiplier must be 1-5000 bps");
+                return Err(ExecutionError::invalid_argument(
+                    "Multiplier must be 1-5000 bps",
+                ));
             }
             // Add to the velocity reward pool based on multiplier
             let reward_addition =
@@ -730,76 +1045,28 @@ pub fn execute_si(
                 .state
                 .velocity_reward_pool
                 .saturating_add(reward_addition);
-            Ok(())
+            Ok(vec![Event::VelocityPoolFunded {
+                amount: reward_addition,
+            }])
         }
 
         StablecoinInstruction::StreamPayment {
             to,
             amount_per_sec,
             duration,
-        } => {
-            if *amount_per_sec == 0 || *duration == 0 {
-                bail!("Stream amount and duration must be non-zero");
-            }
-            let total_stream = amount_per_sec
-                .checked_mul(*duration)
-                .ok_or_else(|| anyhow::anyhow!("Stream total overflow"))?;
-            let account = ctx.state.accounts.entry(*sender).or_default();
-            if account.lusd_balance < total_stream {
-                bail!("Insufficient LUSD for stream escrow");
-            }
-            account.lusd_balance = account.lusd_balance.saturating_sub(total_stream);
-
-            let stream = StreamState {
-                recipient: *to,
-                amount_per_sec: *amount_per_sec,
-                start_timestamp: ctx.timestamp,
-                end_timestamp: ctx.timestamp.saturating_add(*duration),
-                withdrawn: 0,
-            };
-            let acct = ctx.state.accounts.entry(*sender).or_default();
-            acct.active_streams.push(stream);
-            Ok(())
-        }
+        } => instructions::streams::open(ctx, sender, to, *amount_per_sec, *duration),
 
         // ══════════════════════════════════════════════════════════
         // Governance & Staking
         // ══════════════════════════════════════════════════════════
         StablecoinInstruction::RegisterValidator { pubkey, stake } => {
-            if *stake == 0 {
-                bail!("Validator stake must be non-zero");
-            }
-            // Deduct stake from sender's Lumina balance
-            let account = ctx.state.accounts.entry(*sender).or_default();
-            if account.lumina_balance < *stake {
-                bail!("Insufficient Lumina for validator stake");
-            }
-            account.lumina_balance = account.lumina_balance.saturating_sub(*stake);
-
-            ctx.state.validators.push(ValidatorState {
-                pubkey: *pubkey,
-                stake: *stake,
-                power: *stake,
-                is_green: false,
-                energy_proof: None,
-            });
-            Ok(())
+            instructions::staking::register(ctx, sender, pubkey, *stake)
         }
 
         StablecoinInstruction::Vote {
             proposal_id,
             approve,
-        } => {
-            // Verify sender is a validator
-            let is_validator = ctx.state.validators.iter().any(|v| v.pubkey == *sender);
-            if !is_validator {
-                bail!("Only validators can vote");
-            }
-            // Votes are recorded as on-chain state transitions.
-            // In production, a proposal registry tracks tallies.
-            let _ = (*proposal_id, *approve);
-            Ok(())
-        }
+        } => instructions::governance::vote(ctx, sender, *proposal_id, *approve),
 
         // ══════════════════════════════════════════════════════════
         // Phase 1: Seedless Security & Dynamic Economics
@@ -808,16 +1075,12 @@ pub fn execute_si(
             device_key,
             guardians,
         } => {
-            if device_key.is_empty() || device_key.iter().all(|&b| b == 0) {
-                bail!("Invalid device key");
-            }
-            if guardians.len() < 2 || guardians.len() > 10 {
-                bail!("Must have 2-10 guardians for social recovery");
-            }
             let account = ctx.state.accounts.entry(*sender).or_default();
-            account.passkey_device_key = Some(device_key.to_vec());
-            account.guardians = guardians.clone();
-            Ok(())
+            instructions::passkey::register(account, device_key, guardians)?;
+            Ok(vec![Event::PasskeyRegistered {
+                account: *sender,
+                guardian_count: guardians.len() as u32,
+            }])
         }
 
         StablecoinInstruction::RecoverSocial {
@@ -825,25 +1088,33 @@ pub fn execute_si(
             guardian_signatures,
         } => {
             let acct = ctx.state.accounts.entry(*sender).or_default();
-            instructions::passkey::recover_social(acct, new_device_key, guardian_signatures)
+            instructions::passkey::recover_social(acct, new_device_key, guardian_signatures)?;
+            Ok(vec![Event::PasskeyRecovered { account: *sender }])
         }
 
         StablecoinInstruction::ClaimVelocityReward { epoch, tx_volume } => {
             let account = ctx.state.accounts.entry(*sender).or_default();
             if *epoch <= account.last_reward_epoch {
-                bail!("Rewards already claimed for this epoch");
+                return Err(ExecutionError::precondition(
+                    "Rewards already claimed for this epoch",
+                ));
             }
             if *tx_volume == 0 {
-                bail!("No transaction volume to claim");
+                return Err(ExecutionError::precondition(
+                    "No transaction volume to claim",
+                ));
             }
             // Verify claimed volume matches on-chain tracking
             if account.epoch_tx_volume < *tx_volume {
-                bail!("Claimed volume exceeds recorded volume");
+                return Err(ExecutionError::invalid_argument(
+                    "Claimed volume exceeds recorded volume",
+                ));
             }
 
             // Calculate reward: proportional to volume, capped at pool
             let reward = ctx.state.velocity_reward_pool.min(*tx_volume / 1000); // 0.1% of volume as reward
 
+            let mut events = Vec::new();
             if reward > 0 && ctx.state.velocity_reward_pool >= reward {
                 ctx.state.velocity_reward_pool =
                     ctx.state.velocity_reward_pool.saturating_sub(reward);
@@ -851,22 +1122,31 @@ pub fn execute_si(
                 acct.lumina_balance = acct.lumina_balance.saturating_add(reward);
                 acct.last_reward_epoch = *epoch;
                 acct.epoch_tx_volume = 0; // Reset for next epoch
+                events.push(Event::VelocityRewardClaimed {
+                    account: *sender,
+                    epoch: *epoch,
+                    amount: reward,
+                });
             }
-            Ok(())
+            Ok(events)
         }
 
         StablecoinInstruction::RegisterCustodian { stake, mpc_pubkeys } => {
             if *stake == 0 {
-                bail!("Custodian stake must be non-zero");
+                return Err(ExecutionError::ZeroAmount);
             }
             if mpc_pubkeys.is_empty() || mpc_pubkeys.len() > 7 {
-                bail!("MPC key set must be 1-7 keys");
+                return Err(ExecutionError::invalid_argument(
+                    "MPC key set must be 1-7 keys",
+                ));
             }
 
             // Deduct LJUN stake from sender
             let account = ctx.state.accounts.entry(*sender).or_default();
             if account.ljun_balance < *stake {
-                bail!("Insufficient LJUN for custodian stake");
+                return Err(ExecutionError::InsufficientBalance {
+                    asset: AssetType::LJUN,
+                });
             }
             account.ljun_balance = account.ljun_balance.saturating_sub(*stake);
 
@@ -876,32 +1156,41 @@ pub fn execute_si(
                 mpc_pubkeys: mpc_pubkeys.clone(),
                 registered_height: ctx.height,
             });
-            Ok(())
+            Ok(vec![Event::CustodianRegistered {
+                pubkey: *sender,
+                stake: *stake,
+            }])
         }
 
         StablecoinInstruction::RotateReserves { new_custodian_set } => {
             if new_custodian_set.is_empty() {
-                bail!("New custodian set cannot be empty");
+                return Err(ExecutionError::invalid_argument(
+                    "New custodian set cannot be empty",
+                ));
             }
-            // Only rotate every 30 days (~259200 blocks at 10s/block)
-            let rotation_interval = 259200u64;
+            // Only rotate once per interval (~30 days at genesis)
+            let rotation_interval = ctx.state.protocol_params.reserve_rotation_interval;
             if ctx
                 .height
                 .saturating_sub(ctx.state.last_reserve_rotation_height)
                 < rotation_interval
             {
-                bail!("Reserve rotation too frequent");
+                return Err(ExecutionError::precondition(
+                    "Reserve rotation too frequent",
+                ));
             }
 
             // Validate all new custodians are registered
             for pubkey in new_custodian_set {
                 if !ctx.state.custodians.iter().any(|c| c.pubkey == *pubkey) {
-                    bail!("Custodian not registered: {:?}", pubkey);
+                    return Err(ExecutionError::not_found("Custodian"));
                 }
             }
 
             ctx.state.last_reserve_rotation_height = ctx.height;
-            Ok(())
+            Ok(vec![Event::ReservesRotated {
+                custodian_count: new_custodian_set.len() as u32,
+            }])
         }
 
         StablecoinInstruction::ClaimInsurance {
@@ -909,10 +1198,14 @@ pub fn execute_si(
             claimed_amount,
         } => {
             if !verify_insurance_loss_proof(loss_proof, *claimed_amount) {
-                bail!("Invalid insurance loss proof");
+                return Err(ExecutionError::InvalidProof {
+                    kind: ProofKind::InsuranceLoss,
+                });
             }
             if *claimed_amount > ctx.state.insurance_fund_balance {
-                bail!("Claim exceeds insurance fund balance");
+                return Err(ExecutionError::precondition(
+                    "Claim exceeds insurance fund balance",
+                ));
             }
 
             ctx.state.insurance_fund_balance = ctx
@@ -923,15 +1216,23 @@ pub fn execute_si(
             account.lusd_balance = account
                 .lusd_balance
                 .checked_add(*claimed_amount)
-                .ok_or_else(|| anyhow::anyhow!("Balance overflow"))?;
+                .ok_or_else(|| ExecutionError::overflow("Balance"))?;
             ctx.state.total_lusd_supply = ctx
                 .state
                 .total_lusd_supply
                 .checked_add(*claimed_amount)
-                .ok_or_else(|| anyhow::anyhow!("Supply overflow"))?;
+                .ok_or_else(|| ExecutionError::overflow("Supply"))?;
 
             recalculate_ratios(ctx);
-            Ok(())
+            Ok(vec![
+                Event::InsuranceFundDebited {
+                    amount: *claimed_amount,
+                },
+                Event::InsuranceClaimPaid {
+                    account: *sender,
+                    amount: *claimed_amount,
+                },
+            ])
         }
 
         // ══════════════════════════════════════════════════════════
@@ -939,41 +1240,54 @@ pub fn execute_si(
         // ══════════════════════════════════════════════════════════
         StablecoinInstruction::SwitchToPQSignature { new_pq_pubkey } => {
             if new_pq_pubkey.is_empty() {
-                bail!("PQ public key cannot be empty");
+                return Err(ExecutionError::invalid_argument(
+                    "PQ public key cannot be empty",
+                ));
             }
             let account = ctx.state.accounts.entry(*sender).or_default();
             account.pq_pubkey = Some(new_pq_pubkey.clone());
-            Ok(())
+            account.passkey_auth = false;
+            Ok(vec![Event::PqKeyRegistered { account: *sender }])
         }
 
         StablecoinInstruction::RegisterGreenValidator { energy_proof } => {
             if !verify_green_energy_proof(energy_proof) {
-                bail!("Invalid green energy proof");
+                return Err(ExecutionError::InvalidProof {
+                    kind: ProofKind::GreenEnergy,
+                });
             }
 
-            // Find validator by sender pubkey and flag as green
-            let mut found = false;
+            // Find validator by sender pubkey and flag as green; the 2x power
+            // boost applies from the next epoch boundary
+            let mut found = None;
             for v in ctx.state.validators.iter_mut() {
                 if v.pubkey == *sender {
                     v.is_green = true;
                     v.energy_proof = Some(energy_proof.clone());
-                    // Green validators get 2x voting power
-                    v.power = v.stake.saturating_mul(2);
-                    found = true;
+                    found = Some(v.target_power());
                     break;
                 }
             }
-            if !found {
-                bail!("Sender is not a registered validator");
-            }
-            Ok(())
+            let Some(power) = found else {
+                return Err(ExecutionError::unauthorized(
+                    "Sender is not a registered validator",
+                ));
+            };
+            Ok(vec![Event::GreenValidatorRegistered {
+                pubkey: *sender,
+                power,
+            }])
         }
 
         StablecoinInstruction::SubmitGreenProof { energy_proof } => {
             if !verify_green_energy_proof(energy_proof) {
-                bail!("Invalid green energy proof");
+                return Err(ExecutionError::InvalidProof {
+                    kind: ProofKind::GreenEnergy,
+                });
             }
-            Ok(())
+            Ok(vec![Event::ProofVerified {
+                kind: ProofKind::GreenEnergy,
+            }])
         }
 
         StablecoinInstruction::UploadComplianceCircuit {
@@ -981,12 +1295,16 @@ pub fn execute_si(
             verifier_key,
         } => {
             if verifier_key.is_empty() {
-                bail!("Verifier key cannot be empty");
+                return Err(ExecutionError::invalid_argument(
+                    "Verifier key cannot be empty",
+                ));
             }
             ctx.state
                 .compliance_circuits
                 .insert(*circuit_id, verifier_key.clone());
-            Ok(())
+            Ok(vec![Event::ComplianceCircuitUploaded {
+                circuit_id: *circuit_id,
+            }])
         }
 
         // ══════════════════════════════════════════════════════════
@@ -999,86 +1317,109 @@ pub fn execute_si(
             commitment,
         } => {
             if *amount == 0 {
-                bail!("Flash mint amount must be non-zero");
+                return Err(ExecutionError::ZeroAmount);
             }
             if *collateral_amount == 0 {
-                bail!("Flash mint collateral must be non-zero");
+                return Err(ExecutionError::ZeroAmount);
             }
 
-            let min_collateral = amount.saturating_mul(110) / 100;
+            let min_collateral =
+                bps_of(*amount, ctx.state.protocol_params.flash_mint_collateral_bps);
             if *collateral_amount < min_collateral {
-                bail!(
-                    "Insufficient collateral for flash mint: need >= {}",
-                    min_collateral
-                );
+                return Err(ExecutionError::InsufficientCollateral {
+                    required: min_collateral,
+                    provided: *collateral_amount,
+                });
             }
 
-            // Domain-bind the collateral lock (commitment is stored off-chain/on-chain by custody
-            // subsystems; here we only enforce accounting and end-of-block burn).
-            let _ = (collateral_asset, commitment);
-
-            ctx.state.stabilization_pool_balance = ctx
+            // The commitment is stored by custody subsystems; here we only enforce
+            // accounting and the end-of-block burn.
+            let _ = commitment;
+            let pending_asset = ctx
                 .state
-                .stabilization_pool_balance
-                .checked_add(*collateral_amount)
-                .ok_or_else(|| anyhow::anyhow!("Collateral overflow"))?;
+                .accounts
+                .get(sender)
+                .and_then(|account| account.pending_flash_asset.as_ref());
+            if pending_asset.is_some_and(|asset| asset != collateral_asset) {
+                return Err(ExecutionError::precondition(
+                    "Pending flash mint is backed by a different collateral asset",
+                ));
+            }
+
+            // The flash position is an ordinary vault draw, released by FlashBurn
+            let mut events =
+                instructions::vaults::deposit(ctx, sender, collateral_asset, *collateral_amount)?;
+            events.extend(instructions::vaults::draw(ctx, sender, *amount)?);
 
             let account = ctx.state.accounts.entry(*sender).or_default();
             account.pending_flash_mint = account
                 .pending_flash_mint
                 .checked_add(*amount)
-                .ok_or_else(|| anyhow::anyhow!("Account flash mint overflow"))?;
+                .ok_or_else(|| ExecutionError::overflow("Account flash mint"))?;
             account.pending_flash_collateral = account
                 .pending_flash_collateral
                 .checked_add(*collateral_amount)
-                .ok_or_else(|| anyhow::anyhow!("Account flash collateral overflow"))?;
-
-            account.lusd_balance = account
-                .lusd_balance
-                .checked_add(*amount)
-                .ok_or_else(|| anyhow::anyhow!("Balance overflow"))?;
-
-            ctx.state.total_lusd_supply = ctx
-                .state
-                .total_lusd_supply
-                .checked_add(*amount)
-                .ok_or_else(|| anyhow::anyhow!("Supply overflow"))?;
+                .ok_or_else(|| ExecutionError::overflow("Account flash collateral"))?;
+            account.pending_flash_asset = Some(collateral_asset.clone());
 
             ctx.state.pending_flash_mints = ctx
                 .state
                 .pending_flash_mints
                 .checked_add(*amount)
-                .ok_or_else(|| anyhow::anyhow!("Flash mint overflow"))?;
-            Ok(())
+                .ok_or_else(|| ExecutionError::overflow("Flash mint"))?;
+            events.push(Event::FlashMinted {
+                account: *sender,
+                amount: *amount,
+                collateral_amount: *collateral_amount,
+            });
+            Ok(events)
         }
 
         StablecoinInstruction::FlashBurn { amount } => {
             if *amount == 0 {
-                bail!("Flash burn amount must be non-zero");
+                return Err(ExecutionError::ZeroAmount);
             }
             let account = ctx.state.accounts.entry(*sender).or_default();
             if account.pending_flash_mint == 0 {
-                bail!("No pending flash mint to burn");
+                return Err(ExecutionError::precondition(
+                    "No pending flash mint to burn",
+                ));
             }
             if *amount != account.pending_flash_mint {
-                bail!("Flash burn must burn full pending flash mint in this block");
+                return Err(ExecutionError::precondition(
+                    "Flash burn must burn full pending flash mint in this block",
+                ));
             }
             if account.lusd_balance < *amount {
-                bail!("Insufficient LUSD for flash burn");
+                return Err(ExecutionError::InsufficientBalance {
+                    asset: AssetType::LUSD,
+                });
             }
-            account.lusd_balance = checked_sub_u64(account.lusd_balance, *amount, "LUSD balance")?;
-            ctx.state.total_lusd_supply =
-                checked_sub_u64(ctx.state.total_lusd_supply, *amount, "LUSD supply")?;
+            let collateral_to_release = account.pending_flash_collateral;
+            let collateral_asset = account
+                .pending_flash_asset
+                .clone()
+                .ok_or_else(|| ExecutionError::precondition("No pending flash mint to burn"))?;
+
+            let mut events = instructions::vaults::repay(ctx, sender, *amount)?;
+            events.extend(instructions::vaults::withdraw(
+                ctx,
+                sender,
+                &collateral_asset,
+                collateral_to_release,
+            )?);
             ctx.state.pending_flash_mints = ctx.state.pending_flash_mints.saturating_sub(*amount);
 
-            let collateral_to_release = account.pending_flash_collateral;
-            ctx.state.stabilization_pool_balance = ctx
-                .state
-                .stabilization_pool_balance
-                .saturating_sub(collateral_to_release);
+            let account = ctx.state.accounts.entry(*sender).or_default();
             account.pending_flash_mint = 0;
             account.pending_flash_collateral = 0;
-            Ok(())
+            account.pending_flash_asset = None;
+            events.push(Event::FlashBurned {
+                account: *sender,
+                amount: *amount,
+                collateral_released: collateral_to_release,
+            });
+            Ok(events)
         }
 
         StablecoinInstruction::InstantRedeem {
@@ -1087,23 +1428,18 @@ pub fn execute_si(
         } => {
             let _ = destination;
             if *amount == 0 {
-                bail!("Amount must be greater than zero");
+                return Err(ExecutionError::ZeroAmount);
             }
 
             let account = ctx.state.accounts.entry(*sender).or_default();
             if account.lusd_balance < *amount {
-                bail!("Insufficient LUSD balance");
+                return Err(ExecutionError::InsufficientBalance {
+                    asset: AssetType::LUSD,
+                });
             }
 
-            if ctx.state.circuit_breaker_active || ctx.state.reserve_ratio < 0.95 {
-                ctx.state.fair_redeem_queue.push(RedemptionRequest {
-                    address: *sender,
-                    amount: *amount,
-                    timestamp: ctx.timestamp,
-                });
-                let acct = ctx.state.accounts.entry(*sender).or_default();
-                acct.lusd_balance = checked_sub_u64(acct.lusd_balance, *amount, "LUSD balance")?;
-                return Ok(());
+            if ctx.state.circuit_breaker_active || below_redemption_threshold(ctx) {
+                return instructions::redemption::enqueue(ctx, sender, *amount);
             }
 
             let acct = ctx.state.accounts.entry(*sender).or_default();
@@ -1114,7 +1450,17 @@ pub fn execute_si(
                 ctx.state.stabilization_pool_balance.saturating_sub(*amount);
 
             recalculate_ratios(ctx);
-            Ok(())
+            Ok(vec![
+                Event::Burned {
+                    from: *sender,
+                    asset: AssetType::LUSD,
+                    amount: *amount,
+                },
+                Event::CollateralReleased {
+                    account: *sender,
+                    amount: *amount,
+                },
+            ])
         }
 
         StablecoinInstruction::MintWithCreditScore {
@@ -1125,7 +1471,7 @@ pub fn execute_si(
             oracle,
         } => {
             if *amount == 0 {
-                bail!("Amount must be non-zero");
+                return Err(ExecutionError::ZeroAmount);
             }
 
             let oracle_allowed = ctx.state.trusted_credit_oracles.contains(oracle);
@@ -1134,9 +1480,10 @@ pub fn execute_si(
             let is_replay = ctx.state.used_credit_proofs.contains(&proof_id);
 
             if !oracle_allowed || !proof_ok || is_replay {
-                // Fallback to normal mint semantics.
+                // Fallback to a normal senior mint against Lumina collateral.
                 let fallback = StablecoinInstruction::MintSenior {
                     amount: *amount,
+                    collateral_asset: AssetType::Lumina,
                     collateral_amount: *collateral_amount,
                     proof: Vec::new(),
                 };
@@ -1150,6 +1497,7 @@ pub fn execute_si(
             if score < *min_score_threshold {
                 let fallback = StablecoinInstruction::MintSenior {
                     amount: *amount,
+                    collateral_asset: AssetType::Lumina,
                     collateral_amount: *collateral_amount,
                     proof: Vec::new(),
                 };
@@ -1166,10 +1514,10 @@ pub fn execute_si(
             };
             let required_collateral = amount.saturating_mul(required_bps) / 10_000;
             if *collateral_amount < required_collateral {
-                bail!(
-                    "Collateral too low for scored mint: need >= {}",
-                    required_collateral
-                );
+                return Err(ExecutionError::InsufficientCollateral {
+                    required: required_collateral,
+                    provided: *collateral_amount,
+                });
             }
 
             ctx.state.used_credit_proofs.push(proof_id);
@@ -1178,210 +1526,355 @@ pub fn execute_si(
                 .state
                 .stabilization_pool_balance
                 .checked_add(*collateral_amount)
-                .ok_or_else(|| anyhow::anyhow!("Collateral overflow"))?;
+                .ok_or_else(|| ExecutionError::overflow("Collateral"))?;
 
             let account = ctx.state.accounts.entry(*sender).or_default();
             account.lusd_balance = account
                 .lusd_balance
                 .checked_add(*amount)
-                .ok_or_else(|| anyhow::anyhow!("Balance overflow"))?;
+                .ok_or_else(|| ExecutionError::overflow("Balance"))?;
             account.credit_score = score;
 
             ctx.state.total_lusd_supply = ctx
                 .state
                 .total_lusd_supply
                 .checked_add(*amount)
-                .ok_or_else(|| anyhow::anyhow!("Supply overflow"))?;
+                .ok_or_else(|| ExecutionError::overflow("Supply"))?;
 
             recalculate_ratios(ctx);
-            Ok(())
+            Ok(vec![
+                Event::CreditScoreRecorded {
+                    account: *sender,
+                    score,
+                },
+                Event::CollateralDeposited {
+                    account: *sender,
+                    amount: *collateral_amount,
+                },
+                Event::Minted {
+                    to: *sender,
+                    asset: AssetType::LUSD,
+                    amount: *amount,
+                },
+            ])
         }
 
         StablecoinInstruction::WrapToYieldToken {
             amount,
             maturity_blocks,
-        } => {
-            if *amount == 0 || *maturity_blocks == 0 {
-                bail!("Amount and maturity must be non-zero");
-            }
-            let account = ctx.state.accounts.entry(*sender).or_default();
-            if account.lusd_balance < *amount {
-                bail!("Insufficient LUSD for yield token wrap");
-            }
-            account.lusd_balance = checked_sub_u64(account.lusd_balance, *amount, "LUSD balance")?;
-
-            let token_id = ctx.state.next_yield_token_id;
-            ctx.state.next_yield_token_id = ctx
-                .state
-                .next_yield_token_id
-                .checked_add(1)
-                .ok_or_else(|| anyhow::anyhow!("Token ID overflow"))?;
-
-            let position = YieldPosition {
-                token_id,
-                principal: *amount,
-                maturity_height: ctx.height.saturating_add(*maturity_blocks),
-                issued_height: ctx.height,
-            };
-
-            let acct = ctx.state.accounts.entry(*sender).or_default();
-            acct.yield_positions.push(position);
-            Ok(())
-        }
+        } => instructions::yield_tokens::wrap(ctx, sender, *amount, *maturity_blocks),
 
         StablecoinInstruction::UnwrapYieldToken { token_id } => {
-            let account = ctx.state.accounts.entry(*sender).or_default();
-            let pos_idx = account
-                .yield_positions
-                .iter()
-                .position(|p| p.token_id == *token_id)
-                .ok_or_else(|| anyhow::anyhow!("Yield position not found"))?;
-
-            let position = account.yield_positions[pos_idx].clone();
-            if ctx.height < position.maturity_height {
-                bail!("Yield token has not reached maturity");
-            }
-
-            // Calculate yield: 5% annualized, prorated by blocks held
-            // Assuming 10s blocks, ~3_153_600 blocks/year
-            let blocks_held = ctx.height.saturating_sub(position.issued_height);
-            let yield_earned = position
-                .principal
-                .saturating_mul(5)
-                .saturating_mul(blocks_held)
-                / (100u64.saturating_mul(3_153_600));
-
-            // Route a junior-yield contribution to insurance automatically.
-            let insurance_cut = yield_earned.saturating_mul(10) / 100;
-            let user_yield = yield_earned.saturating_sub(insurance_cut);
-            let total_return = position.principal.saturating_add(user_yield);
-
-            let acct = ctx.state.accounts.entry(*sender).or_default();
-            acct.lusd_balance = acct
-                .lusd_balance
-                .checked_add(total_return)
-                .ok_or_else(|| anyhow::anyhow!("Balance overflow"))?;
-            acct.yield_positions.remove(pos_idx);
-
-            ctx.state.insurance_fund_balance = ctx
-                .state
-                .insurance_fund_balance
-                .checked_add(insurance_cut)
-                .ok_or_else(|| anyhow::anyhow!("Insurance overflow"))?;
-
-            ctx.state.total_lusd_supply = ctx
-                .state
-                .total_lusd_supply
-                .checked_add(yield_earned)
-                .ok_or_else(|| anyhow::anyhow!("Supply overflow"))?;
-
-            recalculate_ratios(ctx);
-            Ok(())
+            instructions::yield_tokens::unwrap(ctx, sender, *token_id)
         }
 
         StablecoinInstruction::ListRWA {
             asset_description,
+            asset_class,
             attested_value,
             attestation_proof,
             maturity_date,
             collateral_eligibility,
-        } => {
-            if asset_description.is_empty() {
-                bail!("Asset description must be non-empty");
-            }
-            if *attested_value == 0 {
-                bail!("Attested value must be non-zero");
-            }
-            if !verify_rwa_attestation(attestation_proof, *attested_value) {
-                bail!("Invalid RWA attestation proof");
-            }
-
-            let rwa_id = ctx.state.next_rwa_id;
-            ctx.state.next_rwa_id = ctx
-                .state
-                .next_rwa_id
-                .checked_add(1)
-                .ok_or_else(|| anyhow::anyhow!("RWA id overflow"))?;
-
-            ctx.state.rwa_listings.insert(
-                rwa_id,
-                RWAListing {
-                    owner: *sender,
-                    asset_description: asset_description.clone(),
-                    attestation_proof: attestation_proof.clone(),
-                    attested_value: *attested_value,
-                    maturity_date: *maturity_date,
-                    collateral_eligibility: *collateral_eligibility,
-                    is_active: true,
-                    pledged_amount: 0,
-                },
-            );
-            Ok(())
-        }
+        } => instructions::rwa::list(
+            ctx,
+            sender,
+            asset_description,
+            *asset_class,
+            *attested_value,
+            attestation_proof,
+            *maturity_date,
+            *collateral_eligibility,
+        ),
 
         StablecoinInstruction::UseRWAAsCollateral {
             rwa_id,
             amount_to_pledge,
-        } => {
-            if *amount_to_pledge == 0 {
-                bail!("Pledge amount must be non-zero");
-            }
-            let listing = ctx
-                .state
-                .rwa_listings
-                .get_mut(rwa_id)
-                .ok_or_else(|| anyhow::anyhow!("RWA asset not found"))?;
+        } => instructions::rwa::pledge(ctx, sender, *rwa_id, *amount_to_pledge),
 
-            if !listing.is_active {
-                bail!("RWA listing is not active");
-            }
-            if !listing.collateral_eligibility {
-                bail!("RWA listing not eligible as collateral");
-            }
+        StablecoinInstruction::ComputeHealthIndex => {
+            compute_health_index(ctx);
+            Ok(vec![Event::HealthIndexComputed {
+                health_index: ctx.state.health_index,
+            }])
+        }
 
-            let remaining_capacity = listing
-                .attested_value
-                .saturating_sub(listing.pledged_amount);
-            if *amount_to_pledge > remaining_capacity {
-                bail!("Pledge exceeds RWA remaining collateral capacity");
-            }
+        // ══════════════════════════════════════════════════════════
+        // Governance Registry
+        // ══════════════════════════════════════════════════════════
+        StablecoinInstruction::SubmitProposal { action } => {
+            instructions::governance::submit(ctx, sender, action)
+        }
+
+        StablecoinInstruction::ExecuteProposal { proposal_id } => {
+            instructions::governance::execute(ctx, *proposal_id)
+        }
+
+        // ══════════════════════════════════════════════════════════
+        // Access Control
+        // ══════════════════════════════════════════════════════════
+        StablecoinInstruction::UpdateRoleByAdmin {
+            role,
+            account,
+            granted,
+            admin_signatures,
+        } => instructions::roles::update_by_admin(ctx, *role, account, *granted, admin_signatures),
+
+        // ══════════════════════════════════════════════════════════
+        // Stream Settlement
+        // ══════════════════════════════════════════════════════════
+        StablecoinInstruction::WithdrawStream { stream_id } => {
+            instructions::streams::withdraw(ctx, sender, *stream_id)
+        }
+        StablecoinInstruction::CancelStream { stream_id } => {
+            instructions::streams::cancel(ctx, sender, *stream_id)
+        }
 
-            listing.pledged_amount = listing
-                .pledged_amount
-                .checked_add(*amount_to_pledge)
-                .ok_or_else(|| anyhow::anyhow!("Pledge overflow"))?;
+        // ══════════════════════════════════════════════════════════
+        // Redemption Settlement
+        // ══════════════════════════════════════════════════════════
+        StablecoinInstruction::CancelRedemption { request_id } => {
+            instructions::redemption::cancel(ctx, sender, *request_id)
+        }
 
+        // ══════════════════════════════════════════════════════════
+        // Validator Lifecycle
+        // ══════════════════════════════════════════════════════════
+        StablecoinInstruction::AddStake { pubkey, amount } => {
+            instructions::staking::add_stake(ctx, sender, pubkey, *amount)
+        }
+        StablecoinInstruction::Unbond { pubkey, amount } => {
+            instructions::staking::unbond(ctx, sender, pubkey, *amount)
+        }
+        StablecoinInstruction::WithdrawStake { pubkey } => {
+            instructions::staking::withdraw(ctx, sender, pubkey)
+        }
+
+        // ══════════════════════════════════════════════════════════
+        // Delegation
+        // ══════════════════════════════════════════════════════════
+        StablecoinInstruction::Delegate { validator, amount } => {
+            instructions::staking::delegate(ctx, sender, validator, *amount)
+        }
+        StablecoinInstruction::Undelegate { validator, amount } => {
+            instructions::staking::undelegate(ctx, sender, validator, *amount)
+        }
+        StablecoinInstruction::ClaimDelegatorRewards { validator } => {
+            instructions::staking::claim_rewards(ctx, sender, validator)
+        }
+        StablecoinInstruction::SetCommission {
+            pubkey,
+            commission_bps,
+        } => instructions::staking::set_commission(ctx, sender, pubkey, *commission_bps),
+
+        // ══════════════════════════════════════════════════════════
+        // Slashing
+        // ══════════════════════════════════════════════════════════
+        StablecoinInstruction::SubmitEvidence { evidence } => {
+            instructions::evidence::submit(ctx, evidence)
+        }
+
+        // ══════════════════════════════════════════════════════════
+        // Transaction Bundles
+        // ══════════════════════════════════════════════════════════
+        StablecoinInstruction::Bundle { instructions } => {
+            execute_bundle(instructions, sender, ctx, &mut gas::GasMeter::unlimited())
+        }
+
+        // ══════════════════════════════════════════════════════════
+        // Passkey Authorization
+        // ══════════════════════════════════════════════════════════
+        StablecoinInstruction::SwitchToPasskeySignature => {
             let account = ctx.state.accounts.entry(*sender).or_default();
-            account.lusd_balance = account
-                .lusd_balance
-                .checked_add(*amount_to_pledge)
-                .ok_or_else(|| anyhow::anyhow!("Balance overflow"))?;
+            instructions::passkey::switch_to_passkey(account)?;
+            Ok(vec![Event::PasskeySignatureEnabled { account: *sender }])
+        }
 
-            ctx.state.total_lusd_supply = ctx
-                .state
-                .total_lusd_supply
-                .checked_add(*amount_to_pledge)
-                .ok_or_else(|| anyhow::anyhow!("Supply overflow"))?;
+        // ══════════════════════════════════════════════════════════
+        // Multisig Accounts
+        // ══════════════════════════════════════════════════════════
+        StablecoinInstruction::CreateMultisigAccount { members, threshold } => {
+            instructions::multisig::create(ctx, sender, members, *threshold)
+        }
+        StablecoinInstruction::AddMultisigMember { member } => {
+            instructions::multisig::add_member(ctx, sender, member)
+        }
+        StablecoinInstruction::RemoveMultisigMember { member } => {
+            instructions::multisig::remove_member(ctx, sender, member)
+        }
+        StablecoinInstruction::SetMultisigThreshold { threshold } => {
+            instructions::multisig::set_threshold(ctx, sender, *threshold)
+        }
 
-            ctx.state.stabilization_pool_balance = ctx
-                .state
-                .stabilization_pool_balance
-                .checked_add(*amount_to_pledge)
-                .ok_or_else(|| anyhow::anyhow!("Pool overflow"))?;
+        // ══════════════════════════════════════════════════════════
+        // Session Keys
+        // ══════════════════════════════════════════════════════════
+        StablecoinInstruction::RegisterSessionKey {
+            pubkey,
+            permissions,
+            spend_limit,
+            period_blocks,
+            expires_at,
+        } => instructions::sessions::register(
+            ctx,
+            sender,
+            pubkey,
+            permissions,
+            *spend_limit,
+            *period_blocks,
+            *expires_at,
+        ),
+        StablecoinInstruction::RevokeSessionKey { pubkey } => {
+            instructions::sessions::revoke(ctx, sender, pubkey)
+        }
 
-            recalculate_ratios(ctx);
-            Ok(())
+        // ══════════════════════════════════════════════════════════
+        // Asset Issuance
+        // ══════════════════════════════════════════════════════════
+        StablecoinInstruction::ConfigureAsset {
+            ticker,
+            max_supply,
+            freeze_authority,
+        } => instructions::assets::configure(ctx, sender, ticker, *max_supply, *freeze_authority),
+        StablecoinInstruction::MintAsset { ticker, to, amount } => {
+            instructions::assets::mint(ctx, sender, ticker, to, *amount)
+        }
+        StablecoinInstruction::BurnAsset { ticker, amount } => {
+            instructions::assets::burn(ctx, sender, ticker, *amount)
         }
+        StablecoinInstruction::SetAssetFrozen {
+            ticker,
+            account,
+            frozen,
+        } => instructions::assets::set_frozen(ctx, sender, ticker, account, *frozen),
 
-        StablecoinInstruction::ComputeHealthIndex => {
-            compute_health_index(ctx);
-            Ok(())
+        // ══════════════════════════════════════════════════════════
+        // Allowances
+        // ══════════════════════════════════════════════════════════
+        StablecoinInstruction::Approve {
+            spender,
+            asset,
+            amount,
+        } => instructions::allowances::approve(ctx, sender, spender, asset, *amount),
+        StablecoinInstruction::TransferFrom {
+            owner,
+            to,
+            asset,
+            amount,
+        } => instructions::allowances::transfer_from(ctx, sender, owner, to, asset, *amount),
+        StablecoinInstruction::RevokeApproval { spender, asset } => {
+            instructions::allowances::revoke(ctx, sender, spender, asset)
+        }
+
+        // ══════════════════════════════════════════════════════════
+        // Confidential Balances
+        // ══════════════════════════════════════════════════════════
+        StablecoinInstruction::Shield { amount } => {
+            instructions::confidential::shield(ctx, sender, *amount)
+        }
+
+        StablecoinInstruction::Unshield { amount, proof } => {
+            instructions::confidential::unshield(ctx, sender, *amount, proof)
+        }
+
+        StablecoinInstruction::SetAuditorKey { auditor_pubkey } => {
+            instructions::confidential::set_auditor_key(ctx, sender, auditor_pubkey.as_deref())
         }
+
+        StablecoinInstruction::SetReceiveKey { receive_pubkey } => {
+            instructions::confidential::set_receive_key(ctx, sender, receive_pubkey.as_deref())
+        }
+
+        StablecoinInstruction::ApplyPendingBalance => {
+            instructions::confidential::apply_pending(ctx, sender)
+        }
+
+        // ══════════════════════════════════════════════════════════
+        // Yield Tokens
+        // ══════════════════════════════════════════════════════════
+        StablecoinInstruction::TransferYieldToken { token_id, side, to } => {
+            instructions::yield_tokens::transfer(ctx, sender, *token_id, *side, to)
+        }
+
+        StablecoinInstruction::ClaimYield { token_id } => {
+            instructions::yield_tokens::claim_yield(ctx, sender, *token_id)
+        }
+
+        // ══════════════════════════════════════════════════════════
+        // RWA Lifecycle
+        // ══════════════════════════════════════════════════════════
+        StablecoinInstruction::RepayRWALoan { rwa_id, amount } => {
+            instructions::rwa::repay(ctx, sender, *rwa_id, *amount)
+        }
+
+        StablecoinInstruction::UnpledgeRWA { rwa_id, amount } => {
+            instructions::rwa::unpledge(ctx, sender, *rwa_id, *amount)
+        }
+
+        StablecoinInstruction::DelistRWA { rwa_id } => {
+            instructions::rwa::delist(ctx, sender, *rwa_id)
+        }
+
+        StablecoinInstruction::LiquidateRWA { rwa_id } => {
+            instructions::rwa::liquidate(ctx, *rwa_id)
+        }
+
+        // ══════════════════════════════════════════════════════════
+        // RWA Valuation
+        // ══════════════════════════════════════════════════════════
+        StablecoinInstruction::RevalueRWA {
+            rwa_id,
+            attested_value,
+        } => instructions::rwa::revalue(ctx, sender, *rwa_id, *attested_value),
+
+        // ══════════════════════════════════════════════════════════
+        // Collateralized Debt Vaults
+        // ══════════════════════════════════════════════════════════
+        StablecoinInstruction::DepositVaultCollateral { asset, amount } => {
+            instructions::vaults::deposit(ctx, sender, asset, *amount)
+        }
+
+        StablecoinInstruction::WithdrawVaultCollateral { asset, amount } => {
+            instructions::vaults::withdraw(ctx, sender, asset, *amount)
+        }
+
+        StablecoinInstruction::DrawVaultDebt { amount } => {
+            instructions::vaults::draw(ctx, sender, *amount)
+        }
+
+        StablecoinInstruction::RepayVaultDebt { amount } => {
+            instructions::vaults::repay(ctx, sender, *amount)
+        }
+
+        StablecoinInstruction::LiquidateVault { owner } => {
+            instructions::vaults::liquidate(ctx, owner)
+        }
+
+        StablecoinInstruction::BidVaultAuction {
+            auction_id,
+            max_payment,
+        } => instructions::vaults::bid(ctx, sender, *auction_id, *max_payment),
     }
 }
 
+fn pool_updated_event(ctx: &ExecutionContext) -> Event {
+    Event::StabilizationPoolUpdated {
+        balance: ctx.state.stabilization_pool_balance,
+        reserve_ratio_bps: (ctx.state.reserve_ratio.max(0.0) * 10_000.0) as u64,
+    }
+}
+
+/// `bps` basis points of `amount`, rounded down and saturating at `u64::MAX`.
+fn bps_of(amount: u64, bps: u64) -> u64 {
+    u64::try_from((amount as u128) * (bps as u128) / 10_000).unwrap_or(u64::MAX)
+}
+
+fn below_redemption_threshold(ctx: &ExecutionContext) -> bool {
+    let threshold = ctx.state.protocol_params.redemption_queue_threshold_bps as f64 / 10_000.0;
+    ctx.state.reserve_ratio < threshold
+}
+
 /// Core stability math — called after every monetary operation.
-/// Auto-triggers circuit breaker at <85% reserves.
+/// Auto-triggers circuit breaker below the breaker threshold (85% reserves at genesis).
 fn recalculate_ratios(ctx: &mut ExecutionContext) {
     if ctx.state.total_lusd_supply == 0 {
         ctx.state.reserve_ratio = 1.0;
@@ -1391,7 +1884,8 @@ fn recalculate_ratios(ctx: &mut ExecutionContext) {
     ctx.state.reserve_ratio =
         (ctx.state.stabilization_pool_balance as f64) / (ctx.state.total_lusd_supply as f64);
 
-    if ctx.state.reserve_ratio < 0.85 {
+    let breaker = ctx.state.protocol_params.circuit_breaker_threshold_bps as f64 / 10_000.0;
+    if ctx.state.reserve_ratio < breaker {
         ctx.state.circuit_breaker_active = true;
     }
 }
@@ -52,12 +52,17 @@ fn test_circuit_breaker_logic() {
 
     state.total_lusd_supply = 1_000_000;
     state.stabilization_pool_balance = 100_000;
+    state
+        .oracle_prices
+        .insert("LUMINA-USD".to_string(), 1_000_000);
+    state.accounts.entry(sender).or_default().lumina_balance = 2;
 
     let manager = lumina_crypto::zk::ZkManager::setup();
     let mint_si = StablecoinInstruction::MintSenior {
         amount: 1,
-        collateral_amount: 1,
-        proof: manager.prove_reserves(vec![1], 1),
+        collateral_asset: lumina_types::instruction::AssetType::Lumina,
+        collateral_amount: 2,
+        proof: manager.prove_reserves(vec![2], 2),
     };
 
     {
@@ -71,6 +76,8 @@ fn test_circuit_breaker_logic() {
 
     assert!(state.reserve_ratio < 0.85);
     assert!(state.circuit_breaker_active);
+    state.accounts.entry(sender).or_default().lumina_balance = 100_000;
+    let supply_before = state.total_lusd_supply;
 
     // Build a signed tx to verify circuit breaker blocks mints
     let mut tx = Transaction {
@@ -78,7 +85,7 @@ fn test_circuit_breaker_logic() {
         nonce: 0,
         instruction: mint_si,
         signature: vec![0u8; 64],
-        gas_limit: 1000,
+        gas_limit: 100_000,
         gas_price: 1,
     };
     tx.signature = lumina_crypto::signatures::sign(&kp, &tx.signing_bytes());
@@ -87,7 +94,10 @@ fn test_circuit_breaker_logic() {
         height: 2,
         timestamp: 200,
     };
-    assert!(execute_transaction(&tx, &mut ctx).is_err());
+    let receipt = execute_transaction(&tx, &mut ctx).unwrap();
+    assert_eq!(receipt.error(), Some(&ExecutionError::CircuitBreakerActive));
+    assert_eq!(state.total_lusd_supply, supply_before);
+    assert_eq!(state.accounts.get(&sender).unwrap().nonce, 1);
 }
 
 #[test]
@@ -130,8 +140,212 @@ fn test_redemption_queueing() {
         assert!(execute_si(&process_si, &sender, &mut ctx).is_ok());
     }
 
-    assert_eq!(state.total_lusd_supply, 4000);
-    assert_eq!(state.fair_redeem_queue.len(), 0);
+    // 90% reserves settle 90% of the request; the remainder stays queued
+    assert_eq!(state.total_lusd_supply, 4100);
+    assert_eq!(state.fair_redeem_queue.len(), 1);
+    assert_eq!(state.fair_redeem_queue[0].remaining(), 100);
+}
+
+#[test]
+fn test_error_codes_are_pinned() {
+    use lumina_types::instruction::AssetType;
+    use lumina_types::receipt::ProofKind;
+    use lumina_types::state::Role;
+
+    let table = [
+        (ExecutionError::InvalidSignature, "INVALID_SIGNATURE"),
+        (
+            ExecutionError::GasPriceTooLow { price: 0, min: 1 },
+            "GAS_PRICE_TOO_LOW",
+        ),
+        (
+            ExecutionError::OutOfGas {
+                required: 2,
+                limit: 1,
+            },
+            "OUT_OF_GAS",
+        ),
+        (
+            ExecutionError::InvalidNonce {
+                expected: 1,
+                got: 0,
+            },
+            "INVALID_NONCE",
+        ),
+        (
+            ExecutionError::InsufficientGasFunds { need: 2, have: 1 },
+            "INSUFFICIENT_GAS_FUNDS",
+        ),
+        (
+            ExecutionError::InsufficientBalance {
+                asset: AssetType::LUSD,
+            },
+            "INSUFFICIENT_BALANCE",
+        ),
+        (
+            ExecutionError::InsufficientCollateral {
+                required: 2,
+                provided: 1,
+            },
+            "INSUFFICIENT_COLLATERAL",
+        ),
+        (ExecutionError::overflow("x"), "OVERFLOW"),
+        (ExecutionError::underflow("x"), "UNDERFLOW"),
+        (ExecutionError::ZeroAmount, "ZERO_AMOUNT"),
+        (ExecutionError::invalid_argument("x"), "INVALID_ARGUMENT"),
+        (
+            ExecutionError::CircuitBreakerActive,
+            "CIRCUIT_BREAKER_ACTIVE",
+        ),
+        (
+            ExecutionError::InvalidProof {
+                kind: ProofKind::Reserve,
+            },
+            "INVALID_PROOF",
+        ),
+        (ExecutionError::replay("x"), "REPLAY"),
+        (ExecutionError::not_found("x"), "NOT_FOUND"),
+        (ExecutionError::unauthorized("x"), "UNAUTHORIZED"),
+        (ExecutionError::precondition("x"), "PRECONDITION_FAILED"),
+        (
+            ExecutionError::MissingRole {
+                role: Role::Treasury,
+            },
+            "MISSING_ROLE",
+        ),
+        (
+            ExecutionError::BundleInstructionFailed {
+                index: 0,
+                error: Box::new(ExecutionError::ZeroAmount),
+            },
+            "BUNDLE_INSTRUCTION_FAILED",
+        ),
+    ];
+    for (error, code) in table {
+        assert_eq!(error.code(), code, "{error:?}");
+    }
+}
+
+#[test]
+fn test_receipt_hash_commits_to_error_code_not_message() {
+    let failed = |error| Receipt {
+        tx_id: [1u8; 32],
+        status: ReceiptStatus::Failed { error },
+        gas_used: 100,
+        events: Vec::new(),
+    };
+    let bundled = |reason| ExecutionError::BundleInstructionFailed {
+        index: 1,
+        error: Box::new(ExecutionError::precondition(reason)),
+    };
+
+    // Rewording a message leaves the commitment unchanged
+    assert_eq!(
+        failed(ExecutionError::precondition("Vault is unsafe")).hash(),
+        failed(ExecutionError::precondition(
+            "Vault would be undercollateralized"
+        ))
+        .hash()
+    );
+    assert_eq!(
+        failed(bundled("old wording")).hash(),
+        failed(bundled("new wording")).hash()
+    );
+
+    // Codes and typed fields are committed
+    assert_ne!(
+        failed(ExecutionError::precondition("x")).hash(),
+        failed(ExecutionError::unauthorized("x")).hash()
+    );
+    assert_ne!(
+        failed(ExecutionError::OutOfGas {
+            required: 2,
+            limit: 1
+        })
+        .hash(),
+        failed(ExecutionError::OutOfGas {
+            required: 3,
+            limit: 1
+        })
+        .hash()
+    );
+}
+
+#[test]
+fn test_redemption_events_and_receipts_root() {
+    let mut state = GlobalState::default();
+    let sender = [1u8; 32];
+
+    state.accounts.insert(
+        sender,
+        AccountState {
+            lusd_balance: 5000,
+            ..Default::default()
+        },
+    );
+    state.total_lusd_supply = 5000;
+    state.reserve_ratio = 0.90;
+
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+    let queued = execute_si(
+        &StablecoinInstruction::RedeemSenior { amount: 1000 },
+        &sender,
+        &mut ctx,
+    )
+    .unwrap();
+    assert_eq!(
+        queued,
+        vec![Event::RedemptionQueued {
+            request_id: 0,
+            account: sender,
+            amount: 1000,
+        }]
+    );
+
+    let settled = execute_si(
+        &StablecoinInstruction::FairRedeemQueue { batch_size: 1 },
+        &sender,
+        &mut ctx,
+    )
+    .unwrap();
+    assert_eq!(
+        settled,
+        vec![
+            Event::RedemptionSettled {
+                request_id: 0,
+                account: sender,
+                amount: 900,
+                remaining: 100,
+            },
+            Event::CollateralReleased {
+                account: sender,
+                amount: 900,
+            },
+        ]
+    );
+
+    let first = Receipt {
+        tx_id: [1u8; 32],
+        status: ReceiptStatus::Success,
+        gas_used: 100,
+        events: queued,
+    };
+    let second = Receipt {
+        tx_id: [2u8; 32],
+        status: ReceiptStatus::Success,
+        gas_used: 100,
+        events: settled,
+    };
+    let root = lumina_types::block::Block::receipts_root(&[first.clone(), second.clone()]);
+    assert_ne!(root, [0u8; 32]);
+    assert_ne!(
+        root,
+        lumina_types::block::Block::receipts_root(&[second, first])
+    );
 }
 
 #[test]
@@ -233,6 +447,16 @@ fn test_social_recovery_threshold_and_uniqueness() {
 fn test_insurance_fund_mechanics() {
     let mut state = GlobalState::default();
     let sender = [6u8; 32];
+    state.accounts.insert(
+        sender,
+        AccountState {
+            lumina_balance: 1500,
+            ..Default::default()
+        },
+    );
+    state
+        .oracle_prices
+        .insert("LUMINA-USD".to_string(), 1_000_000);
 
     // Mint senior — 5% should go to insurance fund
     let mut ctx = ExecutionContext {
@@ -244,8 +468,9 @@ fn test_insurance_fund_mechanics() {
     let manager = lumina_crypto::zk::ZkManager::setup();
     let si = StablecoinInstruction::MintSenior {
         amount: 1000,
-        collateral_amount: 1200,
-        proof: manager.prove_reserves(vec![1200], 1200),
+        collateral_asset: lumina_types::instruction::AssetType::Lumina,
+        collateral_amount: 1500,
+        proof: manager.prove_reserves(vec![1500], 1500),
     };
     assert!(execute_si(&si, &sender, &mut ctx).is_ok());
 
@@ -253,6 +478,8 @@ fn test_insurance_fund_mechanics() {
     assert_eq!(state.insurance_fund_balance, 50);
     assert_eq!(state.accounts.get(&sender).unwrap().lusd_balance, 950);
     assert_eq!(state.total_lusd_supply, 950);
+    assert_eq!(state.vaults[&sender].debt, 950);
+    assert_eq!(state.vaults[&sender].collateral["Lumina"], 1500);
 }
 
 #[test]
@@ -269,6 +496,7 @@ fn test_yield_token_wrap_unwrap() {
     );
     state.total_lusd_supply = 10000;
     state.stabilization_pool_balance = 10000;
+    state.set_role(lumina_types::state::Role::Treasury, sender, true);
 
     // Wrap
     {
@@ -285,29 +513,119 @@ fn test_yield_token_wrap_unwrap() {
     }
 
     assert_eq!(state.accounts.get(&sender).unwrap().lusd_balance, 5000);
-    assert_eq!(
-        state.accounts.get(&sender).unwrap().yield_positions.len(),
-        1
-    );
+    assert_eq!(state.yield_positions.len(), 1);
+
+    // Yield accrues from distributions; with no LJUN holders the whole junior
+    // share goes to the position
+    {
+        let mut ctx = ExecutionContext {
+            state: &mut state,
+            height: 150,
+            timestamp: 1500,
+        };
+        let si = StablecoinInstruction::DistributeYield { total_yield: 1000 };
+        assert!(execute_si(&si, &sender, &mut ctx).is_ok());
+    }
+    assert_eq!(state.yield_positions[&0].accrued_yield, 800);
 
     // Unwrap (at maturity)
     {
         let mut ctx = ExecutionContext {
             state: &mut state,
-            height: 3_153_800,
+            height: 200,
             timestamp: 2500,
         };
         let si = StablecoinInstruction::UnwrapYieldToken { token_id: 0 };
         assert!(execute_si(&si, &sender, &mut ctx).is_ok());
     }
 
-    // Should have principal back plus net yield, and insurance pool should receive junior-yield cut.
-    assert!(state.accounts.get(&sender).unwrap().lusd_balance > 10000);
-    assert!(state.insurance_fund_balance > 0);
-    assert_eq!(
-        state.accounts.get(&sender).unwrap().yield_positions.len(),
-        0
+    // Principal back plus the accrued yield, minted as LUSD
+    assert_eq!(state.accounts.get(&sender).unwrap().lusd_balance, 10800);
+    assert_eq!(state.total_lusd_supply, 10800);
+    assert!(state.yield_positions.is_empty());
+}
+
+#[test]
+fn test_yield_token_sides_trade_separately() {
+    use lumina_types::instruction::YieldTokenSide;
+
+    let issuer = [32u8; 32];
+    let lender = [33u8; 32];
+    let speculator = [34u8; 32];
+    let junior = [35u8; 32];
+    let mut state = GlobalState::default();
+    state.accounts.insert(
+        issuer,
+        AccountState {
+            lusd_balance: 3_000,
+            ..Default::default()
+        },
+    );
+    state.accounts.insert(
+        junior,
+        AccountState {
+            ljun_balance: 1_000,
+            ..Default::default()
+        },
     );
+    state.total_ljun_supply = 1_000;
+    state.total_lusd_supply = 3_000;
+    state.stabilization_pool_balance = 3_000;
+    state.set_role(lumina_types::state::Role::Treasury, issuer, true);
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 10,
+        timestamp: 100,
+    };
+
+    let wrap = StablecoinInstruction::WrapToYieldToken {
+        amount: 3_000,
+        maturity_blocks: 50,
+    };
+    execute_si(&wrap, &issuer, &mut ctx).unwrap();
+    let send = |side, to| StablecoinInstruction::TransferYieldToken {
+        token_id: 0,
+        side,
+        to,
+    };
+    execute_si(&send(YieldTokenSide::Principal, lender), &issuer, &mut ctx).unwrap();
+    execute_si(&send(YieldTokenSide::Yield, speculator), &issuer, &mut ctx).unwrap();
+    assert!(execute_si(&send(YieldTokenSide::Yield, issuer), &issuer, &mut ctx).is_err());
+    assert_eq!(ctx.state.yield_positions[&0].principal_owner, Some(lender));
+
+    // 3_000 wrapped against 1_000 LJUN: three quarters of the junior share
+    let events = execute_si(
+        &StablecoinInstruction::DistributeYield { total_yield: 1_000 },
+        &issuer,
+        &mut ctx,
+    )
+    .unwrap();
+    assert!(events.contains(&Event::YieldTokensAccrued {
+        amount: 600,
+        positions: 1,
+    }));
+    assert_eq!(ctx.state.accounts[&junior].ljun_balance, 1_200);
+
+    let claim = StablecoinInstruction::ClaimYield { token_id: 0 };
+    let unwrap = StablecoinInstruction::UnwrapYieldToken { token_id: 0 };
+    assert!(execute_si(&claim, &speculator, &mut ctx).is_err());
+    assert!(execute_si(&unwrap, &lender, &mut ctx).is_err());
+
+    // Nothing accrues after maturity; each side redeems on its own
+    ctx.height = 60;
+    execute_si(
+        &StablecoinInstruction::DistributeYield { total_yield: 1_000 },
+        &issuer,
+        &mut ctx,
+    )
+    .unwrap();
+    assert!(execute_si(&claim, &lender, &mut ctx).is_err());
+    execute_si(&claim, &speculator, &mut ctx).unwrap();
+    assert_eq!(ctx.state.accounts[&speculator].lusd_balance, 600);
+    execute_si(&unwrap, &lender, &mut ctx).unwrap();
+    assert_eq!(ctx.state.accounts[&lender].lusd_balance, 3_000);
+    assert!(ctx.state.yield_positions.is_empty());
+    assert!(execute_si(&claim, &speculator, &mut ctx).is_err());
 }
 
 #[test]
@@ -345,6 +663,8 @@ fn test_parallel_non_conflicting_transfers() {
 
     state.accounts.entry(s1).or_default().lusd_balance = 100;
     state.accounts.entry(s2).or_default().lusd_balance = 100;
+    state.accounts.entry(s1).or_default().lumina_balance = 1_000_000;
+    state.accounts.entry(s2).or_default().lumina_balance = 1_000_000;
 
     let mut tx1 = Transaction {
         sender: s1,
@@ -386,6 +706,285 @@ fn test_parallel_non_conflicting_transfers() {
     assert_eq!(ctx.state.accounts.get(&r2).unwrap().lusd_balance, 20);
 }
 
+#[test]
+fn test_gas_prepaid_and_unused_gas_refunded() {
+    let mut state = GlobalState::default();
+    let (sender, kp) = new_sender();
+    let receiver = [31u8; 32];
+
+    state.accounts.insert(
+        sender,
+        AccountState {
+            lusd_balance: 100,
+            lumina_balance: 10_000,
+            ..Default::default()
+        },
+    );
+
+    let mut tx = Transaction {
+        sender,
+        nonce: 0,
+        instruction: StablecoinInstruction::Transfer {
+            to: receiver,
+            amount: 40,
+            asset: lumina_types::instruction::AssetType::LUSD,
+        },
+        signature: vec![],
+        gas_limit: 5_000,
+        gas_price: 2,
+    };
+    tx.signature = lumina_crypto::signatures::sign(&kp, &tx.signing_bytes());
+
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+    let receipt = execute_transaction(&tx, &mut ctx).unwrap();
+    let gas_used = receipt.gas_used;
+
+    assert!(receipt.is_success());
+    assert_eq!(receipt.tx_id, tx.id());
+    assert_eq!(
+        receipt.events,
+        vec![Event::Transferred {
+            from: sender,
+            to: receiver,
+            asset: lumina_types::instruction::AssetType::LUSD,
+            amount: 40,
+        }]
+    );
+    assert_eq!(gas_used, gas::TX_BASE_GAS + gas::TRANSFER_GAS);
+    let acct = state.accounts.get(&sender).unwrap();
+    assert_eq!(acct.lumina_balance, 10_000 - gas_used * 2);
+    assert_eq!(acct.lusd_balance, 60);
+    assert_eq!(state.gas_fee_pool, gas_used * 2);
+}
+
+#[test]
+fn test_out_of_gas_fails_and_unfunded_gas_is_rejected() {
+    let mut state = GlobalState::default();
+    let (sender, kp) = new_sender();
+
+    state.accounts.insert(
+        sender,
+        AccountState {
+            lusd_balance: 100,
+            lumina_balance: 1_000,
+            ..Default::default()
+        },
+    );
+
+    let transfer = StablecoinInstruction::Transfer {
+        to: [32u8; 32],
+        amount: 10,
+        asset: lumina_types::instruction::AssetType::LUSD,
+    };
+
+    // gas_limit below the schedule cost: included as failed, whole budget charged
+    let mut tx = Transaction {
+        sender,
+        nonce: 0,
+        instruction: transfer.clone(),
+        signature: vec![],
+        gas_limit: gas::TX_BASE_GAS,
+        gas_price: 1,
+    };
+    tx.signature = lumina_crypto::signatures::sign(&kp, &tx.signing_bytes());
+    {
+        let mut ctx = ExecutionContext {
+            state: &mut state,
+            height: 1,
+            timestamp: 100,
+        };
+        let receipt = execute_transaction(&tx, &mut ctx).unwrap();
+        assert!(!receipt.is_success());
+        assert_eq!(
+            receipt.error(),
+            Some(&ExecutionError::OutOfGas {
+                required: gas::TX_BASE_GAS + gas::TRANSFER_GAS,
+                limit: gas::TX_BASE_GAS,
+            })
+        );
+        assert_eq!(receipt.gas_used, gas::TX_BASE_GAS);
+        assert!(receipt.events.is_empty());
+    }
+
+    // gas_limit * gas_price exceeds the Lumina balance: invalid, nothing charged
+    let mut tx = Transaction {
+        sender,
+        nonce: 1,
+        instruction: transfer,
+        signature: vec![],
+        gas_limit: 2_000,
+        gas_price: 1,
+    };
+    tx.signature = lumina_crypto::signatures::sign(&kp, &tx.signing_bytes());
+    {
+        let mut ctx = ExecutionContext {
+            state: &mut state,
+            height: 1,
+            timestamp: 100,
+        };
+        let err = execute_transaction(&tx, &mut ctx).unwrap_err();
+        assert_eq!(
+            err,
+            ExecutionError::InsufficientGasFunds {
+                need: 2_000,
+                have: 1_000 - gas::TX_BASE_GAS,
+            }
+        );
+        assert_eq!(err.code(), "INSUFFICIENT_GAS_FUNDS");
+    }
+
+    let acct = state.accounts.get(&sender).unwrap();
+    assert_eq!(acct.nonce, 1);
+    assert_eq!(acct.lumina_balance, 1_000 - gas::TX_BASE_GAS);
+    assert_eq!(acct.lusd_balance, 100);
+    assert_eq!(state.gas_fee_pool, gas::TX_BASE_GAS);
+
+    // A rejected transaction from an unused address leaves state untouched,
+    // so block builders and importers agree on the root
+    let (stranger, stranger_kp) = new_sender();
+    let mut tx = Transaction {
+        sender: stranger,
+        nonce: 0,
+        instruction: StablecoinInstruction::Shield { amount: 1 },
+        signature: vec![],
+        gas_limit: 2_000,
+        gas_price: 1,
+    };
+    tx.signature = lumina_crypto::signatures::sign(&stranger_kp, &tx.signing_bytes());
+    let root = state.root_hash();
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+    assert!(execute_transaction(&tx, &mut ctx).is_err());
+    assert!(!state.accounts.contains_key(&stranger));
+    assert_eq!(state.root_hash(), root);
+}
+
+#[test]
+fn test_state_checkpoint_restores_journaled_and_plain_fields() {
+    let alice = [40u8; 32];
+    let bob = [41u8; 32];
+    let mut state = GlobalState {
+        total_lusd_supply: 500,
+        ..Default::default()
+    };
+    state.accounts.insert(
+        alice,
+        AccountState {
+            lusd_balance: 500,
+            ..Default::default()
+        },
+    );
+    state.accounts.insert(bob, AccountState::default());
+    let root = state.root_hash();
+
+    let checkpoint = state.checkpoint();
+    state.accounts.get_mut(&alice).unwrap().lusd_balance = 0;
+    state.accounts.remove(&bob);
+    state.accounts.entry([42u8; 32]).or_default().nonce = 7;
+    state.validators.push(Default::default());
+    state.set_role(lumina_types::state::Role::Oracle, alice, true);
+    state.total_lusd_supply = 0;
+    state.rollback(checkpoint);
+    assert_eq!(state.root_hash(), root);
+    assert_eq!(state.accounts.len(), 2);
+    assert!(state.validators.is_empty());
+    assert!(!state.has_role(lumina_types::state::Role::Oracle, &alice));
+    assert_eq!(state.total_lusd_supply, 500);
+
+    let checkpoint = state.checkpoint();
+    state.accounts.get_mut(&alice).unwrap().lusd_balance = 100;
+    state.validators.push(Default::default());
+    state.commit(checkpoint);
+    assert_eq!(state.accounts[&alice].lusd_balance, 100);
+    assert_eq!(state.validators.len(), 1);
+
+    // Committed writes are no longer journaled
+    let checkpoint = state.checkpoint();
+    state.rollback(checkpoint);
+    assert_eq!(state.accounts[&alice].lusd_balance, 100);
+    assert_eq!(state.validators.len(), 1);
+}
+
+#[test]
+fn test_failed_instruction_rolls_back_but_charges_fee() {
+    let mut state = GlobalState::default();
+    let (sender, kp) = new_sender();
+    let receiver = [33u8; 32];
+
+    state.accounts.insert(
+        sender,
+        AccountState {
+            lusd_balance: 100,
+            lumina_balance: 10_000,
+            ..Default::default()
+        },
+    );
+    // The sender is debited before the receiver credit overflows
+    state.accounts.insert(
+        receiver,
+        AccountState {
+            lusd_balance: u64::MAX,
+            ..Default::default()
+        },
+    );
+
+    let mut tx = Transaction {
+        sender,
+        nonce: 0,
+        instruction: StablecoinInstruction::Transfer {
+            to: receiver,
+            amount: 10,
+            asset: lumina_types::instruction::AssetType::LUSD,
+        },
+        signature: vec![],
+        gas_limit: 5_000,
+        gas_price: 1,
+    };
+    tx.signature = lumina_crypto::signatures::sign(&kp, &tx.signing_bytes());
+
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+    let receipt = execute_transaction(&tx, &mut ctx).unwrap();
+
+    assert_eq!(
+        receipt.status,
+        ReceiptStatus::Failed {
+            error: ExecutionError::overflow("Balance")
+        }
+    );
+    let fee = gas::TX_BASE_GAS + gas::TRANSFER_GAS;
+    assert_eq!(receipt.gas_used, fee);
+
+    let acct = state.accounts.get(&sender).unwrap();
+    assert_eq!(acct.nonce, 1);
+    assert_eq!(acct.lusd_balance, 100);
+    assert_eq!(acct.epoch_tx_volume, 0);
+    assert_eq!(acct.lumina_balance, 10_000 - fee);
+    assert_eq!(
+        state.accounts.get(&receiver).unwrap().lusd_balance,
+        u64::MAX
+    );
+    assert_eq!(state.gas_fee_pool, fee);
+
+    // A replay of the failed transaction is still rejected by the nonce
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+    assert!(execute_transaction(&tx, &mut ctx).is_err());
+}
+
 #[test]
 fn test_flash_mint_and_flash_burn_same_block() {
     let mut state = GlobalState::default();
@@ -395,6 +994,10 @@ fn test_flash_mint_and_flash_burn_same_block() {
     state.stabilization_pool_balance = 1_000_000;
     state.total_lusd_supply = 1_000_000;
     state.reserve_ratio = 1.0;
+    state
+        .oracle_prices
+        .insert("LUMINA-USD".to_string(), 1_000_000);
+    state.accounts.entry(sender).or_default().lumina_balance = 1500;
 
     // Flash mint
     {
@@ -406,14 +1009,16 @@ fn test_flash_mint_and_flash_burn_same_block() {
         let si = StablecoinInstruction::FlashMint {
             amount: 1000,
             collateral_asset: lumina_types::instruction::AssetType::Lumina,
-            collateral_amount: 1200,
+            collateral_amount: 1500,
             commitment: [9u8; 32],
         };
         execute_si(&si, &sender, &mut ctx).unwrap();
         assert_eq!(ctx.state.pending_flash_mints, 1000);
         let acct = ctx.state.accounts.get(&sender).unwrap();
         assert_eq!(acct.pending_flash_mint, 1000);
-        assert_eq!(acct.pending_flash_collateral, 1200);
+        assert_eq!(acct.pending_flash_collateral, 1500);
+        assert_eq!(acct.lumina_balance, 0);
+        assert_eq!(ctx.state.vaults[&sender].debt, 1000);
     }
 
     // Must burn full amount
@@ -429,6 +1034,8 @@ fn test_flash_mint_and_flash_burn_same_block() {
         let acct = ctx.state.accounts.get(&sender).unwrap();
         assert_eq!(acct.pending_flash_mint, 0);
         assert_eq!(acct.pending_flash_collateral, 0);
+        assert_eq!(acct.lumina_balance, 1500);
+        assert!(!ctx.state.vaults.contains_key(&sender));
     }
 }
 
@@ -439,6 +1046,10 @@ fn test_flash_burn_rejects_partial_repayment_fail_closed() {
 
     state.stabilization_pool_balance = 1_000_000;
     state.total_lusd_supply = 1_000_000;
+    state
+        .oracle_prices
+        .insert("LUMINA-USD".to_string(), 1_000_000);
+    state.accounts.entry(sender).or_default().lumina_balance = 1_500;
 
     {
         let mut ctx = ExecutionContext {
@@ -449,7 +1060,7 @@ fn test_flash_burn_rejects_partial_repayment_fail_closed() {
         let flash = StablecoinInstruction::FlashMint {
             amount: 1_000,
             collateral_asset: lumina_types::instruction::AssetType::Lumina,
-            collateral_amount: 1_100,
+            collateral_amount: 1_500,
             commitment: [1u8; 32],
         };
         execute_si(&flash, &sender, &mut ctx).unwrap();
@@ -463,6 +1074,7 @@ fn test_flash_burn_rejects_partial_repayment_fail_closed() {
     let partial_burn = StablecoinInstruction::FlashBurn { amount: 999 };
     let err = execute_si(&partial_burn, &sender, &mut ctx).unwrap_err();
 
+    assert_eq!(err.code(), "PRECONDITION_FAILED");
     assert!(err
         .to_string()
         .contains("Flash burn must burn full pending flash mint in this block"));
@@ -475,6 +1087,10 @@ fn test_flash_burn_rejects_when_balance_missing_fail_closed() {
 
     state.stabilization_pool_balance = 1_000_000;
     state.total_lusd_supply = 1_000_000;
+    state
+        .oracle_prices
+        .insert("LUMINA-USD".to_string(), 1_000_000);
+    state.accounts.entry(sender).or_default().lumina_balance = 750;
 
     {
         let mut ctx = ExecutionContext {
@@ -485,7 +1101,7 @@ fn test_flash_burn_rejects_when_balance_missing_fail_closed() {
         let flash = StablecoinInstruction::FlashMint {
             amount: 500,
             collateral_asset: lumina_types::instruction::AssetType::Lumina,
-            collateral_amount: 550,
+            collateral_amount: 750,
             commitment: [2u8; 32],
         };
         execute_si(&flash, &sender, &mut ctx).unwrap();
@@ -507,7 +1123,12 @@ fn test_flash_burn_rejects_when_balance_missing_fail_closed() {
     let burn = StablecoinInstruction::FlashBurn { amount: 500 };
     let err = execute_si(&burn, &sender, &mut ctx).unwrap_err();
 
-    assert!(err.to_string().contains("Insufficient LUSD for flash burn"));
+    assert_eq!(
+        err,
+        ExecutionError::InsufficientBalance {
+            asset: lumina_types::instruction::AssetType::LUSD,
+        }
+    );
 }
 
 #[test]
@@ -603,6 +1224,7 @@ fn test_rwa_listing_and_pledge() {
         };
         let list = StablecoinInstruction::ListRWA {
             asset_description: "invoice #123".to_string(),
+            asset_class: lumina_types::state::RwaAssetClass::Invoice,
             attested_value,
             attestation_proof: proof,
             maturity_date: Some(1_000_000),
@@ -631,15 +1253,211 @@ fn test_rwa_listing_and_pledge() {
 }
 
 #[test]
-fn test_submit_zk_por_requires_valid_proof_and_no_replay() {
-    let mut state = GlobalState::default();
-    let sender = [12u8; 32];
-    let manager = lumina_crypto::zk::ZkManager::setup();
-    let proof = manager.prove_reserves(vec![40, 60], 100);
+fn test_rwa_repay_unpledge_delist_and_default() {
+    let owner = [36u8; 32];
+    let keeper = [37u8; 32];
+    let mut state = GlobalState {
+        insurance_fund_balance: 1_000,
+        ..Default::default()
+    };
 
-    {
-        let mut ctx = ExecutionContext {
-            state: &mut state,
+    let manager = lumina_crypto::zk::ZkManager::setup();
+    let attested_value = 5_000u64;
+    let list = |maturity_date| StablecoinInstruction::ListRWA {
+        asset_description: "receivable".to_string(),
+        asset_class: lumina_types::state::RwaAssetClass::Invoice,
+        attested_value,
+        attestation_proof: bound_proof(
+            *blake3::hash(&attested_value.to_le_bytes()).as_bytes(),
+            manager.prove_range(1, 10),
+        ),
+        maturity_date: Some(maturity_date),
+        collateral_eligibility: true,
+    };
+    let pledge = |rwa_id| StablecoinInstruction::UseRWAAsCollateral {
+        rwa_id,
+        amount_to_pledge: 3_000,
+    };
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+    execute_si(&list(1_000), &owner, &mut ctx).unwrap();
+    execute_si(&list(500), &owner, &mut ctx).unwrap();
+    execute_si(&list(500), &owner, &mut ctx).unwrap();
+    assert!(execute_si(&pledge(0), &keeper, &mut ctx).is_err());
+    execute_si(&pledge(0), &owner, &mut ctx).unwrap();
+    execute_si(&pledge(1), &owner, &mut ctx).unwrap();
+    assert_eq!(ctx.state.total_lusd_supply, 6_000);
+
+    // Collateral cannot be released while it backs debt, nor delisted
+    let unpledge = StablecoinInstruction::UnpledgeRWA {
+        rwa_id: 0,
+        amount: 3_000,
+    };
+    let delist = StablecoinInstruction::DelistRWA { rwa_id: 0 };
+    assert!(execute_si(&unpledge, &owner, &mut ctx).is_err());
+    assert!(execute_si(&delist, &owner, &mut ctx).is_err());
+    assert!(execute_si(
+        &StablecoinInstruction::LiquidateRWA { rwa_id: 0 },
+        &keeper,
+        &mut ctx
+    )
+    .is_err());
+
+    let repay = StablecoinInstruction::RepayRWALoan {
+        rwa_id: 0,
+        amount: 3_000,
+    };
+    execute_si(&repay, &owner, &mut ctx).unwrap();
+    assert_eq!(ctx.state.rwa_listings[&0].debt, 0);
+    assert_eq!(ctx.state.total_lusd_supply, 3_000);
+    assert!(execute_si(&repay, &owner, &mut ctx).is_err());
+    execute_si(&unpledge, &owner, &mut ctx).unwrap();
+    execute_si(&delist, &owner, &mut ctx).unwrap();
+    assert!(!ctx.state.rwa_listings.contains_key(&0));
+    assert!(!ctx.state.rwa_maturities.contains_key(&1_000));
+
+    // At maturity the unpaid listing 1 defaults and the unpledged listing 2
+    // matures; both are settled at end of block and pruned
+    ctx.timestamp = 500;
+    let pool_before = ctx.state.stabilization_pool_balance;
+    let events = end_block(&mut ctx);
+    assert!(events.contains(&Event::RwaLiquidated {
+        rwa_id: 1,
+        owner,
+        attested_value,
+        debt: 3_000,
+        insurance_covered: 1_000,
+    }));
+    assert!(events.contains(&Event::RwaMatured { rwa_id: 2 }));
+    assert!(ctx.state.rwa_listings.is_empty());
+    assert!(ctx.state.rwa_maturities.is_empty());
+    assert_eq!(ctx.state.insurance_fund_balance, 0);
+    assert_eq!(ctx.state.stabilization_pool_balance, pool_before - 2_000);
+
+    // The claim went to the insurance fund, so the defaulted owner can't touch it
+    let delist = StablecoinInstruction::DelistRWA { rwa_id: 1 };
+    assert!(execute_si(&delist, &owner, &mut ctx).is_err());
+}
+
+#[test]
+fn test_rwa_revaluation_haircuts_and_staleness() {
+    use lumina_types::state::{ProposalAction, Role, RwaAssetClass, RwaClassParams};
+
+    let owner = [38u8; 32];
+    let attestor = [39u8; 32];
+    let validator = [40u8; 32];
+    let mut state = governance_state(&[(validator, 100)]);
+    state.set_role(Role::RwaAttestor, attestor, true);
+
+    let manager = lumina_crypto::zk::ZkManager::setup();
+    let attested_value = 10_000u64;
+    let list = StablecoinInstruction::ListRWA {
+        asset_description: "office building".to_string(),
+        asset_class: RwaAssetClass::RealEstate,
+        attested_value,
+        attestation_proof: bound_proof(
+            *blake3::hash(&attested_value.to_le_bytes()).as_bytes(),
+            manager.prove_range(1, 10),
+        ),
+        maturity_date: None,
+        collateral_eligibility: true,
+    };
+    let pledge = |amount_to_pledge| StablecoinInstruction::UseRWAAsCollateral {
+        rwa_id: 0,
+        amount_to_pledge,
+    };
+    let revalue = |attested_value| StablecoinInstruction::RevalueRWA {
+        rwa_id: 0,
+        attested_value,
+    };
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+    execute_si(&list, &owner, &mut ctx).unwrap();
+
+    // Real estate: 30% haircut, 60% LTV -> 10_000 backs at most 4_200
+    assert!(execute_si(&pledge(4_201), &owner, &mut ctx).is_err());
+    execute_si(&pledge(4_000), &owner, &mut ctx).unwrap();
+
+    // Only attestors revalue; a drop below the debt after haircut defaults
+    assert!(matches!(
+        execute_si(&revalue(5_000), &owner, &mut ctx),
+        Err(ExecutionError::MissingRole {
+            role: Role::RwaAttestor
+        })
+    ));
+    let liquidate = StablecoinInstruction::LiquidateRWA { rwa_id: 0 };
+    assert!(execute_si(&liquidate, &owner, &mut ctx).is_err());
+    ctx.timestamp = 200;
+    let events = execute_si(&revalue(20_000), &attestor, &mut ctx).unwrap();
+    assert_eq!(
+        events,
+        vec![Event::RwaRevalued {
+            rwa_id: 0,
+            attestor,
+            attested_value: 20_000,
+        }]
+    );
+    assert_eq!(ctx.state.rwa_listings[&0].valued_at, 200);
+    execute_si(&pledge(4_400), &owner, &mut ctx).unwrap();
+
+    // A stale valuation stops further pledging until it is refreshed
+    ctx.timestamp = 200 + 180 * 86_400 + 1;
+    assert!(execute_si(&pledge(1), &owner, &mut ctx).is_err());
+    execute_si(&revalue(20_010), &attestor, &mut ctx).unwrap();
+    execute_si(&pledge(1), &owner, &mut ctx).unwrap();
+
+    // Governance tightens the class; out-of-range parameters are rejected
+    let params = RwaClassParams {
+        haircut_bps: 6_000,
+        max_ltv_bps: 5_000,
+        max_valuation_age: 86_400,
+    };
+    let propose = |params| StablecoinInstruction::SubmitProposal {
+        action: ProposalAction::SetRwaClassParams {
+            class: RwaAssetClass::RealEstate,
+            params,
+        },
+    };
+    let bad = RwaClassParams {
+        haircut_bps: 10_001,
+        ..params.clone()
+    };
+    assert!(execute_si(&propose(bad), &validator, &mut ctx).is_err());
+    execute_si(&propose(params.clone()), &validator, &mut ctx).unwrap();
+    let vote = StablecoinInstruction::Vote {
+        proposal_id: 0,
+        approve: true,
+    };
+    execute_si(&vote, &validator, &mut ctx).unwrap();
+    let execute = StablecoinInstruction::ExecuteProposal { proposal_id: 0 };
+    execute_si(&execute, &validator, &mut ctx).unwrap();
+    assert_eq!(
+        ctx.state.rwa_class_params(RwaAssetClass::RealEstate),
+        params
+    );
+    assert!(execute_si(&pledge(1), &owner, &mut ctx).is_err());
+
+    // 8_401 of debt now exceeds the 8_004 haircut value
+    execute_si(&liquidate, &owner, &mut ctx).unwrap();
+    assert!(!ctx.state.rwa_listings.contains_key(&0));
+}
+
+#[test]
+fn test_submit_zk_por_requires_valid_proof_and_no_replay() {
+    let mut state = GlobalState::default();
+    let sender = [12u8; 32];
+    let manager = lumina_crypto::zk::ZkManager::setup();
+    let proof = manager.prove_reserves(vec![40, 60], 100);
+
+    {
+        let mut ctx = ExecutionContext {
+            state: &mut state,
             height: 1,
             timestamp: 100,
         };
@@ -666,12 +1484,42 @@ fn test_submit_zk_por_requires_valid_proof_and_no_replay() {
     }
 }
 
+fn signed_order(
+    maker: &([u8; 32], lumina_crypto::signatures::SigningKey),
+    side: lumina_types::instruction::OrderSide,
+    limit_price: u64,
+    quantity: u64,
+) -> lumina_types::instruction::SignedOrder {
+    use lumina_types::instruction::{AssetType, LimitOrder, SignedOrder};
+
+    let order = LimitOrder {
+        maker: maker.0,
+        base: AssetType::LJUN,
+        quote: AssetType::LUSD,
+        side,
+        limit_price,
+        quantity,
+        expires_at: 100,
+        salt: 0,
+    };
+    let signature = lumina_crypto::signatures::sign(&maker.1, &order.id());
+    SignedOrder { order, signature }
+}
+
 #[test]
 fn test_zero_slip_batch_match_blocks_duplicates_and_replay() {
+    use lumina_types::instruction::OrderSide;
+
     let mut state = GlobalState::default();
     let sender = [13u8; 32];
+    let (buyer, seller) = (new_sender(), new_sender());
+    state.accounts.entry(buyer.0).or_default().lusd_balance = 1_000;
+    state.accounts.entry(seller.0).or_default().ljun_balance = 1_000;
 
-    let orders = vec![[1u8; 32], [2u8; 32], [3u8; 32]];
+    let orders = vec![
+        signed_order(&buyer, OrderSide::Buy, 1_000_000, 10),
+        signed_order(&seller, OrderSide::Sell, 1_000_000, 10),
+    ];
     {
         let mut ctx = ExecutionContext {
             state: &mut state,
@@ -702,11 +1550,119 @@ fn test_zero_slip_batch_match_blocks_duplicates_and_replay() {
             height: 3,
             timestamp: 300,
         };
+        let order = signed_order(&buyer, OrderSide::Buy, 1_000_000, 5);
         let dup = StablecoinInstruction::ZeroSlipBatchMatch {
-            orders: vec![[9u8; 32], [9u8; 32]],
+            orders: vec![order.clone(), order],
         };
         assert!(execute_si(&dup, &sender, &mut ctx).is_err());
     }
+
+    // A partially filled order stays open for later batches until it is filled
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 4,
+        timestamp: 400,
+    };
+    let buy = signed_order(&buyer, OrderSide::Buy, 1_000_000, 8);
+    for sell_quantity in [3, 5] {
+        let batch = StablecoinInstruction::ZeroSlipBatchMatch {
+            orders: vec![
+                buy.clone(),
+                signed_order(&seller, OrderSide::Sell, 1_000_000, sell_quantity),
+            ],
+        };
+        assert!(execute_si(&batch, &sender, &mut ctx).is_ok());
+    }
+    assert_eq!(ctx.state.order_fills[&buy.order.id()].filled, 8);
+}
+
+#[test]
+fn test_batch_auction_uniform_clearing_price_and_fills() {
+    use lumina_types::instruction::{AssetType, OrderSide};
+
+    let relayer = [14u8; 32];
+    let (a, b, c, d) = (new_sender(), new_sender(), new_sender(), new_sender());
+    let mut state = GlobalState::default();
+    for (maker, lusd, ljun) in [(&a, 1_000, 0), (&b, 1_000, 0), (&c, 0, 100), (&d, 0, 100)] {
+        let acct = state.accounts.entry(maker.0).or_default();
+        acct.lusd_balance = lusd;
+        acct.ljun_balance = ljun;
+    }
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+
+    // Volume is 100 at both 1.05 and 1.10 with equal imbalance; the lower price wins
+    let d_sell = signed_order(&d, OrderSide::Sell, 1_050_000, 60);
+    let batch = StablecoinInstruction::ZeroSlipBatchMatch {
+        orders: vec![
+            signed_order(&a, OrderSide::Buy, 1_100_000, 100),
+            signed_order(&b, OrderSide::Buy, 1_000_000, 50),
+            signed_order(&c, OrderSide::Sell, 950_000, 80),
+            d_sell.clone(),
+        ],
+    };
+    let events = execute_si(&batch, &relayer, &mut ctx).unwrap();
+    assert!(matches!(
+        events.last(),
+        Some(Event::BatchMatched {
+            clearing_price: 1_050_000,
+            volume: 100,
+            order_count: 4,
+            ..
+        })
+    ));
+    let fills: Vec<(u64, u64)> = events
+        .iter()
+        .filter_map(|e| match e {
+            Event::OrderFilled {
+                base_amount,
+                quote_amount,
+                price,
+                ..
+            } => {
+                assert_eq!(*price, 1_050_000);
+                Some((*base_amount, *quote_amount))
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(fills, vec![(100, 105), (80, 84), (20, 21)]);
+
+    let balances = |ctx: &ExecutionContext, who: &([u8; 32], _)| {
+        let acct = &ctx.state.accounts[&who.0];
+        (acct.lusd_balance, acct.ljun_balance)
+    };
+    assert_eq!(balances(&ctx, &a), (895, 100));
+    assert_eq!(balances(&ctx, &b), (1_000, 0));
+    assert_eq!(balances(&ctx, &c), (84, 20));
+    assert_eq!(balances(&ctx, &d), (21, 80));
+
+    // The rest of d's order stays open for a later batch, then is spent
+    let next = |buy_qty: u64, price: u64| StablecoinInstruction::ZeroSlipBatchMatch {
+        orders: vec![
+            signed_order(&b, OrderSide::Buy, price, buy_qty),
+            d_sell.clone(),
+        ],
+    };
+    assert!(execute_si(&next(40, 1_000_000), &relayer, &mut ctx).is_err());
+    execute_si(&next(40, 1_050_000), &relayer, &mut ctx).unwrap();
+    assert_eq!(balances(&ctx, &d), (63, 40));
+    assert!(execute_si(&next(41, 1_060_000), &relayer, &mut ctx).is_err());
+
+    // Orders must carry their maker's signature and share one pair
+    let mut forged = signed_order(&c, OrderSide::Sell, 900_000, 10);
+    forged.order.maker = a.0;
+    let mut other_pair = signed_order(&c, OrderSide::Sell, 900_000, 10);
+    other_pair.order.base = AssetType::Lumina;
+    for bad in [forged, other_pair] {
+        let batch = StablecoinInstruction::ZeroSlipBatchMatch {
+            orders: vec![signed_order(&b, OrderSide::Buy, 1_000_000, 10), bad],
+        };
+        assert!(execute_si(&batch, &relayer, &mut ctx).is_err());
+    }
 }
 
 #[test]
@@ -715,16 +1671,23 @@ fn test_custom_asset_transfer_and_burn() {
     let sender = [21u8; 32];
     let receiver = [22u8; 32];
 
-    let mut sender_state = AccountState::default();
-    sender_state.custom_balances.insert("BTC".to_string(), 25);
-    state.accounts.insert(sender, sender_state);
-
     {
         let mut ctx = ExecutionContext {
             state: &mut state,
             height: 1,
             timestamp: 100,
         };
+        let register = StablecoinInstruction::RegisterAsset {
+            ticker: "BTC".to_string(),
+            decimals: 8,
+        };
+        execute_si(&register, &sender, &mut ctx).unwrap();
+        let mint = StablecoinInstruction::MintAsset {
+            ticker: "BTC".to_string(),
+            to: sender,
+            amount: 25,
+        };
+        execute_si(&mint, &sender, &mut ctx).unwrap();
         let si = StablecoinInstruction::Transfer {
             to: receiver,
             amount: 10,
@@ -771,4 +1734,1838 @@ fn test_custom_asset_transfer_and_burn() {
             .copied(),
         Some(10)
     );
+    assert_eq!(state.asset_registry.get("BTC").unwrap().total_supply, 20);
+}
+
+#[test]
+fn test_asset_registry_issuance_caps_and_freezing() {
+    use lumina_types::instruction::AssetType;
+
+    let issuer = [23u8; 32];
+    let holder = [24u8; 32];
+    let mut state = GlobalState::default();
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+    let gold = || "GOLD".to_string();
+    let transfer = |to: [u8; 32], amount: u64| StablecoinInstruction::Transfer {
+        to,
+        amount,
+        asset: AssetType::Custom(gold()),
+    };
+
+    // Unregistered tickers cannot be minted or moved, and native tickers are reserved
+    let mint = |amount: u64| StablecoinInstruction::MintAsset {
+        ticker: gold(),
+        to: holder,
+        amount,
+    };
+    assert!(execute_si(&mint(1), &issuer, &mut ctx).is_err());
+    assert!(execute_si(&transfer(holder, 1), &issuer, &mut ctx).is_err());
+    let lusd = StablecoinInstruction::RegisterAsset {
+        ticker: "LUSD".to_string(),
+        decimals: 6,
+    };
+    assert!(execute_si(&lusd, &issuer, &mut ctx).is_err());
+
+    let register = StablecoinInstruction::RegisterAsset {
+        ticker: gold(),
+        decimals: 6,
+    };
+    execute_si(&register, &issuer, &mut ctx).unwrap();
+    assert!(execute_si(&register, &holder, &mut ctx).is_err());
+    let configure = StablecoinInstruction::ConfigureAsset {
+        ticker: gold(),
+        max_supply: Some(1_000),
+        freeze_authority: Some(issuer),
+    };
+    assert!(execute_si(&configure, &holder, &mut ctx).is_err());
+    execute_si(&configure, &issuer, &mut ctx).unwrap();
+
+    // Only the issuer mints, and never past the cap
+    assert!(execute_si(&mint(600), &holder, &mut ctx).is_err());
+    execute_si(&mint(600), &issuer, &mut ctx).unwrap();
+    assert!(execute_si(&mint(401), &issuer, &mut ctx).is_err());
+    let info = ctx.state.asset_registry.get("GOLD").unwrap();
+    assert_eq!((info.decimals, info.total_supply), (6, 600));
+
+    // A frozen holder can neither send nor receive
+    execute_si(&transfer(issuer, 100), &holder, &mut ctx).unwrap();
+    let freeze = |frozen: bool| StablecoinInstruction::SetAssetFrozen {
+        ticker: gold(),
+        account: holder,
+        frozen,
+    };
+    assert!(execute_si(&freeze(true), &holder, &mut ctx).is_err());
+    execute_si(&freeze(true), &issuer, &mut ctx).unwrap();
+    assert!(execute_si(&transfer(issuer, 1), &holder, &mut ctx).is_err());
+    assert!(execute_si(&transfer(holder, 1), &issuer, &mut ctx).is_err());
+    execute_si(&freeze(false), &issuer, &mut ctx).unwrap();
+
+    // Issuer burns come out of its own balance and shrink supply
+    let burn = |amount: u64| StablecoinInstruction::BurnAsset {
+        ticker: gold(),
+        amount,
+    };
+    assert!(execute_si(&burn(10), &holder, &mut ctx).is_err());
+    assert!(execute_si(&burn(101), &issuer, &mut ctx).is_err());
+    execute_si(&burn(100), &issuer, &mut ctx).unwrap();
+    assert_eq!(
+        ctx.state.asset_registry.get("GOLD").unwrap().total_supply,
+        500
+    );
+    assert_eq!(ctx.state.accounts[&holder].custom_balances["GOLD"], 500);
+}
+
+fn governance_state(powers: &[([u8; 32], u64)]) -> GlobalState {
+    let mut state = GlobalState::default();
+    for (pubkey, power) in powers {
+        state.validators.push(lumina_types::state::ValidatorState {
+            pubkey: *pubkey,
+            operator: *pubkey,
+            stake: *power,
+            power: *power,
+            is_green: false,
+            energy_proof: None,
+            status: lumina_types::state::ValidatorStatus::Active,
+            unbonding: Vec::new(),
+            ..Default::default()
+        });
+    }
+    state
+}
+
+#[test]
+fn test_governance_proposal_executes_at_end_block() {
+    use lumina_types::state::{ProposalAction, ProposalStatus};
+
+    let (a, b, c) = ([1u8; 32], [2u8; 32], [3u8; 32]);
+    let mut state = governance_state(&[(a, 50), (b, 30), (c, 20)]);
+    let oracle = [9u8; 32];
+
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 10,
+        timestamp: 100,
+    };
+    let submit = StablecoinInstruction::SubmitProposal {
+        action: ProposalAction::SetCreditOracle {
+            oracle,
+            trusted: true,
+        },
+    };
+    execute_si(&submit, &a, &mut ctx).unwrap();
+    assert!(execute_si(&submit, &[7u8; 32], &mut ctx).is_err());
+
+    let yes = StablecoinInstruction::Vote {
+        proposal_id: 0,
+        approve: true,
+    };
+    let no = StablecoinInstruction::Vote {
+        proposal_id: 0,
+        approve: false,
+    };
+    execute_si(&yes, &a, &mut ctx).unwrap();
+    execute_si(&yes, &b, &mut ctx).unwrap();
+    execute_si(&no, &c, &mut ctx).unwrap();
+
+    // Nothing is tallied while voting is open
+    let voting_end_height = ctx.state.proposals[&0].voting_end_height;
+    assert!(end_block(&mut ctx).is_empty());
+    assert_eq!(ctx.state.proposals[&0].status, ProposalStatus::Active);
+
+    ctx.height = voting_end_height;
+    assert_eq!(
+        execute_si(&yes, &a, &mut ctx).unwrap_err().code(),
+        "PRECONDITION_FAILED"
+    );
+    let events = end_block(&mut ctx);
+    assert_eq!(
+        events,
+        vec![
+            Event::ProposalFinalized {
+                proposal_id: 0,
+                approved: true,
+                yes_power: 80,
+                no_power: 20,
+            },
+            Event::ProposalExecuted { proposal_id: 0 },
+        ]
+    );
+    assert_eq!(state.proposals[&0].status, ProposalStatus::Executed);
+    assert!(state.trusted_credit_oracles.contains(&oracle));
+}
+
+#[test]
+fn test_governance_quorum_and_early_execution() {
+    use lumina_types::state::{ProposalAction, ProposalStatus};
+
+    let (a, b, c) = ([1u8; 32], [2u8; 32], [3u8; 32]);
+    let mut state = governance_state(&[(a, 20), (b, 70), (c, 10)]);
+
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+    let breaker_on = StablecoinInstruction::SubmitProposal {
+        action: ProposalAction::SetCircuitBreaker { active: true },
+    };
+    execute_si(&breaker_on, &a, &mut ctx).unwrap();
+    execute_si(&breaker_on, &a, &mut ctx).unwrap();
+
+    // Proposal 0: only 20% of power votes, below the 33.34% quorum
+    let vote_a = StablecoinInstruction::Vote {
+        proposal_id: 0,
+        approve: true,
+    };
+    execute_si(&vote_a, &a, &mut ctx).unwrap();
+    let execute_0 = StablecoinInstruction::ExecuteProposal { proposal_id: 0 };
+    assert!(execute_si(&execute_0, &c, &mut ctx).is_err());
+
+    // Proposal 1: 70% of total power approves, so it can execute before voting closes
+    let vote_b = StablecoinInstruction::Vote {
+        proposal_id: 1,
+        approve: true,
+    };
+    execute_si(&vote_b, &b, &mut ctx).unwrap();
+    let execute_1 = StablecoinInstruction::ExecuteProposal { proposal_id: 1 };
+    execute_si(&execute_1, &c, &mut ctx).unwrap();
+    assert!(ctx.state.circuit_breaker_active);
+    assert!(execute_si(&execute_1, &c, &mut ctx).is_err());
+
+    // Early execution drops the proposal from the end-height index
+    let voting_end_height = ctx.state.proposals[&0].voting_end_height;
+    assert_eq!(
+        ctx.state.active_proposals_by_end[&voting_end_height],
+        std::collections::BTreeSet::from([0])
+    );
+
+    ctx.height = voting_end_height;
+    end_block(&mut ctx);
+    assert_eq!(state.proposals[&0].status, ProposalStatus::Rejected);
+    assert_eq!(state.proposals[&1].status, ProposalStatus::Executed);
+    assert!(state.active_proposals_by_end.is_empty());
+}
+
+#[test]
+fn test_protocol_params_change_only_through_governance() {
+    use lumina_types::state::{ProposalAction, ProtocolParams};
+
+    let validator = [1u8; 32];
+    let mut state = governance_state(&[(validator, 100)]);
+    state.accounts.insert(
+        validator,
+        AccountState {
+            lusd_balance: 1_000,
+            ..Default::default()
+        },
+    );
+    state.set_role(lumina_types::state::Role::Treasury, validator, true);
+
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+    let distribute = StablecoinInstruction::DistributeYield { total_yield: 1_000 };
+    assert_eq!(
+        execute_si(&distribute, &validator, &mut ctx).unwrap()[0],
+        Event::YieldDistributed {
+            junior_share: 800,
+            pool_share: 150,
+            insurance_share: 50,
+        }
+    );
+
+    let bad_split = ProtocolParams {
+        yield_junior_bps: 9_000,
+        ..ProtocolParams::default()
+    };
+    let submit_bad = StablecoinInstruction::SubmitProposal {
+        action: ProposalAction::UpdateProtocolParams(bad_split),
+    };
+    assert_eq!(
+        execute_si(&submit_bad, &validator, &mut ctx)
+            .unwrap_err()
+            .code(),
+        "INVALID_ARGUMENT"
+    );
+
+    let retuned = ProtocolParams {
+        yield_junior_bps: 7_000,
+        yield_pool_bps: 2_000,
+        yield_insurance_bps: 1_000,
+        ..ProtocolParams::default()
+    };
+    let submit = StablecoinInstruction::SubmitProposal {
+        action: ProposalAction::UpdateProtocolParams(retuned.clone()),
+    };
+    execute_si(&submit, &validator, &mut ctx).unwrap();
+    let vote = StablecoinInstruction::Vote {
+        proposal_id: 0,
+        approve: true,
+    };
+    execute_si(&vote, &validator, &mut ctx).unwrap();
+    let execute = StablecoinInstruction::ExecuteProposal { proposal_id: 0 };
+    execute_si(&execute, &validator, &mut ctx).unwrap();
+    assert_eq!(ctx.state.protocol_params, retuned);
+
+    assert_eq!(
+        execute_si(&distribute, &validator, &mut ctx).unwrap()[0],
+        Event::YieldDistributed {
+            junior_share: 700,
+            pool_share: 200,
+            insurance_share: 100,
+        }
+    );
+}
+
+#[test]
+fn test_privileged_instructions_require_role() {
+    use lumina_types::error::ExecutionError;
+    use lumina_types::state::Role;
+
+    let guardian = [3u8; 32];
+    let outsider = [4u8; 32];
+    let mut state = GlobalState::default();
+    state.set_role(Role::Guardian, guardian, true);
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+
+    let halt = StablecoinInstruction::RunCircuitBreaker { active: true };
+    assert_eq!(
+        execute_si(&halt, &outsider, &mut ctx).unwrap_err(),
+        ExecutionError::MissingRole {
+            role: Role::Guardian
+        }
+    );
+    assert!(!ctx.state.circuit_breaker_active);
+
+    let oracle = StablecoinInstruction::UpdateOracle {
+        asset: "ETH-USD".to_string(),
+        price: 1,
+        timestamp: 100,
+        signature: vec![],
+    };
+    assert_eq!(
+        execute_si(&oracle, &guardian, &mut ctx).unwrap_err(),
+        ExecutionError::MissingRole { role: Role::Oracle }
+    );
+
+    execute_si(&halt, &guardian, &mut ctx).unwrap();
+    assert!(ctx.state.circuit_breaker_active);
+}
+
+#[test]
+fn test_admin_multisig_updates_roles() {
+    use lumina_types::error::ExecutionError;
+    use lumina_types::state::{AdminMultisig, Role};
+
+    let admins: Vec<_> = (0..3).map(|_| new_sender()).collect();
+    let oracle = [5u8; 32];
+    let mut state = GlobalState {
+        admin_multisig: AdminMultisig {
+            members: admins.iter().map(|(pk, _)| *pk).collect(),
+            threshold: 2,
+            nonce: 0,
+        },
+        ..Default::default()
+    };
+    let message = state
+        .admin_multisig
+        .role_update_message(Role::Oracle, &oracle, true);
+    let sign = |kp| lumina_crypto::signatures::sign(kp, &message);
+
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+
+    // The same member signing twice only counts once
+    let single = StablecoinInstruction::UpdateRoleByAdmin {
+        role: Role::Oracle,
+        account: oracle,
+        granted: true,
+        admin_signatures: vec![sign(&admins[0].1), sign(&admins[0].1)],
+    };
+    assert!(matches!(
+        execute_si(&single, &admins[0].0, &mut ctx),
+        Err(ExecutionError::Unauthorized { .. })
+    ));
+
+    let grant = StablecoinInstruction::UpdateRoleByAdmin {
+        role: Role::Oracle,
+        account: oracle,
+        granted: true,
+        admin_signatures: vec![sign(&admins[0].1), sign(&admins[2].1)],
+    };
+    let events = execute_si(&grant, &admins[0].0, &mut ctx).unwrap();
+    assert_eq!(
+        events,
+        vec![Event::RoleUpdated {
+            role: Role::Oracle,
+            account: oracle,
+            granted: true,
+        }]
+    );
+    assert!(ctx.state.has_role(Role::Oracle, &oracle));
+
+    // Signatures are bound to the admin nonce and cannot be replayed
+    assert!(matches!(
+        execute_si(&grant, &admins[0].0, &mut ctx),
+        Err(ExecutionError::Unauthorized { .. })
+    ));
+}
+
+#[test]
+fn test_governance_grants_role() {
+    use lumina_types::state::{ProposalAction, Role};
+
+    let validator = [1u8; 32];
+    let treasury = [6u8; 32];
+    let mut state = governance_state(&[(validator, 100)]);
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+
+    let submit = StablecoinInstruction::SubmitProposal {
+        action: ProposalAction::SetRole {
+            role: Role::Treasury,
+            account: treasury,
+            granted: true,
+        },
+    };
+    execute_si(&submit, &validator, &mut ctx).unwrap();
+    let vote = StablecoinInstruction::Vote {
+        proposal_id: 0,
+        approve: true,
+    };
+    execute_si(&vote, &validator, &mut ctx).unwrap();
+    let execute = StablecoinInstruction::ExecuteProposal { proposal_id: 0 };
+    execute_si(&execute, &validator, &mut ctx).unwrap();
+
+    assert!(ctx.state.has_role(Role::Treasury, &treasury));
+    let hedge = StablecoinInstruction::DynamicHedge { ratio_bps: 100 };
+    assert!(execute_si(&hedge, &treasury, &mut ctx).is_ok());
+}
+
+#[test]
+fn test_stream_withdraw_and_cancel() {
+    use lumina_types::error::ExecutionError;
+
+    let payer = [7u8; 32];
+    let payee = [8u8; 32];
+    let mut state = GlobalState::default();
+    state.accounts.insert(
+        payer,
+        AccountState {
+            lusd_balance: 10_000,
+            ..Default::default()
+        },
+    );
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 1_000,
+    };
+
+    let open = StablecoinInstruction::StreamPayment {
+        to: payee,
+        amount_per_sec: 10,
+        duration: 100,
+    };
+    execute_si(&open, &payer, &mut ctx).unwrap();
+    execute_si(&open, &payer, &mut ctx).unwrap();
+    assert_eq!(ctx.state.accounts[&payer].lusd_balance, 8_000);
+    assert_eq!(ctx.state.accounts[&payee].incoming_streams, vec![0, 1]);
+
+    let withdraw = StablecoinInstruction::WithdrawStream { stream_id: 0 };
+    assert!(matches!(
+        execute_si(&withdraw, &payer, &mut ctx),
+        Err(ExecutionError::Unauthorized { .. })
+    ));
+    assert_eq!(
+        execute_si(&withdraw, &payee, &mut ctx).unwrap_err(),
+        ExecutionError::ZeroAmount
+    );
+
+    ctx.timestamp = 1_030;
+    let events = execute_si(&withdraw, &payee, &mut ctx).unwrap();
+    assert_eq!(
+        events,
+        vec![Event::StreamWithdrawn {
+            stream_id: 0,
+            recipient: payee,
+            amount: 300,
+        }]
+    );
+
+    // Cancelling stream 0 pays the unclaimed vested part and refunds the rest
+    ctx.timestamp = 1_040;
+    let cancel = StablecoinInstruction::CancelStream { stream_id: 0 };
+    assert!(matches!(
+        execute_si(&cancel, &payee, &mut ctx),
+        Err(ExecutionError::Unauthorized { .. })
+    ));
+    let events = execute_si(&cancel, &payer, &mut ctx).unwrap();
+    assert_eq!(
+        events,
+        vec![Event::StreamClosed {
+            stream_id: 0,
+            paid_to_recipient: 100,
+            refunded_to_sender: 600,
+        }]
+    );
+    assert_eq!(ctx.state.accounts[&payee].lusd_balance, 400);
+    assert_eq!(ctx.state.accounts[&payer].lusd_balance, 8_600);
+    assert_eq!(ctx.state.accounts[&payee].incoming_streams, vec![1]);
+
+    // Stream 1 settles once it has run its course and been fully withdrawn
+    ctx.timestamp = 5_000;
+    let withdraw = StablecoinInstruction::WithdrawStream { stream_id: 1 };
+    execute_si(&withdraw, &payee, &mut ctx).unwrap();
+    assert_eq!(ctx.state.accounts[&payee].lusd_balance, 1_400);
+    assert!(ctx.state.accounts[&payer].active_streams.is_empty());
+    assert!(ctx.state.accounts[&payee].incoming_streams.is_empty());
+    assert!(matches!(
+        execute_si(&withdraw, &payee, &mut ctx),
+        Err(ExecutionError::NotFound { .. })
+    ));
+}
+
+#[test]
+fn test_redemption_queue_pro_rata_and_cancel() {
+    use lumina_types::error::ExecutionError;
+
+    let alice = [21u8; 32];
+    let bob = [22u8; 32];
+    let carol = [23u8; 32];
+    let mut state = GlobalState::default();
+    for who in [alice, bob, carol] {
+        state.accounts.insert(
+            who,
+            AccountState {
+                lusd_balance: 2_000,
+                ..Default::default()
+            },
+        );
+    }
+    state.total_lusd_supply = 6_000;
+    state.stabilization_pool_balance = 5_400;
+    state.reserve_ratio = 0.90;
+
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+    for who in [alice, bob, carol] {
+        let redeem = StablecoinInstruction::RedeemSenior { amount: 1_000 };
+        execute_si(&redeem, &who, &mut ctx).unwrap();
+    }
+    assert_eq!(ctx.state.redemption_position(2), Some(2));
+
+    // Only the requester can cancel, and the full amount comes back while unfilled
+    let cancel = StablecoinInstruction::CancelRedemption { request_id: 1 };
+    assert!(matches!(
+        execute_si(&cancel, &alice, &mut ctx),
+        Err(ExecutionError::Unauthorized { .. })
+    ));
+    execute_si(&cancel, &bob, &mut ctx).unwrap();
+    assert_eq!(ctx.state.accounts[&bob].lusd_balance, 2_000);
+    assert_eq!(ctx.state.redemption_position(1), None);
+    assert_eq!(ctx.state.redemption_position(2), Some(1));
+
+    // Both requests in the batch receive the same 90% fill and rotate to the back
+    let process = StablecoinInstruction::FairRedeemQueue { batch_size: 2 };
+    let events = execute_si(&process, &alice, &mut ctx).unwrap();
+    assert_eq!(
+        events,
+        vec![
+            Event::RedemptionSettled {
+                request_id: 0,
+                account: alice,
+                amount: 900,
+                remaining: 100,
+            },
+            Event::CollateralReleased {
+                account: alice,
+                amount: 900,
+            },
+            Event::RedemptionSettled {
+                request_id: 2,
+                account: carol,
+                amount: 900,
+                remaining: 100,
+            },
+            Event::CollateralReleased {
+                account: carol,
+                amount: 900,
+            },
+        ]
+    );
+    assert_eq!(ctx.state.total_lusd_supply, 4_200);
+
+    // Cancelling a partially filled request refunds only the unfilled part
+    let cancel = StablecoinInstruction::CancelRedemption { request_id: 2 };
+    let events = execute_si(&cancel, &carol, &mut ctx).unwrap();
+    assert_eq!(
+        events,
+        vec![Event::RedemptionCancelled {
+            request_id: 2,
+            account: carol,
+            refunded: 100,
+        }]
+    );
+    assert_eq!(ctx.state.accounts[&carol].lusd_balance, 1_100);
+}
+
+#[test]
+fn test_validator_lifecycle_applies_at_epoch_boundaries() {
+    use lumina_types::error::ExecutionError;
+    use lumina_types::state::ValidatorStatus;
+
+    let operator = [31u8; 32];
+    let validator = [32u8; 32];
+    let mut state = GlobalState::default();
+    state.accounts.insert(
+        operator,
+        AccountState {
+            lumina_balance: 1_000,
+            ..Default::default()
+        },
+    );
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+
+    let register = StablecoinInstruction::RegisterValidator {
+        pubkey: validator,
+        stake: 600,
+    };
+    execute_si(&register, &operator, &mut ctx).unwrap();
+    assert!(matches!(
+        execute_si(&register, &operator, &mut ctx),
+        Err(ExecutionError::InvalidArgument { .. })
+    ));
+    let top_up = StablecoinInstruction::AddStake {
+        pubkey: validator,
+        amount: 200,
+    };
+    assert!(matches!(
+        execute_si(&top_up, &validator, &mut ctx),
+        Err(ExecutionError::Unauthorized { .. })
+    ));
+    execute_si(&top_up, &operator, &mut ctx).unwrap();
+
+    // Power stays fixed until the epoch boundary
+    assert_eq!(ctx.state.validators[0].power, 0);
+    ctx.height = EPOCH_LENGTH;
+    let events = end_block(&mut ctx);
+    assert!(events.contains(&Event::ValidatorSetUpdated {
+        epoch: 1,
+        active_validators: 1,
+        total_power: 800,
+    }));
+
+    let unbond = StablecoinInstruction::Unbond {
+        pubkey: validator,
+        amount: 800,
+    };
+    execute_si(&unbond, &operator, &mut ctx).unwrap();
+    assert_eq!(ctx.state.validators[0].status, ValidatorStatus::Unbonding);
+    assert_eq!(ctx.state.validators[0].power, 800);
+
+    let withdraw = StablecoinInstruction::WithdrawStake { pubkey: validator };
+    assert!(matches!(
+        execute_si(&withdraw, &operator, &mut ctx),
+        Err(ExecutionError::PreconditionFailed { .. })
+    ));
+
+    ctx.height = EPOCH_LENGTH * 2;
+    end_block(&mut ctx);
+    assert_eq!(ctx.state.validators[0].power, 0);
+
+    ctx.height = EPOCH_LENGTH + ctx.state.protocol_params.unbonding_period_blocks;
+    assert_eq!(
+        execute_si(&withdraw, &operator, &mut ctx).unwrap(),
+        vec![Event::StakeWithdrawn {
+            pubkey: validator,
+            account: operator,
+            amount: 800,
+        }]
+    );
+    assert_eq!(ctx.state.accounts[&operator].lumina_balance, 1_000);
+
+    // Fully exited validators leave the set at the next boundary
+    ctx.height = EPOCH_LENGTH * 20;
+    end_block(&mut ctx);
+    assert!(ctx.state.validators.is_empty());
+}
+
+#[test]
+fn test_delegation_rewards_use_accumulator() {
+    use lumina_types::error::ExecutionError;
+
+    let operator = [41u8; 32];
+    let validator = [42u8; 32];
+    let alice = [43u8; 32];
+    let bob = [44u8; 32];
+    let mut state = GlobalState::default();
+    for who in [operator, alice, bob] {
+        state.accounts.insert(
+            who,
+            AccountState {
+                lumina_balance: 1_000,
+                ..Default::default()
+            },
+        );
+    }
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+
+    let register = StablecoinInstruction::RegisterValidator {
+        pubkey: validator,
+        stake: 500,
+    };
+    execute_si(&register, &operator, &mut ctx).unwrap();
+    let commission = StablecoinInstruction::SetCommission {
+        pubkey: validator,
+        commission_bps: 1_000,
+    };
+    assert!(matches!(
+        execute_si(&commission, &alice, &mut ctx),
+        Err(ExecutionError::Unauthorized { .. })
+    ));
+    execute_si(&commission, &operator, &mut ctx).unwrap();
+    for (who, amount) in [(alice, 300), (bob, 200)] {
+        let delegate = StablecoinInstruction::Delegate { validator, amount };
+        execute_si(&delegate, &who, &mut ctx).unwrap();
+    }
+
+    // Delegated stake counts toward power from the next epoch
+    ctx.height = EPOCH_LENGTH;
+    end_block(&mut ctx);
+    assert_eq!(ctx.state.validators[0].power, 1_000);
+
+    // 1000 in fees: 100 commission, the remaining 900 split by bonded stake
+    ctx.state.gas_fee_pool = 1_000;
+    ctx.height = EPOCH_LENGTH * 2;
+    let events = end_block(&mut ctx);
+    assert!(events.contains(&Event::StakingRewardsDistributed {
+        epoch: 1,
+        amount: 1_000,
+    }));
+    assert_eq!(ctx.state.gas_fee_pool, 0);
+    assert_eq!(ctx.state.accounts[&operator].lumina_balance, 500 + 550);
+
+    // Alice's later top-up does not earn rewards distributed before it
+    let delegate = StablecoinInstruction::Delegate {
+        validator,
+        amount: 500,
+    };
+    execute_si(&delegate, &alice, &mut ctx).unwrap();
+    let claim = StablecoinInstruction::ClaimDelegatorRewards { validator };
+    assert_eq!(
+        execute_si(&claim, &alice, &mut ctx).unwrap(),
+        vec![Event::DelegatorRewardsClaimed {
+            delegator: alice,
+            pubkey: validator,
+            amount: 270,
+        }]
+    );
+    assert_eq!(
+        execute_si(&claim, &alice, &mut ctx).unwrap_err(),
+        ExecutionError::ZeroAmount
+    );
+
+    let undelegate = StablecoinInstruction::Undelegate {
+        validator,
+        amount: 200,
+    };
+    execute_si(&undelegate, &bob, &mut ctx).unwrap();
+    assert_eq!(
+        execute_si(&claim, &bob, &mut ctx).unwrap()[0],
+        Event::DelegatorRewardsClaimed {
+            delegator: bob,
+            pubkey: validator,
+            amount: 180,
+        }
+    );
+    ctx.height += ctx.state.protocol_params.unbonding_period_blocks;
+    let withdraw = StablecoinInstruction::WithdrawStake { pubkey: validator };
+    execute_si(&withdraw, &bob, &mut ctx).unwrap();
+    assert_eq!(ctx.state.accounts[&bob].lumina_balance, 1_000 + 180);
+    assert!(!ctx.state.validators[0].delegations.contains_key(&bob));
+}
+
+#[test]
+fn test_double_sign_evidence_slashes_and_jails() {
+    use lumina_types::block::{DoubleSignEvidence, SignedVote, Vote};
+    use lumina_types::error::ExecutionError;
+    use lumina_types::state::ValidatorStatus;
+
+    let (validator, key) = new_sender();
+    let delegator = [51u8; 32];
+    let reporter = [52u8; 32];
+    let mut state = governance_state(&[(validator, 10_000)]);
+    state.validators[0].delegated = 2_000;
+    state.validators[0].delegations.insert(
+        delegator,
+        lumina_types::state::Delegation {
+            amount: 2_000,
+            ..Default::default()
+        },
+    );
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 500,
+        timestamp: 100,
+    };
+
+    let vote = |height: u64, block: u8| SignedVote {
+        height,
+        block_hash: [block; 32],
+        signature: lumina_crypto::signatures::sign(
+            &key,
+            &Vote::signing_bytes(height, &[block; 32]),
+        ),
+    };
+    let evidence = |first: SignedVote, second: SignedVote| StablecoinInstruction::SubmitEvidence {
+        evidence: DoubleSignEvidence {
+            validator,
+            first,
+            second,
+        },
+    };
+
+    // Same block twice is not an equivocation
+    assert!(matches!(
+        execute_si(&evidence(vote(400, 1), vote(400, 1)), &reporter, &mut ctx),
+        Err(ExecutionError::InvalidArgument { .. })
+    ));
+    let mut forged = vote(400, 2);
+    forged.signature = vote(400, 3).signature;
+    assert!(matches!(
+        execute_si(&evidence(vote(400, 1), forged), &reporter, &mut ctx),
+        Err(ExecutionError::InvalidProof { .. })
+    ));
+
+    let double_sign = evidence(vote(400, 1), vote(400, 2));
+    let events = execute_si(&double_sign, &reporter, &mut ctx).unwrap();
+    let jailed_until = 500 + ctx.state.protocol_params.jail_duration_blocks;
+    assert_eq!(
+        events[0],
+        Event::ValidatorSlashed {
+            pubkey: validator,
+            infraction_height: 400,
+            amount: 600,
+            jailed_until,
+        }
+    );
+    let v = &ctx.state.validators[0];
+    assert_eq!((v.stake, v.delegated, v.power), (9_500, 1_900, 0));
+    assert_eq!(v.delegations[&delegator].amount, 1_900);
+    assert_eq!(
+        v.status,
+        ValidatorStatus::Jailed {
+            until_height: jailed_until
+        }
+    );
+    // Slashed Lumina goes to the fund's Lumina balance, not its LUSD one
+    assert_eq!(events[1], Event::InsuranceFundCredited { amount: 600 });
+    assert_eq!(ctx.state.insurance_fund_lumina, 600);
+    assert_eq!(ctx.state.insurance_fund_balance, 0);
+
+    assert!(matches!(
+        execute_si(&double_sign, &reporter, &mut ctx),
+        Err(ExecutionError::Replay { .. })
+    ));
+
+    ctx.height = 400 + ctx.state.protocol_params.evidence_max_age_blocks + 1;
+    assert!(matches!(
+        execute_si(&evidence(vote(400, 1), vote(400, 4)), &reporter, &mut ctx),
+        Err(ExecutionError::PreconditionFailed { .. })
+    ));
+
+    // Replay records past the evidence window are pruned on the next slash
+    let recent = ctx.height - 1;
+    execute_si(
+        &evidence(vote(recent, 1), vote(recent, 2)),
+        &reporter,
+        &mut ctx,
+    )
+    .unwrap();
+    assert_eq!(
+        *ctx.state.slashed_infractions,
+        std::collections::BTreeSet::from([(recent, validator)])
+    );
+}
+
+#[test]
+fn test_bundle_executes_atomically_under_one_nonce() {
+    use lumina_types::instruction::AssetType;
+
+    let (sender, kp) = new_sender();
+    let bob = [61u8; 32];
+    let mut state = GlobalState::default();
+    state.accounts.insert(
+        sender,
+        AccountState {
+            lusd_balance: 1_000,
+            lumina_balance: 1_000_000,
+            ..Default::default()
+        },
+    );
+    state.stabilization_pool_balance = 1_000_000;
+    state.total_lusd_supply = 1_000_000;
+    state
+        .oracle_prices
+        .insert("LUMINA-USD".to_string(), 1_000_000);
+
+    let flash_bundle = |transfer: u64| StablecoinInstruction::Bundle {
+        instructions: vec![
+            StablecoinInstruction::FlashMint {
+                amount: 1_000,
+                collateral_asset: AssetType::Lumina,
+                collateral_amount: 1_500,
+                commitment: [6u8; 32],
+            },
+            StablecoinInstruction::Transfer {
+                to: bob,
+                amount: transfer,
+                asset: AssetType::LUSD,
+            },
+            StablecoinInstruction::FlashBurn { amount: 1_000 },
+        ],
+    };
+    let signed = |nonce: u64, instruction: StablecoinInstruction| {
+        let mut tx = Transaction {
+            sender,
+            nonce,
+            instruction,
+            signature: vec![],
+            gas_limit: 100_000,
+            gas_price: 1,
+        };
+        tx.signature = lumina_crypto::signatures::sign(&kp, &tx.signing_bytes());
+        tx
+    };
+
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+
+    // Burning more than is left after the transfer fails the third step, and the
+    // mint and transfer before it are rolled back with it
+    let tx = signed(0, flash_bundle(1_500));
+    assert_eq!(tx.instructions().len(), 3);
+    let receipt = execute_transaction(&tx, &mut ctx).unwrap();
+    match receipt.error() {
+        Some(ExecutionError::BundleInstructionFailed { index, .. }) => assert_eq!(*index, 2),
+        other => panic!("unexpected result: {other:?}"),
+    }
+    assert_eq!(ctx.state.pending_flash_mints, 0);
+    assert!(!ctx.state.accounts.contains_key(&bob));
+    assert_eq!(ctx.state.accounts[&sender].lusd_balance, 1_000);
+    assert_eq!(ctx.state.accounts[&sender].nonce, 1);
+
+    let tx = signed(1, flash_bundle(1_000));
+    let receipt = execute_transaction(&tx, &mut ctx).unwrap();
+    assert!(receipt.is_success());
+    assert!(receipt.events.contains(&Event::Transferred {
+        from: sender,
+        to: bob,
+        asset: AssetType::LUSD,
+        amount: 1_000,
+    }));
+    assert_eq!(ctx.state.pending_flash_mints, 0);
+    assert_eq!(ctx.state.accounts[&bob].lusd_balance, 1_000);
+    assert_eq!(ctx.state.accounts[&sender].lusd_balance, 0);
+    assert_eq!(ctx.state.accounts[&sender].nonce, 2);
+
+    let nested = StablecoinInstruction::Bundle {
+        instructions: vec![flash_bundle(0)],
+    };
+    assert!(matches!(
+        execute_si(&nested, &sender, &mut ctx),
+        Err(ExecutionError::InvalidArgument { .. })
+    ));
+}
+
+#[test]
+fn test_bundle_steps_are_metered_against_live_state() {
+    let (issuer, kp) = new_sender();
+    let junior = [62u8; 32];
+    let mut state = GlobalState::default();
+    state.accounts.insert(
+        issuer,
+        AccountState {
+            lusd_balance: 3_000,
+            lumina_balance: 1_000_000,
+            ..Default::default()
+        },
+    );
+    state.accounts.insert(
+        junior,
+        AccountState {
+            ljun_balance: 1_000,
+            ..Default::default()
+        },
+    );
+    state.total_ljun_supply = 1_000;
+    state.total_lusd_supply = 3_000;
+    state.stabilization_pool_balance = 3_000;
+    state.set_role(lumina_types::state::Role::Treasury, issuer, true);
+
+    // The yield position opened by the first step is walked by the second
+    let bundle = StablecoinInstruction::Bundle {
+        instructions: vec![
+            StablecoinInstruction::WrapToYieldToken {
+                amount: 3_000,
+                maturity_blocks: 50,
+            },
+            StablecoinInstruction::DistributeYield { total_yield: 1_000 },
+        ],
+    };
+    let estimate = gas::transaction_gas(&bundle, &state);
+    let metered = estimate + gas::PER_ITEM_GAS;
+    let signed = |nonce: u64, gas_limit: u64| {
+        let mut tx = Transaction {
+            sender: issuer,
+            nonce,
+            instruction: bundle.clone(),
+            signature: vec![],
+            gas_limit,
+            gas_price: 1,
+        };
+        tx.signature = lumina_crypto::signatures::sign(&kp, &tx.signing_bytes());
+        tx
+    };
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 10,
+        timestamp: 100,
+    };
+
+    // A budget sized from the pre-state runs out on the second step
+    let receipt = execute_transaction(&signed(0, estimate), &mut ctx).unwrap();
+    assert_eq!(
+        receipt.error(),
+        Some(&ExecutionError::OutOfGas {
+            required: metered,
+            limit: estimate,
+        })
+    );
+    assert_eq!(receipt.gas_used, estimate);
+    assert!(ctx.state.yield_positions.is_empty());
+    assert_eq!(ctx.state.accounts[&issuer].lusd_balance, 3_000);
+
+    let receipt = execute_transaction(&signed(1, metered), &mut ctx).unwrap();
+    assert!(receipt.is_success());
+    assert_eq!(receipt.gas_used, metered);
+    assert_eq!(ctx.state.yield_positions.len(), 1);
+}
+
+#[test]
+fn test_passkey_authorizes_transactions_once_selected() {
+    use lumina_crypto::signatures::webauthn::{sign_passkey_assertion, PasskeySigningKey};
+    use lumina_types::instruction::AssetType;
+
+    let (sender, kp) = new_sender();
+    let device = PasskeySigningKey::from_slice(&[42u8; 32]).unwrap();
+    let mut state = GlobalState::default();
+    state.accounts.insert(
+        sender,
+        AccountState {
+            lusd_balance: 1_000,
+            lumina_balance: 1_000_000,
+            passkey_device_key: Some(device.verifying_key().to_sec1_bytes().to_vec()),
+            guardians: vec![[3u8; 32], [4u8; 32]],
+            ..Default::default()
+        },
+    );
+    let unsigned = |nonce: u64, instruction: StablecoinInstruction| Transaction {
+        sender,
+        nonce,
+        instruction,
+        signature: vec![],
+        gas_limit: 100_000,
+        gas_price: 1,
+    };
+    let transfer = StablecoinInstruction::Transfer {
+        to: [62u8; 32],
+        amount: 100,
+        asset: AssetType::LUSD,
+    };
+
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+
+    // Until the account switches, the Ed25519 key still signs and assertions are refused
+    let mut tx = unsigned(0, transfer.clone());
+    tx.signature = sign_passkey_assertion(&device, &tx.signing_bytes());
+    assert!(matches!(
+        execute_transaction(&tx, &mut ctx),
+        Err(ExecutionError::InvalidSignature)
+    ));
+    let mut tx = unsigned(0, StablecoinInstruction::SwitchToPasskeySignature);
+    tx.signature = lumina_crypto::signatures::sign(&kp, &tx.signing_bytes());
+    assert!(execute_transaction(&tx, &mut ctx).unwrap().is_success());
+    assert!(ctx.state.accounts[&sender].passkey_auth);
+
+    // Now only a WebAuthn assertion whose challenge commits to this exact tx is accepted
+    let mut tx = unsigned(1, transfer.clone());
+    tx.signature = lumina_crypto::signatures::sign(&kp, &tx.signing_bytes());
+    assert!(execute_transaction(&tx, &mut ctx).is_err());
+    let other = unsigned(2, transfer.clone());
+    tx.signature = sign_passkey_assertion(&device, &other.signing_bytes());
+    assert!(execute_transaction(&tx, &mut ctx).is_err());
+
+    tx.signature = sign_passkey_assertion(&device, &tx.signing_bytes());
+    let receipts = execute_transactions_parallel_non_conflicting(&[tx], &mut ctx).unwrap();
+    assert!(receipts[0].is_success());
+    assert_eq!(ctx.state.accounts[&[62u8; 32]].lusd_balance, 100);
+
+    // Once passkey-authorized, re-registration must supply a real P-256 key
+    let reregister = |device_key: Vec<u8>| StablecoinInstruction::CreatePasskeyAccount {
+        device_key,
+        guardians: vec![[3u8; 32], [4u8; 32]],
+    };
+    assert!(execute_si(&reregister(vec![1u8; 65]), &sender, &mut ctx).is_err());
+    let rotated = PasskeySigningKey::from_slice(&[43u8; 32]).unwrap();
+    let rotated_key = rotated.verifying_key().to_sec1_bytes().to_vec();
+    assert!(execute_si(&reregister(rotated_key.clone()), &sender, &mut ctx).is_ok());
+    assert_eq!(
+        ctx.state.accounts[&sender].passkey_device_key,
+        Some(rotated_key)
+    );
+
+    // An account without a usable P-256 key cannot switch
+    let (other_sender, _) = new_sender();
+    ctx.state
+        .accounts
+        .entry(other_sender)
+        .or_default()
+        .passkey_device_key = Some(vec![1u8; 65]);
+    assert!(execute_si(
+        &StablecoinInstruction::SwitchToPasskeySignature,
+        &other_sender,
+        &mut ctx
+    )
+    .is_err());
+}
+
+#[test]
+fn test_multisig_account_requires_threshold_of_member_signatures() {
+    use lumina_types::instruction::AssetType;
+    use lumina_types::state::MultisigMember;
+
+    let (treasury, treasury_kp) = new_sender();
+    let (a, a_kp) = new_sender();
+    let (b, b_kp) = new_sender();
+    let (c, c_kp) = new_sender();
+    let mut state = GlobalState::default();
+    state.accounts.insert(
+        treasury,
+        AccountState {
+            lusd_balance: 1_000,
+            lumina_balance: 1_000_000,
+            ..Default::default()
+        },
+    );
+    let unsigned = |nonce: u64, instruction: StablecoinInstruction| Transaction {
+        sender: treasury,
+        nonce,
+        instruction,
+        signature: vec![],
+        gas_limit: 100_000,
+        gas_price: 1,
+    };
+    let signed_by = |mut tx: Transaction, keys: &[&lumina_crypto::signatures::SigningKey]| {
+        let sigs: Vec<Vec<u8>> = keys
+            .iter()
+            .map(|k| lumina_crypto::signatures::sign(k, &tx.signing_bytes()))
+            .collect();
+        tx.set_multisig_signatures(&sigs);
+        tx
+    };
+    let transfer = StablecoinInstruction::Transfer {
+        to: [63u8; 32],
+        amount: 100,
+        asset: AssetType::LUSD,
+    };
+
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+
+    let mut tx = unsigned(
+        0,
+        StablecoinInstruction::CreateMultisigAccount {
+            members: vec![MultisigMember::Ed25519(a), MultisigMember::Ed25519(b)],
+            threshold: 2,
+        },
+    );
+    tx.signature = lumina_crypto::signatures::sign(&treasury_kp, &tx.signing_bytes());
+    assert!(execute_transaction(&tx, &mut ctx).unwrap().is_success());
+
+    // The original key alone, one member, or the same member twice is not enough
+    let mut tx = unsigned(1, transfer.clone());
+    tx.signature = lumina_crypto::signatures::sign(&treasury_kp, &tx.signing_bytes());
+    assert!(matches!(
+        execute_transaction(&tx, &mut ctx),
+        Err(ExecutionError::InvalidSignature)
+    ));
+    let tx = signed_by(unsigned(1, transfer.clone()), &[&a_kp]);
+    assert!(execute_transaction(&tx, &mut ctx).is_err());
+    let tx = signed_by(unsigned(1, transfer.clone()), &[&a_kp, &a_kp]);
+    assert!(execute_transaction(&tx, &mut ctx).is_err());
+
+    let tx = signed_by(unsigned(1, transfer.clone()), &[&b_kp, &a_kp]);
+    assert!(execute_transaction(&tx, &mut ctx).unwrap().is_success());
+    assert_eq!(ctx.state.accounts[&[63u8; 32]].lusd_balance, 100);
+
+    // Rotating b out for c and lowering the threshold needs the current 2-of-2
+    let rotate = StablecoinInstruction::Bundle {
+        instructions: vec![
+            StablecoinInstruction::AddMultisigMember {
+                member: MultisigMember::Ed25519(c),
+            },
+            StablecoinInstruction::RemoveMultisigMember {
+                member: MultisigMember::Ed25519(b),
+            },
+            StablecoinInstruction::SetMultisigThreshold { threshold: 1 },
+        ],
+    };
+    let tx = signed_by(unsigned(2, rotate.clone()), &[&a_kp]);
+    assert!(execute_transaction(&tx, &mut ctx).is_err());
+    let tx = signed_by(unsigned(2, rotate), &[&a_kp, &b_kp]);
+    assert!(execute_transaction(&tx, &mut ctx).unwrap().is_success());
+
+    let tx = signed_by(unsigned(3, transfer.clone()), &[&b_kp]);
+    assert!(execute_transaction(&tx, &mut ctx).is_err());
+    let tx = signed_by(unsigned(3, transfer), &[&c_kp]);
+    assert!(execute_transaction(&tx, &mut ctx).unwrap().is_success());
+
+    // Thresholds above the member count are rejected
+    assert!(execute_si(
+        &StablecoinInstruction::SetMultisigThreshold { threshold: 3 },
+        &treasury,
+        &mut ctx
+    )
+    .is_err());
+}
+
+#[test]
+fn test_session_key_scope_budget_and_expiry() {
+    use lumina_types::instruction::AssetType;
+    use lumina_types::state::SessionPermission;
+
+    let (sender, kp) = new_sender();
+    let (session, session_kp) = new_sender();
+    let bob = [64u8; 32];
+    let mut state = GlobalState::default();
+    state.accounts.insert(
+        sender,
+        AccountState {
+            lusd_balance: 1_000,
+            ljun_balance: 1_000,
+            lumina_balance: 1_000_000,
+            ..Default::default()
+        },
+    );
+    let unsigned = |nonce: u64, instruction: StablecoinInstruction| Transaction {
+        sender,
+        nonce,
+        instruction,
+        signature: vec![],
+        gas_limit: 100_000,
+        gas_price: 1,
+    };
+    // Exactly covers a transfer; the prepaid fee counts against the session budget
+    let session_gas = gas::TX_BASE_GAS + gas::TRANSFER_GAS;
+    let session_signed_with_gas = |nonce: u64, instruction: StablecoinInstruction, gas_limit| {
+        let mut tx = unsigned(nonce, instruction);
+        tx.gas_limit = gas_limit;
+        let sig = lumina_crypto::signatures::sign(&session_kp, &tx.signing_bytes());
+        tx.set_session_signature(&session, &sig);
+        tx
+    };
+    let session_signed = |nonce: u64, instruction: StablecoinInstruction| {
+        session_signed_with_gas(nonce, instruction, session_gas)
+    };
+    let transfer = |amount: u64, asset: AssetType| StablecoinInstruction::Transfer {
+        to: bob,
+        amount,
+        asset,
+    };
+
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 10,
+        timestamp: 100,
+    };
+    let mut tx = unsigned(
+        0,
+        StablecoinInstruction::RegisterSessionKey {
+            pubkey: session,
+            permissions: vec![SessionPermission::Transfer {
+                asset: AssetType::LUSD,
+            }],
+            spend_limit: 150 + session_gas,
+            period_blocks: 100,
+            expires_at: 250,
+        },
+    );
+    tx.signature = lumina_crypto::signatures::sign(&kp, &tx.signing_bytes());
+    assert!(execute_transaction(&tx, &mut ctx).unwrap().is_success());
+
+    let tx = session_signed(1, transfer(100, AssetType::LUSD));
+    assert!(execute_transaction(&tx, &mut ctx).unwrap().is_success());
+
+    // Other assets, other instructions and the remaining budget are all enforced
+    let out_of_scope = [
+        transfer(10, AssetType::LJUN),
+        StablecoinInstruction::RevokeSessionKey { pubkey: session },
+        transfer(60, AssetType::LUSD),
+    ];
+    for instruction in out_of_scope {
+        assert!(matches!(
+            execute_transaction(&session_signed(2, instruction), &mut ctx),
+            Err(ExecutionError::Unauthorized { .. })
+        ));
+    }
+    assert_eq!(ctx.state.accounts[&sender].nonce, 2);
+
+    // A new period restores the budget; expiry ends the key's authority
+    ctx.height = 110;
+    let tx = session_signed(2, transfer(150, AssetType::LUSD));
+    assert!(execute_transaction(&tx, &mut ctx).unwrap().is_success());
+    assert_eq!(ctx.state.accounts[&bob].lusd_balance, 250);
+
+    // Gas is paid from the same budget, so a larger gas limit no longer fits
+    ctx.height = 210;
+    let tx = session_signed_with_gas(3, transfer(150, AssetType::LUSD), session_gas + 1);
+    assert!(matches!(
+        execute_transaction(&tx, &mut ctx),
+        Err(ExecutionError::Unauthorized { .. })
+    ));
+
+    // A transaction that fails on-chain still uses up its allowance, so a
+    // session key cannot burn the account's gas with endless failures
+    let lusd = ctx.state.accounts[&sender].lusd_balance;
+    ctx.state.accounts.get_mut(&sender).unwrap().lusd_balance = 0;
+    let tx = session_signed(3, transfer(100, AssetType::LUSD));
+    assert!(!execute_transaction(&tx, &mut ctx).unwrap().is_success());
+    let key = &ctx.state.accounts[&sender].session_keys[0];
+    assert_eq!(key.spent_at(ctx.height), 100 + session_gas);
+    let tx = session_signed(4, transfer(100, AssetType::LUSD));
+    assert!(matches!(
+        execute_transaction(&tx, &mut ctx),
+        Err(ExecutionError::Unauthorized { .. })
+    ));
+    ctx.state.accounts.get_mut(&sender).unwrap().lusd_balance = lusd;
+
+    // An expired key's signature is refused before anything else is checked
+    ctx.height = 250;
+    assert!(matches!(
+        verify_transaction_signature(&ctx.state.accounts[&sender], &tx, ctx.height),
+        Err(ExecutionError::Unauthorized { .. })
+    ));
+    let tx = session_signed(4, transfer(1, AssetType::LUSD));
+    assert!(execute_transaction(&tx, &mut ctx).is_err());
+
+    // A session signature by an unregistered key is never accepted
+    let (stranger, stranger_kp) = new_sender();
+    let mut tx = unsigned(4, transfer(1, AssetType::LUSD));
+    let sig = lumina_crypto::signatures::sign(&stranger_kp, &tx.signing_bytes());
+    tx.set_session_signature(&stranger, &sig);
+    assert!(matches!(
+        execute_transaction(&tx, &mut ctx),
+        Err(ExecutionError::InvalidSignature)
+    ));
+}
+
+#[test]
+fn test_allowances_approve_transfer_from_and_revoke() {
+    use lumina_types::instruction::AssetType;
+
+    let owner = [25u8; 32];
+    let processor = [26u8; 32];
+    let merchant = [27u8; 32];
+    let mut state = GlobalState::default();
+    state.accounts.insert(
+        owner,
+        AccountState {
+            lusd_balance: 1_000,
+            ..Default::default()
+        },
+    );
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+    let pull = |amount: u64| StablecoinInstruction::TransferFrom {
+        owner,
+        to: merchant,
+        asset: AssetType::LUSD,
+        amount,
+    };
+
+    assert!(execute_si(&pull(1), &processor, &mut ctx).is_err());
+    let approve = StablecoinInstruction::Approve {
+        spender: processor,
+        asset: AssetType::LUSD,
+        amount: 300,
+    };
+    execute_si(&approve, &owner, &mut ctx).unwrap();
+    assert_eq!(
+        ctx.state.accounts[&owner].allowance(&processor, "LUSD"),
+        300
+    );
+
+    let events = execute_si(&pull(200), &processor, &mut ctx).unwrap();
+    assert!(events.contains(&Event::Transferred {
+        from: owner,
+        to: merchant,
+        asset: AssetType::LUSD,
+        amount: 200,
+    }));
+    assert_eq!(ctx.state.accounts[&merchant].lusd_balance, 200);
+    assert_eq!(ctx.state.accounts[&owner].lusd_balance, 800);
+    assert_eq!(
+        ctx.state.accounts[&owner].allowance(&processor, "LUSD"),
+        100
+    );
+
+    // The allowance is per asset and cannot be overdrawn
+    assert!(execute_si(&pull(101), &processor, &mut ctx).is_err());
+    let ljun = StablecoinInstruction::TransferFrom {
+        owner,
+        to: merchant,
+        asset: AssetType::LJUN,
+        amount: 1,
+    };
+    assert!(execute_si(&ljun, &processor, &mut ctx).is_err());
+
+    let revoke = StablecoinInstruction::RevokeApproval {
+        spender: processor,
+        asset: AssetType::LUSD,
+    };
+    execute_si(&revoke, &owner, &mut ctx).unwrap();
+    assert!(ctx.state.accounts[&owner].allowances.is_empty());
+    assert!(execute_si(&pull(1), &processor, &mut ctx).is_err());
+    assert!(execute_si(&revoke, &owner, &mut ctx).is_err());
+}
+
+#[test]
+fn test_confidential_lusd_shield_transfer_and_unshield() {
+    use lumina_crypto::audit::AUDIT_NOTE_BYTES;
+    use lumina_crypto::pq::KYBER768_PUBLIC_KEY_BYTES;
+    use lumina_crypto::zk::{commit_value, prove_confidential_spend};
+
+    let alice = [28u8; 32];
+    let bob = [29u8; 32];
+    let mut state = GlobalState::default();
+    state.accounts.insert(
+        alice,
+        AccountState {
+            lusd_balance: 1_000,
+            ..Default::default()
+        },
+    );
+    // The chain only checks the note's shape; bob decrypts it off-chain
+    let note = vec![5u8; AUDIT_NOTE_BYTES];
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+
+    execute_si(
+        &StablecoinInstruction::Shield { amount: 1_000 },
+        &alice,
+        &mut ctx,
+    )
+    .unwrap();
+    assert_eq!(ctx.state.accounts[&alice].lusd_balance, 0);
+    assert_eq!(ctx.state.shielded_lusd_supply, 1_000);
+    assert!(execute_si(
+        &StablecoinInstruction::Shield { amount: 1 },
+        &alice,
+        &mut ctx
+    )
+    .is_err());
+
+    // Shielded amounts carry a zero blinding factor
+    let spend = prove_confidential_spend(1_000, [0u8; 32], 300, [7u8; 32]).unwrap();
+    let transfer = StablecoinInstruction::ConfidentialTransfer {
+        to: bob,
+        amount_commitment: spend.amount_commitment,
+        proof: spend.proof.clone(),
+        recipient_note: note.clone(),
+        audit_note: None,
+    };
+    // Bob has to opt in with a key the opening can be encrypted to
+    assert!(execute_si(&transfer, &alice, &mut ctx).is_err());
+    let set_receive_key = StablecoinInstruction::SetReceiveKey {
+        receive_pubkey: Some(vec![1u8; KYBER768_PUBLIC_KEY_BYTES]),
+    };
+    execute_si(&set_receive_key, &bob, &mut ctx).unwrap();
+    let short_note = StablecoinInstruction::ConfidentialTransfer {
+        to: bob,
+        amount_commitment: spend.amount_commitment,
+        proof: spend.proof.clone(),
+        recipient_note: vec![5u8; 16],
+        audit_note: None,
+    };
+    assert!(execute_si(&short_note, &alice, &mut ctx).is_err());
+
+    execute_si(&transfer, &alice, &mut ctx).unwrap();
+    assert_eq!(
+        ctx.state.accounts[&alice].commitment,
+        Some(commit_value(700, spend.remaining_blinding))
+    );
+    // Incoming amounts wait in the pending balance until bob merges them
+    assert_eq!(ctx.state.accounts[&bob].commitment, None);
+    assert_eq!(
+        ctx.state.accounts[&bob].pending_commitment,
+        Some(commit_value(300, [7u8; 32]))
+    );
+    // The proof was for the old balance and does not cover the new one
+    assert!(execute_si(&transfer, &alice, &mut ctx).is_err());
+    let apply = StablecoinInstruction::ApplyPendingBalance;
+    execute_si(&apply, &bob, &mut ctx).unwrap();
+    assert_eq!(
+        ctx.state.accounts[&bob].commitment,
+        Some(commit_value(300, [7u8; 32]))
+    );
+    assert_eq!(ctx.state.accounts[&bob].pending_commitment, None);
+    assert!(execute_si(&apply, &bob, &mut ctx).is_err());
+
+    // A proof against an inflated balance does not match the on-chain commitment
+    let overspend = prove_confidential_spend(2_000, spend.remaining_blinding, 1_500, [9u8; 32]);
+    let overspend = overspend.unwrap();
+    let forged = StablecoinInstruction::ConfidentialTransfer {
+        to: bob,
+        amount_commitment: overspend.amount_commitment,
+        proof: overspend.proof,
+        recipient_note: note.clone(),
+        audit_note: None,
+    };
+    assert!(execute_si(&forged, &alice, &mut ctx).is_err());
+
+    // A transfer landing before bob's unshield does not invalidate its proof
+    let withdraw = prove_confidential_spend(300, [7u8; 32], 100, [0u8; 32]).unwrap();
+    let gift = prove_confidential_spend(700, spend.remaining_blinding, 50, [8u8; 32]).unwrap();
+    let gift = StablecoinInstruction::ConfidentialTransfer {
+        to: bob,
+        amount_commitment: gift.amount_commitment,
+        proof: gift.proof,
+        recipient_note: note,
+        audit_note: None,
+    };
+    execute_si(&gift, &alice, &mut ctx).unwrap();
+    let wrong_amount = StablecoinInstruction::Unshield {
+        amount: 101,
+        proof: withdraw.proof.clone(),
+    };
+    assert!(execute_si(&wrong_amount, &bob, &mut ctx).is_err());
+    let unshield = StablecoinInstruction::Unshield {
+        amount: 100,
+        proof: withdraw.proof,
+    };
+    execute_si(&unshield, &bob, &mut ctx).unwrap();
+    assert_eq!(ctx.state.accounts[&bob].lusd_balance, 100);
+    assert_eq!(ctx.state.shielded_lusd_supply, 900);
+    assert_eq!(
+        ctx.state.accounts[&bob].commitment,
+        Some(commit_value(200, [7u8; 32]))
+    );
+    assert_eq!(
+        ctx.state.accounts[&bob].pending_commitment,
+        Some(commit_value(50, [8u8; 32]))
+    );
+}
+
+#[test]
+fn test_audited_confidential_transfer_requires_audit_note() {
+    use lumina_crypto::audit::AUDIT_NOTE_BYTES;
+    use lumina_crypto::pq::KYBER768_PUBLIC_KEY_BYTES;
+    use lumina_crypto::zk::prove_confidential_spend;
+
+    let sender = [30u8; 32];
+    let to = [31u8; 32];
+    let mut state = GlobalState::default();
+    state.accounts.insert(
+        sender,
+        AccountState {
+            lusd_balance: 500,
+            ..Default::default()
+        },
+    );
+    state.accounts.insert(
+        to,
+        AccountState {
+            receive_key: Some(vec![2u8; KYBER768_PUBLIC_KEY_BYTES]),
+            ..Default::default()
+        },
+    );
+    let recipient_note = vec![6u8; AUDIT_NOTE_BYTES];
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+    execute_si(
+        &StablecoinInstruction::Shield { amount: 500 },
+        &sender,
+        &mut ctx,
+    )
+    .unwrap();
+
+    let set_key = |key: Option<Vec<u8>>| StablecoinInstruction::SetAuditorKey {
+        auditor_pubkey: key,
+    };
+    assert!(execute_si(&set_key(Some(vec![1u8; 32])), &sender, &mut ctx).is_err());
+    execute_si(
+        &set_key(Some(vec![1u8; KYBER768_PUBLIC_KEY_BYTES])),
+        &sender,
+        &mut ctx,
+    )
+    .unwrap();
+
+    let spend = prove_confidential_spend(500, [0u8; 32], 200, [3u8; 32]).unwrap();
+    let transfer = |audit_note: Option<Vec<u8>>| StablecoinInstruction::ConfidentialTransfer {
+        to,
+        amount_commitment: spend.amount_commitment,
+        proof: spend.proof.clone(),
+        recipient_note: recipient_note.clone(),
+        audit_note,
+    };
+    assert!(execute_si(&transfer(None), &sender, &mut ctx).is_err());
+    assert!(execute_si(&transfer(Some(vec![0u8; 16])), &sender, &mut ctx).is_err());
+
+    let note = vec![7u8; AUDIT_NOTE_BYTES];
+    let events = execute_si(&transfer(Some(note.clone())), &sender, &mut ctx).unwrap();
+    assert!(events.contains(&Event::ConfidentialTransferred {
+        from: sender,
+        to,
+        amount_commitment: spend.amount_commitment,
+        recipient_note: recipient_note.clone(),
+        audit_note: Some(note),
+    }));
+
+    // Once the key is removed, notes are optional again
+    execute_si(&set_key(None), &sender, &mut ctx).unwrap();
+    let next = prove_confidential_spend(300, spend.remaining_blinding, 100, [4u8; 32]).unwrap();
+    let unaudited = StablecoinInstruction::ConfidentialTransfer {
+        to,
+        amount_commitment: next.amount_commitment,
+        proof: next.proof,
+        recipient_note,
+        audit_note: None,
+    };
+    execute_si(&unaudited, &sender, &mut ctx).unwrap();
+}
+
+#[test]
+fn test_vault_liquidation_runs_dutch_auction() {
+    use lumina_types::instruction::AssetType;
+
+    let owner = [41u8; 32];
+    let keeper = [42u8; 32];
+    let bidder = [43u8; 32];
+    let mut state = GlobalState {
+        accounts: [
+            (
+                owner,
+                AccountState {
+                    ljun_balance: 1_000,
+                    ..Default::default()
+                },
+            ),
+            (
+                bidder,
+                AccountState {
+                    lusd_balance: 2_000,
+                    ..Default::default()
+                },
+            ),
+        ]
+        .into_iter()
+        .collect(),
+        total_lusd_supply: 2_000,
+        oracle_prices: [("LJUN-USD".to_string(), 2_000_000)].into_iter().collect(),
+        ..Default::default()
+    };
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+
+    let ljun = |amount| (AssetType::LJUN, amount);
+    let deposit = |(asset, amount)| StablecoinInstruction::DepositVaultCollateral { asset, amount };
+    let withdraw =
+        |(asset, amount)| StablecoinInstruction::WithdrawVaultCollateral { asset, amount };
+    let draw = |amount| StablecoinInstruction::DrawVaultDebt { amount };
+    assert!(execute_si(&deposit((AssetType::LUSD, 10)), &owner, &mut ctx).is_err());
+    // Lumina has no oracle feed here, so it can't be valued as collateral
+    ctx.state.accounts.get_mut(&owner).unwrap().lumina_balance = 1_000;
+    assert!(execute_si(&deposit((AssetType::Lumina, 10)), &owner, &mut ctx).is_err());
+    execute_si(&deposit(ljun(1_000)), &owner, &mut ctx).unwrap();
+
+    // $2_000 of collateral at 150% backs at most 1_333 LUSD
+    assert!(execute_si(&draw(1_334), &owner, &mut ctx).is_err());
+    execute_si(&draw(1_300), &owner, &mut ctx).unwrap();
+    let repay = StablecoinInstruction::RepayVaultDebt { amount: 100 };
+    execute_si(&repay, &owner, &mut ctx).unwrap();
+    assert_eq!(ctx.state.vaults[&owner].debt, 1_200);
+    assert!(execute_si(&withdraw(ljun(200)), &owner, &mut ctx).is_err());
+    execute_si(&withdraw(ljun(50)), &owner, &mut ctx).unwrap();
+
+    let liquidate = StablecoinInstruction::LiquidateVault { owner };
+    assert!(execute_si(&liquidate, &keeper, &mut ctx).is_err());
+
+    // A price drop leaves 950 LJUN worth $1_425 against 1_200 of debt
+    ctx.state
+        .oracle_prices
+        .insert("LJUN-USD".to_string(), 1_500_000);
+    let events = execute_si(&liquidate, &keeper, &mut ctx).unwrap();
+    assert_eq!(
+        events,
+        vec![Event::VaultLiquidated {
+            owner,
+            auction_id: 0,
+            debt: 1_200,
+            penalty: 156,
+            start_price: 1_710,
+        }]
+    );
+    assert!(!ctx.state.vaults.contains_key(&owner));
+
+    // Halfway through, the lot asks half its starting price
+    let bid = |max_payment| StablecoinInstruction::BidVaultAuction {
+        auction_id: 0,
+        max_payment,
+    };
+    ctx.height = 181;
+    let events = execute_si(&bid(500), &bidder, &mut ctx).unwrap();
+    assert_eq!(
+        events[0],
+        Event::VaultAuctionBid {
+            auction_id: 0,
+            bidder,
+            paid: 500,
+            price: 855,
+        }
+    );
+    let auction = &ctx.state.vault_auctions[&0];
+    assert_eq!((auction.lot["LJUN"], auction.debt), (395, 700));
+
+    // Unsold at zero, the auction restarts from the recovered oracle price;
+    // the rest of the debt and the penalty are raised and the leftover
+    // collateral goes back to the owner
+    ctx.height = 361;
+    ctx.state
+        .oracle_prices
+        .insert("LJUN-USD".to_string(), 3_000_000);
+    let events = execute_si(&bid(10_000), &bidder, &mut ctx).unwrap();
+    assert_eq!(
+        events[0],
+        Event::VaultAuctionRestarted {
+            auction_id: 0,
+            start_price: 1_422,
+        }
+    );
+    assert!(events.contains(&Event::VaultAuctionClosed {
+        auction_id: 0,
+        shortfall: 0,
+        insurance_covered: 0,
+    }));
+    assert!(ctx.state.vault_auctions.is_empty());
+    assert_eq!(ctx.state.accounts[&bidder].ljun_balance, 555 + 237);
+    assert_eq!(ctx.state.accounts[&bidder].lusd_balance, 2_000 - 500 - 856);
+    assert_eq!(ctx.state.accounts[&owner].ljun_balance, 50 + 158);
+    assert_eq!(ctx.state.insurance_fund_balance, 156);
+    assert_eq!(ctx.state.total_lusd_supply, 3_200 - 1_356);
+    assert_eq!(ctx.state.stabilization_pool_balance, 0);
+}
+
+#[test]
+fn test_senior_mint_position_can_be_liquidated() {
+    use lumina_types::instruction::AssetType;
+
+    let minter = [44u8; 32];
+    let keeper = [45u8; 32];
+    let mut state = GlobalState {
+        accounts: [(
+            minter,
+            AccountState {
+                lumina_balance: 1_500,
+                ..Default::default()
+            },
+        )]
+        .into_iter()
+        .collect(),
+        oracle_prices: [("LUMINA-USD".to_string(), 1_000_000)]
+            .into_iter()
+            .collect(),
+        ..Default::default()
+    };
+    let mut ctx = ExecutionContext {
+        state: &mut state,
+        height: 1,
+        timestamp: 100,
+    };
+
+    let manager = lumina_crypto::zk::ZkManager::setup();
+    let mint = StablecoinInstruction::MintSenior {
+        amount: 1_000,
+        collateral_asset: AssetType::Lumina,
+        collateral_amount: 1_500,
+        proof: manager.prove_reserves(vec![1_500], 1_500),
+    };
+    execute_si(&mint, &minter, &mut ctx).unwrap();
+    assert_eq!(ctx.state.accounts[&minter].lumina_balance, 0);
+    assert_eq!(ctx.state.vaults[&minter].debt, 950);
+
+    let liquidate = StablecoinInstruction::LiquidateVault { owner: minter };
+    assert!(execute_si(&liquidate, &keeper, &mut ctx).is_err());
+
+    // At $0.90 the 1_500 Lumina no longer covers 950 of debt at 150%
+    ctx.state
+        .oracle_prices
+        .insert("LUMINA-USD".to_string(), 900_000);
+    let events = execute_si(&liquidate, &keeper, &mut ctx).unwrap();
+    assert!(matches!(
+        events[..],
+        [Event::VaultLiquidated { owner, debt: 950, .. }] if owner == minter
+    ));
+    assert!(!ctx.state.vaults.contains_key(&minter));
+    assert_eq!(ctx.state.vault_auctions[&0].lot["Lumina"], 1_500);
 }
@@ -1,5 +1,7 @@
-use lumina_types::state::{AccountState, GlobalState, ValidatorState};
-use std::collections::HashMap;
+use lumina_types::state::{
+    AccountState, AdminMultisig, GlobalState, ProtocolParams, Role, ValidatorState, ValidatorStatus,
+};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 /// Create the genesis state for LuminaChain.
 /// This initializes the very first state of the chain with:
@@ -8,18 +10,24 @@ use std::collections::HashMap;
 /// - Oracle price bootstraps
 /// - Insurance fund seeded
 /// - Velocity reward pool initialized
+/// - Protocol economics at their launch values
+/// - Bootstrap role holders and admin multisig
 pub fn create_genesis_state() -> GlobalState {
-    let mut accounts = HashMap::new();
+    let mut accounts = BTreeMap::new();
     let mut validators = Vec::new();
 
     // Initial validator (replace with ceremony-derived keys before mainnet)
     let validator_pubkey = [0u8; 32];
     validators.push(ValidatorState {
         pubkey: validator_pubkey,
+        operator: [0u8; 32],
         stake: 1_000_000,
         power: 1_000_000,
         is_green: false,
         energy_proof: None,
+        status: ValidatorStatus::Active,
+        unbonding: Vec::new(),
+        ..Default::default()
     });
 
     // Deployer account with initial Lumina gas tokens
@@ -38,12 +46,33 @@ pub fn create_genesis_state() -> GlobalState {
     oracle_prices.insert("BTC-USD".to_string(), 90000_000_000);
     oracle_prices.insert("LUSD-USD".to_string(), 1_000_000); // $1.00 peg
 
+    // Bootstrap operator roles and a 1-of-1 admin multisig held by the deployer
+    // (replace with ceremony-derived keys before mainnet)
+    let mut roles = BTreeMap::new();
+    for role in [
+        Role::Guardian,
+        Role::Oracle,
+        Role::ComplianceAdmin,
+        Role::Treasury,
+        Role::RwaAttestor,
+    ] {
+        roles.insert(role, BTreeSet::from([deployer_addr]));
+    }
+    let admin_multisig = AdminMultisig {
+        members: vec![deployer_addr],
+        threshold: 1,
+        nonce: 0,
+    };
+
     GlobalState {
-        accounts,
+        accounts: accounts.into(),
         reserve_ratio: 1.0,
         oracle_prices,
-        validators,
+        validators: validators.into(),
         health_index: 10000, // Perfect health at genesis
+        protocol_params: ProtocolParams::default(),
+        roles: roles.into(),
+        admin_multisig,
         ..Default::default()
     }
 }
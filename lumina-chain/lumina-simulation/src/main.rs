@@ -3,13 +3,17 @@ use clap::Parser;
 use lumina_crypto::signatures::{generate_keypair, sign, SigningKey};
 use lumina_crypto::zk::ZkManager;
 use lumina_execution::{execute_transaction, ExecutionContext};
-use lumina_types::instruction::{AssetType, StablecoinInstruction};
+use lumina_types::instruction::{AssetType, StablecoinInstruction, PRICE_SCALE};
+use lumina_types::receipt::ReceiptStatus;
 use lumina_types::state::{AccountState, GlobalState};
 use lumina_types::transaction::Transaction;
 use rand::Rng;
 use std::collections::HashMap;
 use std::time::Instant;
 
+/// Lumina seeded into every wallet to pay for gas.
+const SIM_GAS_ALLOWANCE: u64 = 100_000_000;
+
 #[derive(Parser, Debug)]
 #[command(author, version, about = "LuminaChain realistic simulation runner")]
 struct Args {
@@ -62,6 +66,7 @@ fn seed_simulation_money(state: &mut GlobalState, wallets: &[SimWallet], amount:
             wallet.address,
             AccountState {
                 lusd_balance: amount,
+                lumina_balance: SIM_GAS_ALLOWANCE,
                 ..Default::default()
             },
         );
@@ -70,20 +75,47 @@ fn seed_simulation_money(state: &mut GlobalState, wallets: &[SimWallet], amount:
 
     state.stabilization_pool_balance = state.total_lusd_supply.saturating_mul(125) / 100;
     state.reserve_ratio = 1.25;
+    // Prices the Lumina the initial senior mint locks as vault collateral
+    state
+        .oracle_prices
+        .insert(AssetType::Lumina.price_feed(), PRICE_SCALE);
 }
 
-fn seed_custom_assets(
+/// Registers each custom asset with `minter` as issuer and mints `amount` of it
+/// to every wallet.
+fn issue_custom_assets(
     state: &mut GlobalState,
+    minter: &SimWallet,
     wallets: &[SimWallet],
     custom_assets: &[String],
     amount: u64,
-) {
-    for wallet in wallets {
-        let acct = state.accounts.entry(wallet.address).or_default();
-        for ticker in custom_assets {
-            acct.custom_balances.insert(ticker.clone(), amount);
+    nonce_book: &mut HashMap<[u8; 32], u64>,
+) -> Result<()> {
+    let mut ctx = ExecutionContext {
+        state,
+        height: 1,
+        timestamp: 1_700_000_000,
+    };
+    for ticker in custom_assets {
+        let nonce = *nonce_book.get(&minter.address).unwrap_or(&0);
+        let register_tx = build_register_asset_tx(minter, nonce, ticker);
+        let receipt = execute_transaction(&register_tx, &mut ctx)?;
+        if let ReceiptStatus::Failed { error } = receipt.status {
+            bail!("Asset registration failed: {}", error);
+        }
+        nonce_book.insert(minter.address, nonce.saturating_add(1));
+
+        for wallet in wallets {
+            let nonce = *nonce_book.get(&minter.address).unwrap_or(&0);
+            let mint_tx = build_mint_asset_tx(minter, nonce, ticker, wallet.address, amount);
+            let receipt = execute_transaction(&mint_tx, &mut ctx)?;
+            if let ReceiptStatus::Failed { error } = receipt.status {
+                bail!("Asset issuance failed: {}", error);
+            }
+            nonce_book.insert(minter.address, nonce.saturating_add(1));
         }
     }
+    Ok(())
 }
 
 fn build_transfer_tx(
@@ -114,11 +146,12 @@ fn build_transfer_tx(
 
 fn build_mint_tx(sender: &SimWallet, nonce: u64, amount: u64) -> Transaction {
     let zk = ZkManager::setup();
-    let collateral = amount.saturating_mul(120) / 100;
+    let collateral = amount.saturating_mul(150) / 100;
     let proof = zk.prove_reserves(vec![collateral], collateral);
 
     let instruction = StablecoinInstruction::MintSenior {
         amount,
+        collateral_asset: AssetType::Lumina,
         collateral_amount: collateral,
         proof,
     };
@@ -153,6 +186,31 @@ fn build_register_asset_tx(sender: &SimWallet, nonce: u64, ticker: &str) -> Tran
     tx
 }
 
+fn build_mint_asset_tx(
+    sender: &SimWallet,
+    nonce: u64,
+    ticker: &str,
+    to: [u8; 32],
+    amount: u64,
+) -> Transaction {
+    let instruction = StablecoinInstruction::MintAsset {
+        ticker: ticker.to_string(),
+        to,
+        amount,
+    };
+
+    let mut tx = Transaction {
+        sender: sender.address,
+        nonce,
+        instruction,
+        signature: vec![],
+        gas_limit: 1000,
+        gas_price: 1,
+    };
+    tx.signature = sign(&sender.keypair, &tx.signing_bytes());
+    tx
+}
+
 fn run_simulation(args: &Args) -> Result<()> {
     if args.wallets < 2 {
         bail!("wallets must be at least 2");
@@ -163,12 +221,6 @@ fn run_simulation(args: &Args) -> Result<()> {
     let mut state = GlobalState::default();
     let wallets = build_wallets(args.wallets);
     seed_simulation_money(&mut state, &wallets, args.simulation_money);
-    seed_custom_assets(
-        &mut state,
-        &wallets,
-        &custom_assets,
-        args.custom_asset_amount,
-    );
 
     let mut nonce_book = HashMap::<[u8; 32], u64>::new();
     for wallet in &wallets {
@@ -180,18 +232,15 @@ fn run_simulation(args: &Args) -> Result<()> {
 
     let minter = &wallets[0];
 
-    // Register custom assets in oracle registry path.
-    for ticker in &custom_assets {
-        let nonce = *nonce_book.get(&minter.address).unwrap_or(&0);
-        let register_tx = build_register_asset_tx(minter, nonce, ticker);
-        let mut ctx = ExecutionContext {
-            state: &mut state,
-            height: 1,
-            timestamp: 1_700_000_000,
-        };
-        execute_transaction(&register_tx, &mut ctx)?;
-        nonce_book.insert(minter.address, nonce.saturating_add(1));
-    }
+    // Register custom assets with the minter as issuer.
+    issue_custom_assets(
+        &mut state,
+        minter,
+        &wallets,
+        &custom_assets,
+        args.custom_asset_amount,
+        &mut nonce_book,
+    )?;
 
     // Mint once to validate PoR path.
     let nonce = *nonce_book.get(&minter.address).unwrap_or(&0);
@@ -203,7 +252,10 @@ fn run_simulation(args: &Args) -> Result<()> {
             height: 2,
             timestamp: 1_700_000_001,
         };
-        execute_transaction(&mint_tx, &mut ctx)?;
+        let receipt = execute_transaction(&mint_tx, &mut ctx)?;
+        if let ReceiptStatus::Failed { error } = receipt.status {
+            bail!("Initial mint failed: {}", error);
+        }
     }
     nonce_book.insert(minter.address, nonce.saturating_add(1));
 
@@ -239,8 +291,13 @@ fn run_simulation(args: &Args) -> Result<()> {
         };
 
         match result {
-            Ok(_) => {
-                success = success.saturating_add(1);
+            Ok(receipt) => {
+                if receipt.is_success() {
+                    success = success.saturating_add(1);
+                } else {
+                    failed = failed.saturating_add(1);
+                }
+                // Failed receipts still consume the nonce
                 nonce_book.insert(sender.address, nonce.saturating_add(1));
             }
             Err(_) => {
@@ -304,11 +361,22 @@ mod tests {
     fn simulation_bootstraps_custom_assets() {
         let mut state = GlobalState::default();
         let wallets = build_wallets(2);
+        seed_simulation_money(&mut state, &wallets, 1_000);
         let assets = vec!["BTC".to_string(), "ETH".to_string()];
-        seed_custom_assets(&mut state, &wallets, &assets, 42);
+        let mut nonce_book = HashMap::new();
+        issue_custom_assets(
+            &mut state,
+            &wallets[0],
+            &wallets,
+            &assets,
+            42,
+            &mut nonce_book,
+        )
+        .unwrap();
 
         let first = state.accounts.get(&wallets[0].address).unwrap();
         assert_eq!(first.custom_balances.get("BTC"), Some(&42));
         assert_eq!(first.custom_balances.get("ETH"), Some(&42));
+        assert_eq!(state.asset_registry.get("BTC").unwrap().total_supply, 84);
     }
 }
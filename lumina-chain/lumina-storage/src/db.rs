@@ -1,5 +1,6 @@
 use anyhow::{anyhow, Result};
 use lumina_types::block::Block;
+use lumina_types::receipt::Receipt;
 use lumina_types::state::GlobalState;
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
@@ -141,6 +142,43 @@ impl Storage {
         }
     }
 
+    pub fn save_block_receipts(&self, block_hash: [u8; 32], receipts: &[Receipt]) -> Result<()> {
+        let key = format!("block_receipts_{}", hex::encode(block_hash));
+        let encoded =
+            bincode::serialize(receipts).map_err(|e| anyhow!("Serialization error: {}", e))?;
+        self.db
+            .put(key.as_bytes(), encoded)
+            .map_err(|e| anyhow!("DB write error: {}", e))?;
+        Ok(())
+    }
+
+    pub fn load_block_receipts(&self, block_hash: &[u8; 32]) -> Result<Option<Vec<Receipt>>> {
+        let key = format!("block_receipts_{}", hex::encode(block_hash));
+        match self.db.get(key.as_bytes())? {
+            Some(v) => Ok(Some(bincode::deserialize(&v)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Indexes a canonical receipt by its transaction id.
+    pub fn save_receipt(&self, receipt: &Receipt) -> Result<()> {
+        let key = format!("receipt_{}", hex::encode(receipt.tx_id));
+        let encoded =
+            bincode::serialize(receipt).map_err(|e| anyhow!("Serialization error: {}", e))?;
+        self.db
+            .put(key.as_bytes(), encoded)
+            .map_err(|e| anyhow!("DB receipt-index error: {}", e))?;
+        Ok(())
+    }
+
+    pub fn load_receipt(&self, tx_id: &[u8; 32]) -> Result<Option<Receipt>> {
+        let key = format!("receipt_{}", hex::encode(tx_id));
+        match self.db.get(key.as_bytes())? {
+            Some(v) => Ok(Some(bincode::deserialize(&v)?)),
+            None => Ok(None),
+        }
+    }
+
     pub fn save_tip(&self, height: u64, hash: [u8; 32]) -> Result<()> {
         self.db
             .put(b"chain_tip_height", bincode::serialize(&height)?)
@@ -194,6 +232,8 @@ struct MemDb {
     states_by_height: HashMap<u64, GlobalState>,
     states_by_hash: HashMap<[u8; 32], GlobalState>,
     block_meta: HashMap<[u8; 32], (u64, [u8; 32])>,
+    receipts_by_block: HashMap<[u8; 32], Vec<Receipt>>,
+    receipts_by_tx: HashMap<[u8; 32], Receipt>,
     tip: Option<(u64, [u8; 32])>,
 }
 
@@ -313,6 +353,43 @@ impl Storage {
         Ok(guard.block_meta.get(block_hash).cloned())
     }
 
+    pub fn save_block_receipts(&self, block_hash: [u8; 32], receipts: &[Receipt]) -> Result<()> {
+        let mut guard = self
+            .inner
+            .write()
+            .map_err(|_| anyhow!("Storage lock poisoned"))?;
+        guard
+            .receipts_by_block
+            .insert(block_hash, receipts.to_vec());
+        Ok(())
+    }
+
+    pub fn load_block_receipts(&self, block_hash: &[u8; 32]) -> Result<Option<Vec<Receipt>>> {
+        let guard = self
+            .inner
+            .read()
+            .map_err(|_| anyhow!("Storage lock poisoned"))?;
+        Ok(guard.receipts_by_block.get(block_hash).cloned())
+    }
+
+    /// Indexes a canonical receipt by its transaction id.
+    pub fn save_receipt(&self, receipt: &Receipt) -> Result<()> {
+        let mut guard = self
+            .inner
+            .write()
+            .map_err(|_| anyhow!("Storage lock poisoned"))?;
+        guard.receipts_by_tx.insert(receipt.tx_id, receipt.clone());
+        Ok(())
+    }
+
+    pub fn load_receipt(&self, tx_id: &[u8; 32]) -> Result<Option<Receipt>> {
+        let guard = self
+            .inner
+            .read()
+            .map_err(|_| anyhow!("Storage lock poisoned"))?;
+        Ok(guard.receipts_by_tx.get(tx_id).cloned())
+    }
+
     pub fn save_tip(&self, height: u64, hash: [u8; 32]) -> Result<()> {
         let mut guard = self
             .inner
@@ -1,5 +1,5 @@
 use blake3;
-use lumina_types::state::{AccountState, GlobalState};
+use lumina_types::state::{AccountState, GlobalState, Vault, VaultAuction, YieldPosition};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
@@ -58,9 +58,33 @@ impl MerklePatriciaTrie {
         for (account, account_state) in &state.accounts {
             trie.insert_account(*account, account_state);
         }
+        for position in state.yield_positions.values() {
+            trie.insert_yield_position(position);
+        }
+        for (owner, vault) in &state.vaults {
+            trie.insert_vault(owner, vault);
+        }
+        for (auction_id, auction) in &state.vault_auctions {
+            trie.insert_vault_auction(*auction_id, auction);
+        }
         trie
     }
 
+    pub fn insert_yield_position(&mut self, position: &YieldPosition) {
+        let value = bincode::serialize(position).expect("yield position serialization");
+        self.insert(YieldPosition::trie_key(position.token_id), value);
+    }
+
+    pub fn insert_vault(&mut self, owner: &[u8; 32], vault: &Vault) {
+        let value = bincode::serialize(vault).expect("vault serialization");
+        self.insert(Vault::trie_key(owner), value);
+    }
+
+    pub fn insert_vault_auction(&mut self, auction_id: u64, auction: &VaultAuction) {
+        let value = bincode::serialize(auction).expect("vault auction serialization");
+        self.insert(VaultAuction::trie_key(auction_id), value);
+    }
+
     pub fn insert_account(&mut self, key: [u8; 32], account: &AccountState) {
         let value = bincode::serialize(account).expect("account serialization");
         self.insert(key, value);
@@ -385,6 +409,68 @@ mod tests {
         assert_eq!(state_root_from_global_state(&state), state.root_hash());
     }
 
+    #[test]
+    fn test_global_state_root_commits_to_yield_positions() {
+        let mut state = GlobalState::default();
+        state.accounts.insert([9u8; 32], AccountState::default());
+        let accounts_only = state.root_hash();
+
+        state.yield_positions.insert(
+            7,
+            YieldPosition {
+                token_id: 7,
+                principal: 1_000,
+                maturity_height: 100,
+                issued_height: 1,
+                principal_owner: Some([9u8; 32]),
+                yield_owner: Some([9u8; 32]),
+                accrued_yield: 0,
+            },
+        );
+        let with_position = state.root_hash();
+        assert_ne!(accounts_only, with_position);
+        assert_eq!(state_root_from_global_state(&state), with_position);
+
+        state.yield_positions.get_mut(&7).unwrap().accrued_yield = 5;
+        assert_ne!(state.root_hash(), with_position);
+    }
+
+    #[test]
+    fn test_global_state_root_commits_to_vaults_and_auctions() {
+        let mut state = GlobalState::default();
+        state.accounts.insert([9u8; 32], AccountState::default());
+        let accounts_only = state.root_hash();
+
+        state.vaults.insert(
+            [9u8; 32],
+            Vault {
+                collateral: [("LJUN".to_string(), 1_000)].into(),
+                debt: 500,
+            },
+        );
+        let with_vault = state.root_hash();
+        assert_ne!(accounts_only, with_vault);
+        assert_eq!(state_root_from_global_state(&state), with_vault);
+
+        state.vaults.get_mut(&[9u8; 32]).unwrap().debt = 400;
+        let repaid = state.root_hash();
+        assert_ne!(repaid, with_vault);
+
+        state.vault_auctions.insert(
+            0,
+            VaultAuction {
+                owner: [9u8; 32],
+                lot: [("LJUN".to_string(), 1_000)].into(),
+                debt: 400,
+                penalty: 52,
+                start_price: 1_710,
+                start_height: 1,
+            },
+        );
+        assert_ne!(state.root_hash(), repaid);
+        assert_eq!(state_root_from_global_state(&state), state.root_hash());
+    }
+
     #[test]
     fn test_proof_generation_and_verification() {
         let mut trie = MerklePatriciaTrie::new();
@@ -1,3 +1,4 @@
+use crate::receipt::Receipt;
 use crate::transaction::Transaction;
 use serde::{Deserialize, Serialize};
 
@@ -14,6 +15,7 @@ pub struct BlockHeader {
     pub prev_hash: [u8; 32],
     pub transactions_root: [u8; 32],
     pub state_root: [u8; 32],
+    pub receipts_root: [u8; 32],
     pub timestamp: u64,
     pub proposer: [u8; 32],
 }
@@ -31,29 +33,64 @@ pub struct Vote {
     pub signature: Vec<u8>,
 }
 
+impl Vote {
+    /// Domain-separated message a validator signs to vote for `block_hash` at `height`.
+    pub fn signing_bytes(height: u64, block_hash: &[u8; 32]) -> [u8; 32] {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(b"LUMINA_VOTE");
+        hasher.update(&height.to_le_bytes());
+        hasher.update(block_hash);
+        *hasher.finalize().as_bytes()
+    }
+}
+
+/// A vote detached from its block, as carried in evidence.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SignedVote {
+    pub height: u64,
+    pub block_hash: [u8; 32],
+    pub signature: Vec<u8>,
+}
+
+/// Two votes by the same validator for different blocks at the same height.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DoubleSignEvidence {
+    pub validator: [u8; 32],
+    pub first: SignedVote,
+    pub second: SignedVote,
+}
+
+fn merkle_root(leaves: Vec<[u8; 32]>) -> [u8; 32] {
+    if leaves.is_empty() {
+        return [0u8; 32];
+    }
+
+    let mut level = leaves;
+    while level.len() > 1 {
+        let mut next = Vec::with_capacity((level.len() + 1) / 2);
+        let mut i = 0;
+        while i < level.len() {
+            let left = level[i];
+            let right = if i + 1 < level.len() {
+                level[i + 1]
+            } else {
+                left
+            };
+            next.push(hash_concat(&left, &right));
+            i += 2;
+        }
+        level = next;
+    }
+    level[0]
+}
+
 impl Block {
     pub fn transactions_root(txs: &[Transaction]) -> [u8; 32] {
-        if txs.is_empty() {
-            return [0u8; 32];
-        }
+        merkle_root(txs.iter().map(|tx| tx.id()).collect())
+    }
 
-        let mut level: Vec<[u8; 32]> = txs.iter().map(|tx| tx.id()).collect();
-        while level.len() > 1 {
-            let mut next = Vec::with_capacity((level.len() + 1) / 2);
-            let mut i = 0;
-            while i < level.len() {
-                let left = level[i];
-                let right = if i + 1 < level.len() {
-                    level[i + 1]
-                } else {
-                    left
-                };
-                next.push(hash_concat(&left, &right));
-                i += 2;
-            }
-            level = next;
-        }
-        level[0]
+    pub fn receipts_root(receipts: &[Receipt]) -> [u8; 32] {
+        merkle_root(receipts.iter().map(|r| r.hash()).collect())
     }
 
     pub fn hash(&self) -> [u8; 32] {
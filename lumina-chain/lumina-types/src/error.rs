@@ -0,0 +1,158 @@
+use crate::instruction::AssetType;
+use crate::receipt::ProofKind;
+use crate::state::Role;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Typed failure of a transaction or StablecoinInstruction.
+///
+/// Clients match on [`ExecutionError::code`] rather than on the message text; codes are
+/// stable across releases, messages are not.
+#[derive(Error, Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum ExecutionError {
+    // ══════════════════════════════════════════════════════════════
+    // Transaction Envelope
+    // ══════════════════════════════════════════════════════════════
+    #[error("Invalid signature")]
+    InvalidSignature,
+    #[error("Gas price {price} below minimum {min}")]
+    GasPriceTooLow { price: u64, min: u64 },
+    #[error("Out of gas: instruction requires {required}, gas_limit is {limit}")]
+    OutOfGas { required: u64, limit: u64 },
+    #[error("Invalid nonce: expected {expected}, got {got}")]
+    InvalidNonce { expected: u64, got: u64 },
+    #[error("Insufficient Lumina for gas: need {need}, have {have}")]
+    InsufficientGasFunds { need: u64, have: u64 },
+
+    // ══════════════════════════════════════════════════════════════
+    // Balances & Arithmetic
+    // ══════════════════════════════════════════════════════════════
+    #[error("Insufficient {} balance", .asset.ticker())]
+    InsufficientBalance { asset: AssetType },
+    #[error("Insufficient collateral: need >= {required}, got {provided}")]
+    InsufficientCollateral { required: u64, provided: u64 },
+    #[error("{context} overflow")]
+    Overflow { context: String },
+    #[error("{context} underflow")]
+    Underflow { context: String },
+    #[error("Amount must be greater than zero")]
+    ZeroAmount,
+    #[error("Invalid argument: {reason}")]
+    InvalidArgument { reason: String },
+
+    // ══════════════════════════════════════════════════════════════
+    // Protocol State
+    // ══════════════════════════════════════════════════════════════
+    #[error("Circuit breaker active")]
+    CircuitBreakerActive,
+    #[error("Invalid {kind:?} proof")]
+    InvalidProof { kind: ProofKind },
+    #[error("{what} replay detected")]
+    Replay { what: String },
+    #[error("{what} not found")]
+    NotFound { what: String },
+    #[error("Unauthorized: {reason}")]
+    Unauthorized { reason: String },
+    #[error("Precondition failed: {reason}")]
+    PreconditionFailed { reason: String },
+    #[error("Sender lacks the {role:?} role")]
+    MissingRole { role: Role },
+
+    // ══════════════════════════════════════════════════════════════
+    // Bundles
+    // ══════════════════════════════════════════════════════════════
+    #[error("Bundle instruction {index} failed: {error}")]
+    BundleInstructionFailed {
+        index: u32,
+        error: Box<ExecutionError>,
+    },
+}
+
+impl ExecutionError {
+    /// Stable machine-readable code for this error.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ExecutionError::InvalidSignature => "INVALID_SIGNATURE",
+            ExecutionError::GasPriceTooLow { .. } => "GAS_PRICE_TOO_LOW",
+            ExecutionError::OutOfGas { .. } => "OUT_OF_GAS",
+            ExecutionError::InvalidNonce { .. } => "INVALID_NONCE",
+            ExecutionError::InsufficientGasFunds { .. } => "INSUFFICIENT_GAS_FUNDS",
+            ExecutionError::InsufficientBalance { .. } => "INSUFFICIENT_BALANCE",
+            ExecutionError::InsufficientCollateral { .. } => "INSUFFICIENT_COLLATERAL",
+            ExecutionError::Overflow { .. } => "OVERFLOW",
+            ExecutionError::Underflow { .. } => "UNDERFLOW",
+            ExecutionError::ZeroAmount => "ZERO_AMOUNT",
+            ExecutionError::InvalidArgument { .. } => "INVALID_ARGUMENT",
+            ExecutionError::CircuitBreakerActive => "CIRCUIT_BREAKER_ACTIVE",
+            ExecutionError::InvalidProof { .. } => "INVALID_PROOF",
+            ExecutionError::Replay { .. } => "REPLAY",
+            ExecutionError::NotFound { .. } => "NOT_FOUND",
+            ExecutionError::Unauthorized { .. } => "UNAUTHORIZED",
+            ExecutionError::PreconditionFailed { .. } => "PRECONDITION_FAILED",
+            ExecutionError::MissingRole { .. } => "MISSING_ROLE",
+            ExecutionError::BundleInstructionFailed { .. } => "BUNDLE_INSTRUCTION_FAILED",
+        }
+    }
+
+    /// Copy of this error with its free-text fields cleared, keeping the variant and
+    /// every typed field. This is the part of a failure that receipts commit to.
+    pub fn without_messages(&self) -> Self {
+        let mut error = self.clone();
+        match &mut error {
+            ExecutionError::Overflow { context } | ExecutionError::Underflow { context } => {
+                context.clear()
+            }
+            ExecutionError::InvalidArgument { reason }
+            | ExecutionError::Unauthorized { reason }
+            | ExecutionError::PreconditionFailed { reason } => reason.clear(),
+            ExecutionError::Replay { what } | ExecutionError::NotFound { what } => what.clear(),
+            ExecutionError::BundleInstructionFailed { error: inner, .. } => {
+                **inner = inner.without_messages()
+            }
+            _ => {}
+        }
+        error
+    }
+
+    pub fn overflow(context: &str) -> Self {
+        ExecutionError::Overflow {
+            context: context.to_string(),
+        }
+    }
+
+    pub fn underflow(context: &str) -> Self {
+        ExecutionError::Underflow {
+            context: context.to_string(),
+        }
+    }
+
+    pub fn invalid_argument(reason: &str) -> Self {
+        ExecutionError::InvalidArgument {
+            reason: reason.to_string(),
+        }
+    }
+
+    pub fn not_found(what: &str) -> Self {
+        ExecutionError::NotFound {
+            what: what.to_string(),
+        }
+    }
+
+    pub fn replay(what: &str) -> Self {
+        ExecutionError::Replay {
+            what: what.to_string(),
+        }
+    }
+
+    pub fn unauthorized(reason: &str) -> Self {
+        ExecutionError::Unauthorized {
+            reason: reason.to_string(),
+        }
+    }
+
+    pub fn precondition(reason: &str) -> Self {
+        ExecutionError::PreconditionFailed {
+            reason: reason.to_string(),
+        }
+    }
+}
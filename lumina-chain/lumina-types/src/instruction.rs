@@ -1,3 +1,5 @@
+use crate::block::DoubleSignEvidence;
+use crate::state::{MultisigMember, ProposalAction, Role, RwaAssetClass, SessionPermission};
 use serde::{Deserialize, Serialize};
 
 pub type ZkProof = Vec<u8>;
@@ -15,6 +17,7 @@ pub enum StablecoinInstruction {
     },
     MintSenior {
         amount: u64,
+        collateral_asset: AssetType,
         collateral_amount: u64,
         proof: Vec<u8>,
     },
@@ -56,9 +59,18 @@ pub enum StablecoinInstruction {
     // ══════════════════════════════════════════════════════════════
     // Privacy & Compliance
     // ══════════════════════════════════════════════════════════════
+    /// Moves a committed LUSD amount from the sender's confidential balance
+    /// to `to`'s pending balance. `proof` is an aggregated range proof that
+    /// both the amount and the sender's remaining balance are non-negative.
+    /// `recipient_note` is the amount and blinding factor encrypted to `to`'s
+    /// receive key. Senders with an auditor key must also attach
+    /// `audit_note`, the same opening encrypted to that key.
     ConfidentialTransfer {
-        commitment: [u8; 32],
+        to: [u8; 32],
+        amount_commitment: [u8; 32],
         proof: Vec<u8>,
+        recipient_note: Vec<u8>,
+        audit_note: Option<Vec<u8>>,
     },
     ProveCompliance {
         tx_hash: [u8; 32],
@@ -96,8 +108,10 @@ pub enum StablecoinInstruction {
         target_bank_id: [u8; 16],
         mpc_sig: Vec<u8>,
     },
+    /// Settles signed limit orders on one asset pair at a single uniform
+    /// clearing price.
     ZeroSlipBatchMatch {
-        orders: Vec<[u8; 32]>,
+        orders: Vec<SignedOrder>,
     },
     DynamicHedge {
         ratio_bps: u64,
@@ -202,6 +216,7 @@ pub enum StablecoinInstruction {
     },
     ListRWA {
         asset_description: String,
+        asset_class: RwaAssetClass,
         attested_value: u64,
         attestation_proof: ZkProof,
         maturity_date: Option<u64>,
@@ -212,6 +227,303 @@ pub enum StablecoinInstruction {
         amount_to_pledge: u64,
     },
     ComputeHealthIndex,
+
+    // ══════════════════════════════════════════════════════════════
+    // Governance Registry
+    // ══════════════════════════════════════════════════════════════
+    SubmitProposal {
+        action: ProposalAction,
+    },
+    ExecuteProposal {
+        proposal_id: u64,
+    },
+
+    // ══════════════════════════════════════════════════════════════
+    // Access Control
+    // ══════════════════════════════════════════════════════════════
+    /// Grant or revoke a role with signatures from the admin multisig.
+    UpdateRoleByAdmin {
+        role: Role,
+        account: [u8; 32],
+        granted: bool,
+        admin_signatures: Vec<Vec<u8>>,
+    },
+
+    // ══════════════════════════════════════════════════════════════
+    // Stream Settlement
+    // ══════════════════════════════════════════════════════════════
+    /// Recipient claims everything vested so far on a stream.
+    WithdrawStream {
+        stream_id: u64,
+    },
+    /// Sender closes a stream: vested funds go to the recipient, the rest is refunded.
+    CancelStream {
+        stream_id: u64,
+    },
+
+    // ══════════════════════════════════════════════════════════════
+    // Redemption Settlement
+    // ══════════════════════════════════════════════════════════════
+    /// Requester withdraws the unfilled part of a queued redemption.
+    CancelRedemption {
+        request_id: u64,
+    },
+
+    // ══════════════════════════════════════════════════════════════
+    // Validator Lifecycle
+    // ══════════════════════════════════════════════════════════════
+    /// Operator bonds additional Lumina to an existing validator.
+    AddStake {
+        pubkey: [u8; 32],
+        amount: u64,
+    },
+    /// Operator starts unbonding stake; it unlocks after the unbonding period.
+    Unbond {
+        pubkey: [u8; 32],
+        amount: u64,
+    },
+    /// Reclaims the sender's matured unbonding entries, as operator and/or delegator.
+    WithdrawStake {
+        pubkey: [u8; 32],
+    },
+
+    // ══════════════════════════════════════════════════════════════
+    // Delegation
+    // ══════════════════════════════════════════════════════════════
+    Delegate {
+        validator: [u8; 32],
+        amount: u64,
+    },
+    Undelegate {
+        validator: [u8; 32],
+        amount: u64,
+    },
+    ClaimDelegatorRewards {
+        validator: [u8; 32],
+    },
+    /// Operator sets the share of rewards kept before delegators are paid.
+    SetCommission {
+        pubkey: [u8; 32],
+        commission_bps: u64,
+    },
+
+    // ══════════════════════════════════════════════════════════════
+    // Slashing
+    // ══════════════════════════════════════════════════════════════
+    /// Anyone may submit proof that a validator double-signed.
+    SubmitEvidence {
+        evidence: DoubleSignEvidence,
+    },
+
+    // ══════════════════════════════════════════════════════════════
+    // Transaction Bundles
+    // ══════════════════════════════════════════════════════════════
+    /// Ordered instructions executed all-or-nothing under the enclosing
+    /// transaction's single nonce, signature and gas budget. Cannot be nested.
+    Bundle {
+        instructions: Vec<StablecoinInstruction>,
+    },
+
+    // ══════════════════════════════════════════════════════════════
+    // Passkey Authorization
+    // ══════════════════════════════════════════════════════════════
+    /// Authorize the sender's transactions with its registered passkey
+    /// (WebAuthn P-256) instead of its Ed25519 or post-quantum key.
+    SwitchToPasskeySignature,
+
+    // ══════════════════════════════════════════════════════════════
+    // Multisig Accounts
+    // ══════════════════════════════════════════════════════════════
+    /// Places the sender under m-of-n control; from then on its transactions
+    /// carry member signatures instead of a single signature.
+    CreateMultisigAccount {
+        members: Vec<MultisigMember>,
+        threshold: u32,
+    },
+    AddMultisigMember {
+        member: MultisigMember,
+    },
+    RemoveMultisigMember {
+        member: MultisigMember,
+    },
+    SetMultisigThreshold {
+        threshold: u32,
+    },
+
+    // ══════════════════════════════════════════════════════════════
+    // Session Keys
+    // ══════════════════════════════════════════════════════════════
+    /// Registers (or replaces) a delegate key that may sign only `permissions`,
+    /// spending at most `spend_limit` every `period_blocks`, until `expires_at`.
+    RegisterSessionKey {
+        pubkey: [u8; 32],
+        permissions: Vec<SessionPermission>,
+        spend_limit: u64,
+        period_blocks: u64,
+        expires_at: u64,
+    },
+    RevokeSessionKey {
+        pubkey: [u8; 32],
+    },
+
+    // ══════════════════════════════════════════════════════════════
+    // Asset Issuance
+    // ══════════════════════════════════════════════════════════════
+    /// Issuer-only: caps supply and/or delegates freezing. A cap cannot be
+    /// set below the current supply.
+    ConfigureAsset {
+        ticker: String,
+        max_supply: Option<u64>,
+        freeze_authority: Option<[u8; 32]>,
+    },
+    MintAsset {
+        ticker: String,
+        to: [u8; 32],
+        amount: u64,
+    },
+    /// Issuer-only: burns from the issuer's own balance.
+    BurnAsset {
+        ticker: String,
+        amount: u64,
+    },
+    /// Freeze-authority-only: a frozen account can neither send nor receive the asset.
+    SetAssetFrozen {
+        ticker: String,
+        account: [u8; 32],
+        frozen: bool,
+    },
+
+    // ══════════════════════════════════════════════════════════════
+    // Allowances
+    // ══════════════════════════════════════════════════════════════
+    /// Lets `spender` pull up to `amount` of `asset` from the sender.
+    /// Replaces any previous allowance for the same spender and asset.
+    Approve {
+        spender: [u8; 32],
+        asset: AssetType,
+        amount: u64,
+    },
+    /// Moves `amount` from `owner` to `to` against the sender's allowance.
+    TransferFrom {
+        owner: [u8; 32],
+        to: [u8; 32],
+        asset: AssetType,
+        amount: u64,
+    },
+    RevokeApproval {
+        spender: [u8; 32],
+        asset: AssetType,
+    },
+
+    // ══════════════════════════════════════════════════════════════
+    // Confidential Balances
+    // ══════════════════════════════════════════════════════════════
+    /// Moves `amount` public LUSD into the sender's confidential balance.
+    Shield {
+        amount: u64,
+    },
+    /// Moves `amount` out of the sender's confidential balance into public
+    /// LUSD. `proof` is the same spend proof `ConfidentialTransfer` takes,
+    /// over the amount committed with a zero blinding factor.
+    Unshield {
+        amount: u64,
+        proof: Vec<u8>,
+    },
+    /// Registers the Kyber768 key of an auditor who can read the sender's
+    /// confidential transfer amounts, or removes it with `None`.
+    SetAuditorKey {
+        auditor_pubkey: Option<Vec<u8>>,
+    },
+    /// Registers the Kyber768 key incoming confidential transfers are
+    /// encrypted to, or removes it with `None`. Accounts without one cannot
+    /// receive confidential transfers.
+    SetReceiveKey {
+        receive_pubkey: Option<Vec<u8>>,
+    },
+    /// Merges the sender's pending incoming transfers into its confidential
+    /// balance.
+    ApplyPendingBalance,
+
+    // ══════════════════════════════════════════════════════════════
+    // Yield Tokens
+    // ══════════════════════════════════════════════════════════════
+    /// Hands one side of a wrapped position to `to`.
+    TransferYieldToken {
+        token_id: u64,
+        side: YieldTokenSide,
+        to: [u8; 32],
+    },
+    /// Pays the yield accrued to a matured position to its yield-side holder.
+    ClaimYield {
+        token_id: u64,
+    },
+
+    // ══════════════════════════════════════════════════════════════
+    // RWA Lifecycle
+    // ══════════════════════════════════════════════════════════════
+    /// Burns `amount` of the sender's LUSD against an RWA loan.
+    RepayRWALoan {
+        rwa_id: u64,
+        amount: u64,
+    },
+    /// Releases pledged collateral value no longer backing debt.
+    UnpledgeRWA {
+        rwa_id: u64,
+        amount: u64,
+    },
+    /// Removes a listing with nothing pledged against it.
+    DelistRWA {
+        rwa_id: u64,
+    },
+    /// Hands a defaulted RWA to the insurance fund: one past maturity with
+    /// debt outstanding, or whose debt exceeds its attested value after the
+    /// asset class haircut.
+    LiquidateRWA {
+        rwa_id: u64,
+    },
+
+    // ══════════════════════════════════════════════════════════════
+    // RWA Valuation
+    // ══════════════════════════════════════════════════════════════
+    /// Replaces a listing's attested value as of the current block.
+    /// Only `RwaAttestor` role holders may submit revaluations.
+    RevalueRWA {
+        rwa_id: u64,
+        attested_value: u64,
+    },
+
+    // ══════════════════════════════════════════════════════════════
+    // Collateralized Debt Vaults
+    // ══════════════════════════════════════════════════════════════
+    /// Locks `amount` of a non-LUSD asset in the sender's vault.
+    DepositVaultCollateral {
+        asset: AssetType,
+        amount: u64,
+    },
+    /// Releases vault collateral, keeping any debt above the collateral ratio.
+    WithdrawVaultCollateral {
+        asset: AssetType,
+        amount: u64,
+    },
+    /// Mints LUSD against the sender's vault collateral.
+    DrawVaultDebt {
+        amount: u64,
+    },
+    /// Burns `amount` of the sender's LUSD against their vault debt.
+    RepayVaultDebt {
+        amount: u64,
+    },
+    /// Seizes an undercollateralized vault and opens a Dutch auction of its
+    /// collateral. Callable by anyone.
+    LiquidateVault {
+        owner: [u8; 32],
+    },
+    /// Buys from a vault auction at the current asking price, spending at
+    /// most `max_payment` LUSD.
+    BidVaultAuction {
+        auction_id: u64,
+        max_payment: u64,
+    },
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -221,3 +533,93 @@ pub enum AssetType {
     Lumina,
     Custom(String),
 }
+
+impl AssetType {
+    pub fn ticker(&self) -> &str {
+        match self {
+            AssetType::LUSD => "LUSD",
+            AssetType::LJUN => "LJUN",
+            AssetType::Lumina => "Lumina",
+            AssetType::Custom(ticker) => ticker,
+        }
+    }
+
+    /// Inverse of [`AssetType::ticker`]; native tickers are reserved.
+    pub fn from_ticker(ticker: &str) -> Self {
+        match ticker {
+            "LUSD" => AssetType::LUSD,
+            "LJUN" => AssetType::LJUN,
+            "Lumina" => AssetType::Lumina,
+            _ => AssetType::Custom(ticker.to_string()),
+        }
+    }
+
+    /// `oracle_prices` feed that quotes this asset in USD (1e6 fixed point).
+    pub fn price_feed(&self) -> String {
+        match self {
+            AssetType::LUSD => "LUSD-USD".to_string(),
+            AssetType::LJUN => "LJUN-USD".to_string(),
+            AssetType::Lumina => "LUMINA-USD".to_string(),
+            AssetType::Custom(ticker) => format!("{}-USD", ticker),
+        }
+    }
+}
+
+/// Prices are quote units per `PRICE_SCALE` base units.
+pub const PRICE_SCALE: u64 = 1_000_000;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderSide {
+    /// Pays quote for base
+    Buy,
+    /// Sells base for quote
+    Sell,
+}
+
+/// The two transferable claims a `WrapToYieldToken` position is split into.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YieldTokenSide {
+    /// Redeems the wrapped LUSD at maturity
+    Principal,
+    /// Collects what `DistributeYield` accrues to the position until maturity
+    Yield,
+}
+
+/// A maker's limit order for a batch auction.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LimitOrder {
+    pub maker: [u8; 32],
+    pub base: AssetType,
+    pub quote: AssetType,
+    pub side: OrderSide,
+    /// Worst acceptable price, in quote per `PRICE_SCALE` base
+    pub limit_price: u64,
+    /// Base amount to buy or sell
+    pub quantity: u64,
+    /// First height at which the order can no longer be filled
+    pub expires_at: u64,
+    /// Distinguishes otherwise identical orders from the same maker
+    pub salt: u64,
+}
+
+impl LimitOrder {
+    /// Message the maker signs; also the order's id.
+    pub fn id(&self) -> [u8; 32] {
+        let mut data = b"LUMINA_LIMIT_ORDER".to_vec();
+        data.extend(bincode::serialize(self).expect("order serialization"));
+        *blake3::hash(&data).as_bytes()
+    }
+
+    /// Quote value of `base_amount` at `price`, rounded down.
+    pub fn quote_value(base_amount: u64, price: u64) -> Option<u64> {
+        let value = (base_amount as u128) * (price as u128) / (PRICE_SCALE as u128);
+        u64::try_from(value).ok()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SignedOrder {
+    pub order: LimitOrder,
+    /// Maker's signature over `order.id()`
+    pub signature: Vec<u8>,
+}
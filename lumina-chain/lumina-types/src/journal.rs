@@ -0,0 +1,237 @@
+//! Write journals for state that must roll back when a transaction fails.
+//!
+//! [`JournaledMap`] keeps an undo log: the first write to a key after
+//! [`Journal::begin`] records that key's prior value, so rolling back only touches
+//! the keys the transaction wrote. [`Journaled`] covers every other collection
+//! copy-on-write, snapshotting the value on its first mutable access.
+//!
+//! Reads go through `Deref`; a `JournaledMap` is only mutable through its own
+//! methods, so no write can bypass the undo log.
+
+use serde::{Deserialize, Serialize};
+use std::collections::btree_map::{self, BTreeMap};
+use std::ops::{Deref, DerefMut};
+
+/// Checkpointing protocol shared by the journaled collections of `GlobalState`.
+/// Journals do not nest: `begin` discards any open journal.
+pub trait Journal {
+    /// Starts recording writes.
+    fn begin(&mut self);
+    /// Keeps every write since `begin` and stops recording.
+    fn commit(&mut self);
+    /// Undoes every write since `begin` and stops recording.
+    fn rollback(&mut self);
+}
+
+/// Ordered map with a per-key undo log.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(transparent)]
+#[serde(bound(
+    serialize = "K: Ord + Serialize, V: Serialize",
+    deserialize = "K: Ord + Deserialize<'de>, V: Deserialize<'de>"
+))]
+pub struct JournaledMap<K, V> {
+    map: BTreeMap<K, V>,
+    #[serde(skip)]
+    undo: Option<BTreeMap<K, Option<V>>>,
+}
+
+impl<K, V> Default for JournaledMap<K, V> {
+    fn default() -> Self {
+        Self {
+            map: BTreeMap::new(),
+            undo: None,
+        }
+    }
+}
+
+impl<K: Ord + Clone, V: Clone> JournaledMap<K, V> {
+    /// Records the prior value of `key` on its first write since `begin`.
+    fn touch(&mut self, key: &K) {
+        if let Some(undo) = &mut self.undo {
+            if !undo.contains_key(key) {
+                undo.insert(key.clone(), self.map.get(key).cloned());
+            }
+        }
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        if !self.map.contains_key(key) {
+            return None;
+        }
+        self.touch(key);
+        self.map.get_mut(key)
+    }
+
+    pub fn entry(&mut self, key: K) -> btree_map::Entry<'_, K, V> {
+        self.touch(&key);
+        self.map.entry(key)
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.touch(&key);
+        self.map.insert(key, value)
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        if !self.map.contains_key(key) {
+            return None;
+        }
+        self.touch(key);
+        self.map.remove(key)
+    }
+
+    fn touch_all(&mut self) {
+        if let Some(undo) = &mut self.undo {
+            for (key, value) in &self.map {
+                undo.entry(key.clone())
+                    .or_insert_with(|| Some(value.clone()));
+            }
+        }
+    }
+
+    /// Mutable iteration journals every entry; prefer `get_mut` on known keys.
+    pub fn iter_mut(&mut self) -> btree_map::IterMut<'_, K, V> {
+        self.touch_all();
+        self.map.iter_mut()
+    }
+
+    /// Mutable iteration journals every entry; prefer `get_mut` on known keys.
+    pub fn values_mut(&mut self) -> btree_map::ValuesMut<'_, K, V> {
+        self.touch_all();
+        self.map.values_mut()
+    }
+
+    /// Keeps the entries `keep` accepts; only the removed ones are journaled.
+    pub fn retain(&mut self, mut keep: impl FnMut(&K, &V) -> bool) {
+        let removed: Vec<K> = self
+            .map
+            .iter()
+            .filter(|(k, v)| !keep(k, v))
+            .map(|(k, _)| k.clone())
+            .collect();
+        for key in removed {
+            self.remove(&key);
+        }
+    }
+}
+
+impl<K: Ord + Clone, V: Clone> Journal for JournaledMap<K, V> {
+    fn begin(&mut self) {
+        self.undo = Some(BTreeMap::new());
+    }
+
+    fn commit(&mut self) {
+        self.undo = None;
+    }
+
+    fn rollback(&mut self) {
+        for (key, prior) in self.undo.take().unwrap_or_default() {
+            match prior {
+                Some(value) => self.map.insert(key, value),
+                None => self.map.remove(&key),
+            };
+        }
+    }
+}
+
+impl<K, V> Deref for JournaledMap<K, V> {
+    type Target = BTreeMap<K, V>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.map
+    }
+}
+
+impl<K: PartialEq, V: PartialEq> PartialEq for JournaledMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for JournaledMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self {
+            map: iter.into_iter().collect(),
+            undo: None,
+        }
+    }
+}
+
+impl<K: Ord, V> From<BTreeMap<K, V>> for JournaledMap<K, V> {
+    fn from(map: BTreeMap<K, V>) -> Self {
+        Self { map, undo: None }
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a JournaledMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = btree_map::Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.iter()
+    }
+}
+
+/// Copy-on-write wrapper: the first mutable access after `begin` snapshots the value.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(transparent)]
+pub struct Journaled<T> {
+    value: T,
+    #[serde(skip)]
+    recording: bool,
+    #[serde(skip)]
+    snapshot: Option<T>,
+}
+
+impl<T: Clone> Journal for Journaled<T> {
+    fn begin(&mut self) {
+        self.recording = true;
+        self.snapshot = None;
+    }
+
+    fn commit(&mut self) {
+        self.recording = false;
+        self.snapshot = None;
+    }
+
+    fn rollback(&mut self) {
+        if let Some(value) = self.snapshot.take() {
+            self.value = value;
+        }
+        self.recording = false;
+    }
+}
+
+impl<T> Deref for Journaled<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: Clone> DerefMut for Journaled<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        if self.recording && self.snapshot.is_none() {
+            self.snapshot = Some(self.value.clone());
+        }
+        &mut self.value
+    }
+}
+
+impl<T: PartialEq> PartialEq for Journaled<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T> From<T> for Journaled<T> {
+    fn from(value: T) -> Self {
+        Self {
+            value,
+            recording: false,
+            snapshot: None,
+        }
+    }
+}
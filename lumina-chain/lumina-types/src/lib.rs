@@ -1,9 +1,14 @@
 pub mod block;
+pub mod error;
 pub mod instruction;
+pub mod journal;
+pub mod receipt;
 pub mod state;
 pub mod transaction;
 
 pub use block::Block;
+pub use error::ExecutionError;
 pub use instruction::StablecoinInstruction;
+pub use receipt::Receipt;
 pub use state::GlobalState;
 pub use transaction::Transaction;
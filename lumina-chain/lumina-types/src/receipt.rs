@@ -0,0 +1,538 @@
+use crate::error::ExecutionError;
+use crate::instruction::{AssetType, OrderSide, YieldTokenSide};
+use crate::state::Role;
+use serde::{Deserialize, Serialize};
+
+/// Outcome of a transaction included in a block.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum ReceiptStatus {
+    Success,
+    Failed { error: ExecutionError },
+}
+
+/// Execution receipt committed per transaction through `BlockHeader::receipts_root`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Receipt {
+    pub tx_id: [u8; 32],
+    pub status: ReceiptStatus,
+    pub gas_used: u64,
+    pub events: Vec<Event>,
+}
+
+impl Receipt {
+    /// Block-level receipt carrying the events of `end_block` at `height`, committed
+    /// after the transaction receipts. Its id is domain-separated from transaction ids.
+    pub fn end_block(height: u64, events: Vec<Event>) -> Self {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(b"lumina:end_block");
+        hasher.update(&height.to_le_bytes());
+        Receipt {
+            tx_id: *hasher.finalize().as_bytes(),
+            status: ReceiptStatus::Success,
+            gas_used: 0,
+            events,
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.status == ReceiptStatus::Success
+    }
+
+    pub fn error(&self) -> Option<&ExecutionError> {
+        match &self.status {
+            ReceiptStatus::Success => None,
+            ReceiptStatus::Failed { error } => Some(error),
+        }
+    }
+
+    /// Commitment to this receipt. A failure is hashed as its error code plus the
+    /// error's typed fields; message text never reaches `receipts_root`.
+    pub fn hash(&self) -> [u8; 32] {
+        let failure = self
+            .error()
+            .map(|error| (error.code(), error.without_messages()));
+        let encoded = bincode::serialize(&(&self.tx_id, failure, self.gas_used, &self.events))
+            .expect("receipt serialization");
+        *blake3::hash(&encoded).as_bytes()
+    }
+}
+
+/// Kind of zero-knowledge proof checked by an instruction.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum ProofKind {
+    Compliance,
+    TaxAttestation,
+    MultiJurisdictional,
+    GreenEnergy,
+    Reserve,
+    ConfidentialRange,
+    InsuranceLoss,
+    RwaAttestation,
+    DoubleSignEvidence,
+    OrderSignature,
+}
+
+/// Typed event emitted by a StablecoinInstruction, in execution order.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum Event {
+    // ══════════════════════════════════════════════════════════════
+    // Balances & Supply
+    // ══════════════════════════════════════════════════════════════
+    AssetRegistered {
+        ticker: String,
+        decimals: u8,
+    },
+    AssetConfigured {
+        ticker: String,
+        max_supply: Option<u64>,
+        freeze_authority: Option<[u8; 32]>,
+    },
+    AssetFreezeUpdated {
+        ticker: String,
+        account: [u8; 32],
+        frozen: bool,
+    },
+    Minted {
+        to: [u8; 32],
+        asset: AssetType,
+        amount: u64,
+    },
+    Burned {
+        from: [u8; 32],
+        asset: AssetType,
+        amount: u64,
+    },
+    Transferred {
+        from: [u8; 32],
+        to: [u8; 32],
+        asset: AssetType,
+        amount: u64,
+    },
+    /// `amount` is the allowance remaining after the change
+    AllowanceUpdated {
+        owner: [u8; 32],
+        spender: [u8; 32],
+        asset: AssetType,
+        amount: u64,
+    },
+
+    // ══════════════════════════════════════════════════════════════
+    // Pools & Stability
+    // ══════════════════════════════════════════════════════════════
+    CollateralDeposited {
+        account: [u8; 32],
+        amount: u64,
+    },
+    CollateralReleased {
+        account: [u8; 32],
+        amount: u64,
+    },
+    InsuranceFundCredited {
+        amount: u64,
+    },
+    InsuranceFundDebited {
+        amount: u64,
+    },
+    StabilizationPoolUpdated {
+        balance: u64,
+        reserve_ratio_bps: u64,
+    },
+    YieldDistributed {
+        junior_share: u64,
+        pool_share: u64,
+        insurance_share: u64,
+    },
+    CircuitBreakerSet {
+        active: bool,
+    },
+    RedemptionQueued {
+        request_id: u64,
+        account: [u8; 32],
+        amount: u64,
+    },
+    RedemptionSettled {
+        request_id: u64,
+        account: [u8; 32],
+        amount: u64,
+        remaining: u64,
+    },
+    RedemptionCancelled {
+        request_id: u64,
+        account: [u8; 32],
+        refunded: u64,
+    },
+
+    // ══════════════════════════════════════════════════════════════
+    // Privacy, Compliance & Oracles
+    // ══════════════════════════════════════════════════════════════
+    CommitmentUpdated {
+        account: [u8; 32],
+        commitment: [u8; 32],
+    },
+    Shielded {
+        account: [u8; 32],
+        amount: u64,
+    },
+    Unshielded {
+        account: [u8; 32],
+        amount: u64,
+    },
+    ConfidentialTransferred {
+        from: [u8; 32],
+        to: [u8; 32],
+        amount_commitment: [u8; 32],
+        recipient_note: Vec<u8>,
+        audit_note: Option<Vec<u8>>,
+    },
+    PendingCommitmentUpdated {
+        account: [u8; 32],
+        commitment: [u8; 32],
+    },
+    AuditorKeyUpdated {
+        account: [u8; 32],
+        registered: bool,
+    },
+    ReceiveKeyUpdated {
+        account: [u8; 32],
+        registered: bool,
+    },
+    ProofVerified {
+        kind: ProofKind,
+    },
+    OracleUpdated {
+        asset: String,
+        price: u64,
+    },
+    ReservesAttested {
+        total_reserves: u64,
+        timestamp: u64,
+    },
+    ComplianceCircuitUploaded {
+        circuit_id: u64,
+    },
+
+    // ══════════════════════════════════════════════════════════════
+    // DeFi Hooks
+    // ══════════════════════════════════════════════════════════════
+    FiatBridged {
+        account: [u8; 32],
+        amount: u64,
+        target_bank_id: [u8; 16],
+    },
+    BatchMatched {
+        batch_id: [u8; 32],
+        order_count: u32,
+        clearing_price: u64,
+        /// Total base amount exchanged
+        volume: u64,
+    },
+    OrderFilled {
+        order_id: [u8; 32],
+        maker: [u8; 32],
+        side: OrderSide,
+        base_amount: u64,
+        quote_amount: u64,
+        price: u64,
+    },
+    CustodiansReordered {
+        zone_id: u32,
+    },
+    VelocityPoolFunded {
+        amount: u64,
+    },
+    StreamOpened {
+        stream_id: u64,
+        from: [u8; 32],
+        to: [u8; 32],
+        amount_per_sec: u64,
+        end_timestamp: u64,
+    },
+    StreamWithdrawn {
+        stream_id: u64,
+        recipient: [u8; 32],
+        amount: u64,
+    },
+    StreamClosed {
+        stream_id: u64,
+        paid_to_recipient: u64,
+        refunded_to_sender: u64,
+    },
+
+    // ══════════════════════════════════════════════════════════════
+    // Governance, Staking & Accounts
+    // ══════════════════════════════════════════════════════════════
+    ValidatorRegistered {
+        pubkey: [u8; 32],
+        stake: u64,
+    },
+    GreenValidatorRegistered {
+        pubkey: [u8; 32],
+        power: u64,
+    },
+    StakeAdded {
+        pubkey: [u8; 32],
+        amount: u64,
+        total_stake: u64,
+    },
+    StakeUnbonding {
+        pubkey: [u8; 32],
+        amount: u64,
+        release_height: u64,
+    },
+    StakeWithdrawn {
+        pubkey: [u8; 32],
+        account: [u8; 32],
+        amount: u64,
+    },
+    Delegated {
+        delegator: [u8; 32],
+        pubkey: [u8; 32],
+        amount: u64,
+    },
+    Undelegated {
+        delegator: [u8; 32],
+        pubkey: [u8; 32],
+        amount: u64,
+        release_height: u64,
+    },
+    DelegatorRewardsClaimed {
+        delegator: [u8; 32],
+        pubkey: [u8; 32],
+        amount: u64,
+    },
+    CommissionUpdated {
+        pubkey: [u8; 32],
+        commission_bps: u64,
+    },
+    ValidatorSlashed {
+        pubkey: [u8; 32],
+        infraction_height: u64,
+        amount: u64,
+        jailed_until: u64,
+    },
+    StakingRewardsDistributed {
+        epoch: u64,
+        amount: u64,
+    },
+    ValidatorSetUpdated {
+        epoch: u64,
+        active_validators: u32,
+        total_power: u64,
+    },
+    VoteCast {
+        voter: [u8; 32],
+        proposal_id: u64,
+        approve: bool,
+    },
+    ProposalSubmitted {
+        proposal_id: u64,
+        proposer: [u8; 32],
+        voting_end_height: u64,
+    },
+    ProposalFinalized {
+        proposal_id: u64,
+        approved: bool,
+        yes_power: u64,
+        no_power: u64,
+    },
+    ProposalExecuted {
+        proposal_id: u64,
+    },
+    RoleUpdated {
+        role: Role,
+        account: [u8; 32],
+        granted: bool,
+    },
+    AdminMultisigUpdated {
+        member_count: u32,
+        threshold: u32,
+    },
+    PasskeyRegistered {
+        account: [u8; 32],
+        guardian_count: u32,
+    },
+    PasskeyRecovered {
+        account: [u8; 32],
+    },
+    PqKeyRegistered {
+        account: [u8; 32],
+    },
+    PasskeySignatureEnabled {
+        account: [u8; 32],
+    },
+    MultisigAccountCreated {
+        account: [u8; 32],
+        member_count: u32,
+        threshold: u32,
+    },
+    MultisigUpdated {
+        account: [u8; 32],
+        member_count: u32,
+        threshold: u32,
+    },
+    SessionKeyRegistered {
+        account: [u8; 32],
+        pubkey: [u8; 32],
+        expires_at: u64,
+    },
+    SessionKeyRevoked {
+        account: [u8; 32],
+        pubkey: [u8; 32],
+    },
+    VelocityRewardClaimed {
+        account: [u8; 32],
+        epoch: u64,
+        amount: u64,
+    },
+    CustodianRegistered {
+        pubkey: [u8; 32],
+        stake: u64,
+    },
+    ReservesRotated {
+        custodian_count: u32,
+    },
+    InsuranceClaimPaid {
+        account: [u8; 32],
+        amount: u64,
+    },
+
+    // ══════════════════════════════════════════════════════════════
+    // Capital Efficiency & RWA
+    // ══════════════════════════════════════════════════════════════
+    FlashMinted {
+        account: [u8; 32],
+        amount: u64,
+        collateral_amount: u64,
+    },
+    FlashBurned {
+        account: [u8; 32],
+        amount: u64,
+        collateral_released: u64,
+    },
+    CreditScoreRecorded {
+        account: [u8; 32],
+        score: u16,
+    },
+    YieldTokenWrapped {
+        account: [u8; 32],
+        token_id: u64,
+        principal: u64,
+        maturity_height: u64,
+    },
+    YieldTokenUnwrapped {
+        account: [u8; 32],
+        token_id: u64,
+        principal: u64,
+        yield_paid: u64,
+    },
+    YieldTokenTransferred {
+        token_id: u64,
+        side: YieldTokenSide,
+        from: [u8; 32],
+        to: [u8; 32],
+    },
+    /// Part of a `DistributeYield` junior share credited to open positions
+    YieldTokensAccrued {
+        amount: u64,
+        positions: u32,
+    },
+    YieldClaimed {
+        account: [u8; 32],
+        token_id: u64,
+        amount: u64,
+    },
+    RwaListed {
+        rwa_id: u64,
+        owner: [u8; 32],
+        attested_value: u64,
+    },
+    RwaPledged {
+        rwa_id: u64,
+        account: [u8; 32],
+        amount: u64,
+    },
+    RwaLoanRepaid {
+        rwa_id: u64,
+        account: [u8; 32],
+        amount: u64,
+        remaining_debt: u64,
+    },
+    RwaUnpledged {
+        rwa_id: u64,
+        amount: u64,
+        pledged_amount: u64,
+    },
+    RwaDelisted {
+        rwa_id: u64,
+    },
+    RwaMatured {
+        rwa_id: u64,
+    },
+    /// `owner` defaulted and the insurance fund takes over its claim on the
+    /// asset, last attested at `attested_value`. `insurance_covered` of the
+    /// written-off `debt` was paid from the fund; the rest came out of the
+    /// stabilization pool
+    RwaLiquidated {
+        rwa_id: u64,
+        owner: [u8; 32],
+        attested_value: u64,
+        debt: u64,
+        insurance_covered: u64,
+    },
+    RwaRevalued {
+        rwa_id: u64,
+        attestor: [u8; 32],
+        attested_value: u64,
+    },
+    HealthIndexComputed {
+        health_index: u64,
+    },
+    VaultCollateralDeposited {
+        account: [u8; 32],
+        asset: AssetType,
+        amount: u64,
+    },
+    VaultCollateralWithdrawn {
+        account: [u8; 32],
+        asset: AssetType,
+        amount: u64,
+    },
+    VaultDebtDrawn {
+        account: [u8; 32],
+        amount: u64,
+        debt: u64,
+    },
+    VaultDebtRepaid {
+        account: [u8; 32],
+        amount: u64,
+        remaining_debt: u64,
+    },
+    /// `owner`'s vault was seized; its collateral is for sale in `auction_id`
+    VaultLiquidated {
+        owner: [u8; 32],
+        auction_id: u64,
+        debt: u64,
+        penalty: u64,
+        start_price: u64,
+    },
+    /// The asking price reached zero unsold and was reset from the oracle value
+    VaultAuctionRestarted {
+        auction_id: u64,
+        start_price: u64,
+    },
+    /// `bidder` paid `paid` LUSD at asking price `price` for the whole lot
+    VaultAuctionBid {
+        auction_id: u64,
+        bidder: [u8; 32],
+        paid: u64,
+        price: u64,
+    },
+    /// Auction ended; unsold collateral went back to the owner and
+    /// `shortfall` of uncovered debt was written off, `insurance_covered` of it
+    /// by the insurance fund
+    VaultAuctionClosed {
+        auction_id: u64,
+        shortfall: u64,
+        insurance_covered: u64,
+    },
+}
@@ -1,5 +1,8 @@
+use crate::error::ExecutionError;
+use crate::instruction::AssetType;
+use crate::journal::{Journal, Journaled, JournaledMap};
 use serde::{Deserialize, Serialize};
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
 
 /// Per-account state stored in the global state tree.
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
@@ -9,13 +12,29 @@ pub struct AccountState {
     pub ljun_balance: u64,
     pub lumina_balance: u64,
     pub custom_balances: HashMap<String, u64>,
+    /// Pedersen commitment to the account's confidential LUSD balance
     pub commitment: Option<[u8; 32]>,
+    /// Incoming confidential transfers, kept apart from `commitment` until the
+    /// owner merges them, so a sender can't change a balance under its owner
+    pub pending_commitment: Option<[u8; 32]>,
+    /// Kyber768 key that senders encrypt incoming transfer openings to
+    pub receive_key: Option<Vec<u8>>,
+    /// Kyber768 key that confidential transfer amounts are encrypted to
+    pub auditor_key: Option<Vec<u8>>,
     /// Passkey device key (65 bytes WebAuthn compressed public key)
     pub passkey_device_key: Option<Vec<u8>>,
     /// Social recovery guardians (list of pubkeys)
     pub guardians: Vec<[u8; 32]>,
     /// Post-quantum public key (Dilithium/Falcon), if account has opted in
     pub pq_pubkey: Option<Vec<u8>>,
+    /// Transactions are authorized by WebAuthn assertions from `passkey_device_key`
+    pub passkey_auth: bool,
+    /// When set, transactions need `threshold` member signatures instead of one key
+    pub multisig: Option<MultisigPolicy>,
+    /// Delegate keys allowed to sign a limited set of instructions
+    pub session_keys: Vec<SessionKey>,
+    /// Amounts each spender may pull from this account, by spender then asset ticker
+    pub allowances: BTreeMap<[u8; 32], BTreeMap<String, u64>>,
     /// Cumulative transaction volume for velocity reward calculation (per epoch)
     pub epoch_tx_volume: u64,
     /// Last epoch in which velocity rewards were claimed
@@ -24,16 +43,91 @@ pub struct AccountState {
     pub credit_score: u16,
     /// Active stream payments originated by this account
     pub active_streams: Vec<StreamState>,
-    /// Yield token positions
-    pub yield_positions: Vec<YieldPosition>,
+    /// IDs of active streams paying into this account
+    pub incoming_streams: Vec<u64>,
 
     pub pending_flash_mint: u64,
     pub pending_flash_collateral: u64,
+    /// Vault collateral asset locked by the pending flash mint
+    pub pending_flash_asset: Option<AssetType>,
+}
+
+/// Key of one multisig account member.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum MultisigMember {
+    Ed25519([u8; 32]),
+    PostQuantum(Vec<u8>),
+}
+
+/// Signer set controlling a multisig account.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct MultisigPolicy {
+    pub members: Vec<MultisigMember>,
+    pub threshold: u32,
+}
+
+impl AccountState {
+    /// What `spender` may still pull from this account in `ticker`.
+    pub fn allowance(&self, spender: &[u8; 32], ticker: &str) -> u64 {
+        self.allowances
+            .get(spender)
+            .and_then(|by_asset| by_asset.get(ticker))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Sets the allowance, dropping the entry when it reaches zero.
+    pub fn set_allowance(&mut self, spender: &[u8; 32], ticker: &str, amount: u64) {
+        if amount > 0 {
+            self.allowances
+                .entry(*spender)
+                .or_default()
+                .insert(ticker.to_string(), amount);
+        } else if let Some(by_asset) = self.allowances.get_mut(spender) {
+            by_asset.remove(ticker);
+            if by_asset.is_empty() {
+                self.allowances.remove(spender);
+            }
+        }
+    }
+}
+
+/// Instruction a session key may sign.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum SessionPermission {
+    Transfer { asset: AssetType },
+    StreamPayment,
+}
+
+/// Ed25519 delegate key scoped to a few instructions and a spending budget.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SessionKey {
+    pub pubkey: [u8; 32],
+    pub permissions: Vec<SessionPermission>,
+    /// Maximum amount spent per period, summed over permitted instructions
+    pub spend_limit: u64,
+    pub period_blocks: u64,
+    /// First height at which the key is no longer accepted
+    pub expires_at: u64,
+    pub period_start: u64,
+    pub spent_in_period: u64,
+}
+
+impl SessionKey {
+    /// Amount already spent in the period containing `height`.
+    pub fn spent_at(&self, height: u64) -> u64 {
+        if height.saturating_sub(self.period_start) >= self.period_blocks {
+            0
+        } else {
+            self.spent_in_period
+        }
+    }
 }
 
 /// Streaming payment state
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct StreamState {
+    pub stream_id: u64,
     pub recipient: [u8; 32],
     pub amount_per_sec: u64,
     pub start_timestamp: u64,
@@ -41,19 +135,61 @@ pub struct StreamState {
     pub withdrawn: u64,
 }
 
-/// Yield-bearing wrapped token position
+impl StreamState {
+    /// Total LUSD escrowed when the stream was opened.
+    pub fn total(&self) -> u64 {
+        self.amount_per_sec
+            .saturating_mul(self.end_timestamp.saturating_sub(self.start_timestamp))
+    }
+
+    /// LUSD streamed to the recipient by `now`, including amounts already withdrawn.
+    pub fn vested(&self, now: u64) -> u64 {
+        let elapsed = now
+            .min(self.end_timestamp)
+            .saturating_sub(self.start_timestamp);
+        self.amount_per_sec.saturating_mul(elapsed)
+    }
+
+    /// Vested LUSD the recipient has not yet withdrawn.
+    pub fn withdrawable(&self, now: u64) -> u64 {
+        self.vested(now).saturating_sub(self.withdrawn)
+    }
+}
+
+/// Wrapped LUSD split into a principal side and a yield side, each held and
+/// transferred independently
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct YieldPosition {
     pub token_id: u64,
     pub principal: u64,
     pub maturity_height: u64,
     pub issued_height: u64,
+    /// Holder of the principal side; `None` once redeemed
+    pub principal_owner: Option<[u8; 32]>,
+    /// Holder of the yield side; `None` once claimed
+    pub yield_owner: Option<[u8; 32]>,
+    /// LUSD credited by `DistributeYield` before maturity, not yet paid out
+    pub accrued_yield: u64,
+}
+
+impl YieldPosition {
+    /// Key of position `token_id` in the state trie; hashed so it cannot
+    /// collide with an account key.
+    pub fn trie_key(token_id: u64) -> [u8; 32] {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(b"lumina:yield_position");
+        hasher.update(&token_id.to_le_bytes());
+        *hasher.finalize().as_bytes()
+    }
 }
 
 /// Global chain state — the complete state of LuminaChain at any height.
+///
+/// Collections that grow with usage are journaled, so a failing transaction rolls
+/// back through [`GlobalState::checkpoint`] without copying the whole state.
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct GlobalState {
-    pub accounts: HashMap<[u8; 32], AccountState>,
+    pub accounts: JournaledMap<[u8; 32], AccountState>,
     pub total_lusd_supply: u64,
     pub total_ljun_supply: u64,
 
@@ -61,34 +197,59 @@ pub struct GlobalState {
     pub stabilization_pool_balance: u64,
     pub reserve_ratio: f64,
     pub oracle_prices: HashMap<String, u64>,
-    pub validators: Vec<ValidatorState>,
+    pub validators: Journaled<Vec<ValidatorState>>,
 
     // Protection
     pub circuit_breaker_active: bool,
-    pub fair_redeem_queue: Vec<RedemptionRequest>,
+    pub fair_redeem_queue: Journaled<VecDeque<RedemptionRequest>>,
+    pub next_redemption_id: u64,
     pub last_rebalance_height: u64,
 
-    // Insurance fund
+    // Insurance fund, in LUSD and in Lumina slashed from validator stake
     pub insurance_fund_balance: u64,
+    pub insurance_fund_lumina: u64,
+
+    // LUSD held in confidential balances (still counted in total supply)
+    pub shielded_lusd_supply: u64,
+
+    // Gas fees collected in Lumina, pending distribution to validators
+    pub gas_fee_pool: u64,
 
     // Custodian marketplace
-    pub custodians: Vec<CustodianState>,
+    pub custodians: Journaled<Vec<CustodianState>>,
     pub last_reserve_rotation_height: u64,
 
     // Compliance circuits registry
-    pub compliance_circuits: HashMap<u64, Vec<u8>>,
+    pub compliance_circuits: JournaledMap<u64, Vec<u8>>,
+
+    // Issued (custom) assets by ticker
+    pub asset_registry: Journaled<AssetRegistry>,
 
     // RWA registry
-    pub rwa_listings: HashMap<u64, RWAListing>,
+    pub rwa_listings: JournaledMap<u64, RWAListing>,
+    // Listing ids by maturity_date, so end_block settles only the ones due
+    pub rwa_maturities: JournaledMap<u64, BTreeSet<u64>>,
     pub next_rwa_id: u64,
+    // Per-class valuation haircuts, LTV caps and staleness limits set by governance
+    pub rwa_class_params: BTreeMap<RwaAssetClass, RwaClassParams>,
+
+    // Collateralized debt vaults by owner, and Dutch auctions of liquidated vaults
+    pub vaults: JournaledMap<[u8; 32], Vault>,
+    pub vault_auctions: JournaledMap<u64, VaultAuction>,
+    pub next_vault_auction_id: u64,
 
     // Credit oracle allowlist + proof replay protection
     pub trusted_credit_oracles: Vec<[u8; 32]>,
-    pub used_credit_proofs: Vec<[u8; 32]>,
+    pub used_credit_proofs: Journaled<Vec<[u8; 32]>>,
 
-    // Yield token counter
+    // Yield token registry by token id
+    pub yield_positions: JournaledMap<u64, YieldPosition>,
     pub next_yield_token_id: u64,
 
+    // Stream payments: id counter and stream id -> sender index
+    pub next_stream_id: u64,
+    pub stream_senders: JournaledMap<u64, [u8; 32]>,
+
     // Health index (0..10000 representing 0.00..100.00)
     pub health_index: u64,
 
@@ -103,21 +264,187 @@ pub struct GlobalState {
     pub last_por_timestamp: u64,
     pub last_por_hash: Option<[u8; 32]>,
 
-    // Replay protection for zero-slip batches.
-    pub executed_batch_matches: Vec<[u8; 32]>,
+    // Base amount filled per limit order id, until the order expires.
+    // Doubles as replay protection for zero-slip batches.
+    pub order_fills: JournaledMap<[u8; 32], OrderFill>,
+
+    // Economic parameters, changeable only through governance
+    pub protocol_params: ProtocolParams,
+
+    // Governance proposal registry
+    pub proposals: JournaledMap<u64, Proposal>,
+    // Active proposal ids by voting_end_height, so end_block tallies only expiring ones
+    pub active_proposals_by_end: JournaledMap<u64, BTreeSet<u64>>,
+    pub next_proposal_id: u64,
+    pub governance_params: GovernanceParams,
+
+    // Access control: privileged role holders and the admin multisig managing them
+    pub roles: Journaled<BTreeMap<Role, BTreeSet<[u8; 32]>>>,
+    pub admin_multisig: AdminMultisig,
+
+    // Double-sign infractions already punished, as (height, validator); entries
+    // older than evidence_max_age_blocks are pruned
+    pub slashed_infractions: Journaled<BTreeSet<(u64, [u8; 32])>>,
 }
 
 impl GlobalState {
+    /// Risk parameters in force for `class`.
+    pub fn rwa_class_params(&self, class: RwaAssetClass) -> RwaClassParams {
+        self.rwa_class_params
+            .get(&class)
+            .cloned()
+            .unwrap_or_else(|| RwaClassParams::default_for(class))
+    }
+
+    pub fn has_role(&self, role: Role, account: &[u8; 32]) -> bool {
+        self.roles
+            .get(&role)
+            .is_some_and(|holders| holders.contains(account))
+    }
+
+    pub fn set_role(&mut self, role: Role, account: [u8; 32], granted: bool) {
+        if granted {
+            self.roles.entry(role).or_default().insert(account);
+        } else if let Some(holders) = self.roles.get_mut(&role) {
+            holders.remove(&account);
+            if holders.is_empty() {
+                self.roles.remove(&role);
+            }
+        }
+    }
+
+    /// Zero-based position of a queued redemption (0 = next to be settled).
+    pub fn redemption_position(&self, request_id: u64) -> Option<usize> {
+        self.fair_redeem_queue
+            .iter()
+            .position(|r| r.request_id == request_id)
+    }
+
+    /// Starts journaling writes; the checkpoint holds only the non-journaled fields.
+    /// Checkpoints do not nest.
+    pub fn checkpoint(&mut self) -> StateCheckpoint {
+        let mut detached = GlobalState::default();
+        self.swap_journaled(&mut detached);
+        let base = self.clone();
+        self.swap_journaled(&mut detached);
+        for journal in self.journals() {
+            journal.begin();
+        }
+        StateCheckpoint {
+            base: Box::new(base),
+        }
+    }
+
+    /// Keeps every write made since `checkpoint`.
+    pub fn commit(&mut self, _checkpoint: StateCheckpoint) {
+        for journal in self.journals() {
+            journal.commit();
+        }
+    }
+
+    /// Restores the state as it was at `checkpoint`.
+    pub fn rollback(&mut self, checkpoint: StateCheckpoint) {
+        for journal in self.journals() {
+            journal.rollback();
+        }
+        let mut base = *checkpoint.base;
+        self.swap_journaled(&mut base);
+        *self = base;
+    }
+
+    fn journals(&mut self) -> [&mut dyn Journal; 18] {
+        [
+            &mut self.accounts,
+            &mut self.validators,
+            &mut self.fair_redeem_queue,
+            &mut self.custodians,
+            &mut self.compliance_circuits,
+            &mut self.asset_registry,
+            &mut self.rwa_listings,
+            &mut self.rwa_maturities,
+            &mut self.vaults,
+            &mut self.vault_auctions,
+            &mut self.used_credit_proofs,
+            &mut self.yield_positions,
+            &mut self.stream_senders,
+            &mut self.order_fills,
+            &mut self.proposals,
+            &mut self.active_proposals_by_end,
+            &mut self.roles,
+            &mut self.slashed_infractions,
+        ]
+    }
+
+    fn swap_journaled(&mut self, other: &mut GlobalState) {
+        std::mem::swap(&mut self.accounts, &mut other.accounts);
+        std::mem::swap(&mut self.validators, &mut other.validators);
+        std::mem::swap(&mut self.fair_redeem_queue, &mut other.fair_redeem_queue);
+        std::mem::swap(&mut self.custodians, &mut other.custodians);
+        std::mem::swap(
+            &mut self.compliance_circuits,
+            &mut other.compliance_circuits,
+        );
+        std::mem::swap(&mut self.asset_registry, &mut other.asset_registry);
+        std::mem::swap(&mut self.rwa_listings, &mut other.rwa_listings);
+        std::mem::swap(&mut self.rwa_maturities, &mut other.rwa_maturities);
+        std::mem::swap(&mut self.vaults, &mut other.vaults);
+        std::mem::swap(&mut self.vault_auctions, &mut other.vault_auctions);
+        std::mem::swap(&mut self.used_credit_proofs, &mut other.used_credit_proofs);
+        std::mem::swap(&mut self.yield_positions, &mut other.yield_positions);
+        std::mem::swap(&mut self.stream_senders, &mut other.stream_senders);
+        std::mem::swap(&mut self.order_fills, &mut other.order_fills);
+        std::mem::swap(&mut self.proposals, &mut other.proposals);
+        std::mem::swap(
+            &mut self.active_proposals_by_end,
+            &mut other.active_proposals_by_end,
+        );
+        std::mem::swap(&mut self.roles, &mut other.roles);
+        std::mem::swap(
+            &mut self.slashed_infractions,
+            &mut other.slashed_infractions,
+        );
+    }
+
+    /// State trie root over accounts, the yield token registry, vaults and
+    /// vault auctions.
     pub fn root_hash(&self) -> [u8; 32] {
-        let entries: BTreeMap<[u8; 32], Vec<u8>> = self
+        let accounts = self
             .accounts
             .iter()
-            .map(|(k, v)| (*k, bincode::serialize(v).expect("account serialization")))
+            .map(|(k, v)| (*k, bincode::serialize(v).expect("account serialization")));
+        let positions = self.yield_positions.iter().map(|(id, p)| {
+            (
+                YieldPosition::trie_key(*id),
+                bincode::serialize(p).expect("yield position serialization"),
+            )
+        });
+        let vaults = self.vaults.iter().map(|(owner, v)| {
+            (
+                Vault::trie_key(owner),
+                bincode::serialize(v).expect("vault serialization"),
+            )
+        });
+        let auctions = self.vault_auctions.iter().map(|(id, a)| {
+            (
+                VaultAuction::trie_key(*id),
+                bincode::serialize(a).expect("vault auction serialization"),
+            )
+        });
+        let entries: BTreeMap<[u8; 32], Vec<u8>> = accounts
+            .chain(positions)
+            .chain(vaults)
+            .chain(auctions)
             .collect();
         account_trie_root(&entries)
     }
 }
 
+/// Non-journaled part of a [`GlobalState`], restored by [`GlobalState::rollback`].
+#[derive(Debug)]
+pub struct StateCheckpoint {
+    base: Box<GlobalState>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 enum MptNode {
     Leaf {
@@ -231,18 +558,284 @@ fn bytes_to_nibbles(bytes: &[u8; 32]) -> Vec<u8> {
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct RedemptionRequest {
+    pub request_id: u64,
     pub address: [u8; 32],
     pub amount: u64,
     pub timestamp: u64,
+    /// Portion of `amount` already settled by partial fills
+    pub filled: u64,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+impl RedemptionRequest {
+    pub fn remaining(&self) -> u64 {
+        self.amount.saturating_sub(self.filled)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct ValidatorState {
     pub pubkey: [u8; 32],
+    /// Account that bonded the stake and receives it back on withdrawal
+    pub operator: [u8; 32],
+    /// Bonded stake; changes take effect on `power` at the next epoch boundary
     pub stake: u64,
+    /// Consensus voting power, fixed for the current epoch
     pub power: u64,
     pub is_green: bool,
     pub energy_proof: Option<Vec<u8>>,
+    pub status: ValidatorStatus,
+    /// Stake leaving the validator, withdrawable once each entry matures
+    pub unbonding: Vec<UnbondingEntry>,
+    /// Share of epoch rewards kept by the operator before delegators are paid
+    pub commission_bps: u64,
+    /// Total stake delegated to this validator; counts toward `power`
+    pub delegated: u64,
+    /// Cumulative delegator reward per delegated unit, scaled by `REWARD_SCALE`
+    pub reward_per_share: u128,
+    pub delegations: BTreeMap<[u8; 32], Delegation>,
+}
+
+/// Fixed-point scale for `ValidatorState::reward_per_share`.
+pub const REWARD_SCALE: u128 = 1_000_000_000_000;
+
+/// One delegator's position with a validator.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Delegation {
+    pub amount: u64,
+    /// `amount * reward_per_share` at the last settlement
+    pub reward_debt: u128,
+    /// Rewards settled but not yet claimed
+    pub pending_rewards: u64,
+    pub unbonding: Vec<UnbondingEntry>,
+}
+
+impl Delegation {
+    /// Rewards accrued since the last settlement plus those already pending.
+    pub fn claimable(&self, reward_per_share: u128) -> u64 {
+        let accrued = (self.amount as u128)
+            .saturating_mul(reward_per_share)
+            .saturating_sub(self.reward_debt)
+            / REWARD_SCALE;
+        self.pending_rewards
+            .saturating_add(u64::try_from(accrued).unwrap_or(u64::MAX))
+    }
+}
+
+impl ValidatorState {
+    /// Power this validator will carry from the next epoch boundary.
+    pub fn target_power(&self) -> u64 {
+        if self.status != ValidatorStatus::Active {
+            return 0;
+        }
+        // Green validators get 2x voting power
+        let bonded = self.stake.saturating_add(self.delegated);
+        if self.is_green {
+            bonded.saturating_mul(2)
+        } else {
+            bonded
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ValidatorStatus {
+    #[default]
+    Active,
+    /// Excluded from the set until `until_height`
+    Jailed { until_height: u64 },
+    /// All stake unbonded; removed once the unbonding entries are withdrawn
+    Unbonding,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct UnbondingEntry {
+    pub amount: u64,
+    pub release_height: u64,
+}
+
+/// Privileged capability required by operator instructions.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Role {
+    /// Emergency controls (circuit breaker)
+    Guardian,
+    /// Price feed publisher
+    Oracle,
+    /// Compliance circuit management
+    ComplianceAdmin,
+    /// Yield, incentive and reserve management
+    Treasury,
+    /// Publishes RWA revaluations
+    RwaAttestor,
+}
+
+/// m-of-n admin keys allowed to grant and revoke roles outside of governance.
+/// Disabled while `members` is empty.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AdminMultisig {
+    pub members: Vec<[u8; 32]>,
+    pub threshold: u32,
+    /// Bumped on every accepted update so signatures cannot be replayed
+    pub nonce: u64,
+}
+
+impl AdminMultisig {
+    /// Message each admin member signs to approve a role change.
+    pub fn role_update_message(&self, role: Role, account: &[u8; 32], granted: bool) -> [u8; 32] {
+        let mut data = b"LUMINA_ROLE_UPDATE".to_vec();
+        data.extend(
+            bincode::serialize(&(role, account, granted, self.nonce)).expect("role update"),
+        );
+        *blake3::hash(&data).as_bytes()
+    }
+}
+
+/// Tunable protocol economics. Ratios are in basis points (10000 = 100%).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ProtocolParams {
+    /// MintSenior fee routed to the insurance fund
+    pub mint_fee_bps: u64,
+    /// Reserve ratio below which senior redemptions are queued
+    pub redemption_queue_threshold_bps: u64,
+    /// Reserve ratio below which the circuit breaker trips
+    pub circuit_breaker_threshold_bps: u64,
+    /// Minimum FlashMint collateral relative to the minted amount
+    pub flash_mint_collateral_bps: u64,
+    /// Minimum blocks between RotateReserves
+    pub reserve_rotation_interval: u64,
+    /// DistributeYield split: junior holders / stabilization pool / insurance fund
+    pub yield_junior_bps: u64,
+    pub yield_pool_bps: u64,
+    pub yield_insurance_bps: u64,
+    /// Blocks unbonded validator stake stays locked before it can be withdrawn
+    pub unbonding_period_blocks: u64,
+    /// Share of a double-signing validator's stake moved to the insurance fund
+    pub slash_fraction_bps: u64,
+    /// Blocks a slashed validator stays jailed
+    pub jail_duration_blocks: u64,
+    /// Oldest infraction, in blocks behind the current height, that evidence may prove
+    pub evidence_max_age_blocks: u64,
+    /// Minimum vault collateral value relative to its LUSD debt
+    pub vault_collateral_ratio_bps: u64,
+    /// Surcharge on a liquidated vault's debt, paid to the insurance fund
+    pub vault_liquidation_penalty_bps: u64,
+    /// Opening auction price relative to the oracle value of the lot
+    pub vault_auction_start_bps: u64,
+    /// Blocks over which the auction price decays to zero before restarting
+    pub vault_auction_duration_blocks: u64,
+}
+
+impl Default for ProtocolParams {
+    fn default() -> Self {
+        Self {
+            mint_fee_bps: 500,
+            redemption_queue_threshold_bps: 9_500,
+            circuit_breaker_threshold_bps: 8_500,
+            flash_mint_collateral_bps: 11_000,
+            reserve_rotation_interval: 259_200, // ~30 days at 10s/block
+            yield_junior_bps: 8_000,
+            yield_pool_bps: 1_500,
+            yield_insurance_bps: 500,
+            unbonding_period_blocks: 120_960, // ~14 days at 10s/block
+            slash_fraction_bps: 500,
+            jail_duration_blocks: 60_480,     // ~7 days
+            evidence_max_age_blocks: 100_800, // ~11.7 days, inside the unbonding period
+            vault_collateral_ratio_bps: 15_000,
+            vault_liquidation_penalty_bps: 1_300,
+            vault_auction_start_bps: 12_000,
+            vault_auction_duration_blocks: 360, // ~1 hour
+        }
+    }
+}
+
+/// Voting rules applied to every governance proposal.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GovernanceParams {
+    /// Blocks a proposal stays open for voting after submission
+    pub voting_period_blocks: u64,
+    /// Share of total validator power that must vote (basis points)
+    pub quorum_bps: u64,
+    /// Share of voted power that must approve (basis points)
+    pub approval_threshold_bps: u64,
+}
+
+impl Default for GovernanceParams {
+    fn default() -> Self {
+        Self {
+            voting_period_blocks: 25_920, // ~3 days at 10s/block
+            quorum_bps: 3_334,
+            approval_threshold_bps: 6_667,
+        }
+    }
+}
+
+/// State change carried out when a proposal is approved.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum ProposalAction {
+    UpdateGovernanceParams(GovernanceParams),
+    SetCircuitBreaker {
+        active: bool,
+    },
+    SetCreditOracle {
+        oracle: [u8; 32],
+        trusted: bool,
+    },
+    UpdateProtocolParams(ProtocolParams),
+    SetRole {
+        role: Role,
+        account: [u8; 32],
+        granted: bool,
+    },
+    SetAdminMultisig {
+        members: Vec<[u8; 32]>,
+        threshold: u32,
+    },
+    SetRwaClassParams {
+        class: RwaAssetClass,
+        params: RwaClassParams,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum ProposalStatus {
+    Active,
+    Rejected,
+    Executed,
+    /// Approved, but the action could not be applied
+    Failed {
+        error: ExecutionError,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProposalVote {
+    pub approve: bool,
+    /// Voter power snapshotted when the vote was cast
+    pub power: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: [u8; 32],
+    pub action: ProposalAction,
+    pub submitted_height: u64,
+    /// First height at which voting is closed and the proposal is tallied
+    pub voting_end_height: u64,
+    pub votes: BTreeMap<[u8; 32], ProposalVote>,
+    pub status: ProposalStatus,
+}
+
+impl Proposal {
+    /// Returns (approving power, rejecting power).
+    pub fn tally(&self) -> (u64, u64) {
+        self.votes.values().fold((0u64, 0u64), |(yes, no), v| {
+            if v.approve {
+                (yes.saturating_add(v.power), no)
+            } else {
+                (yes, no.saturating_add(v.power))
+            }
+        })
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -253,14 +846,154 @@ pub struct CustodianState {
     pub registered_height: u64,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrderFill {
+    pub filled: u64,
+    pub expires_at: u64,
+}
+
+/// Issuance record for a custom asset.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AssetInfo {
+    pub issuer: [u8; 32],
+    pub decimals: u8,
+    pub total_supply: u64,
+    pub max_supply: Option<u64>,
+    /// Account allowed to freeze and unfreeze holders
+    pub freeze_authority: Option<[u8; 32]>,
+    pub frozen: BTreeSet<[u8; 32]>,
+}
+
+impl AssetInfo {
+    pub fn is_frozen(&self, account: &[u8; 32]) -> bool {
+        self.frozen.contains(account)
+    }
+}
+
+/// Custom assets by ticker. Only registered tickers can be held or moved.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AssetRegistry {
+    pub assets: BTreeMap<String, AssetInfo>,
+}
+
+impl AssetRegistry {
+    pub fn get(&self, ticker: &str) -> Option<&AssetInfo> {
+        self.assets.get(ticker)
+    }
+
+    pub fn get_mut(&mut self, ticker: &str) -> Option<&mut AssetInfo> {
+        self.assets.get_mut(ticker)
+    }
+}
+
+/// Kind of real-world asset behind a listing; selects its risk parameters.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RwaAssetClass {
+    TreasuryBill,
+    Invoice,
+    RealEstate,
+}
+
+/// Collateral rules for one RWA asset class. Ratios are in basis points.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RwaClassParams {
+    /// Discount applied to the attested value before it counts as collateral
+    pub haircut_bps: u64,
+    /// Share of the haircut value that may be borrowed
+    pub max_ltv_bps: u64,
+    /// Seconds after `valued_at` at which a valuation goes stale
+    pub max_valuation_age: u64,
+}
+
+impl RwaClassParams {
+    /// Defaults used until governance sets the class explicitly.
+    pub fn default_for(class: RwaAssetClass) -> Self {
+        let (haircut_bps, max_ltv_bps, max_valuation_age) = match class {
+            RwaAssetClass::TreasuryBill => (200, 9_000, 7 * 86_400),
+            RwaAssetClass::Invoice => (1_500, 7_500, 30 * 86_400),
+            RwaAssetClass::RealEstate => (3_000, 6_000, 180 * 86_400),
+        };
+        Self {
+            haircut_bps,
+            max_ltv_bps,
+            max_valuation_age,
+        }
+    }
+
+    /// Value counted as collateral after the haircut.
+    pub fn collateral_value(&self, attested_value: u64) -> u64 {
+        let kept = 10_000u64.saturating_sub(self.haircut_bps);
+        ((attested_value as u128) * (kept as u128) / 10_000) as u64
+    }
+
+    /// Largest debt `attested_value` can back.
+    pub fn max_debt(&self, attested_value: u64) -> u64 {
+        let value = self.collateral_value(attested_value) as u128;
+        (value * (self.max_ltv_bps.min(10_000) as u128) / 10_000) as u64
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct RWAListing {
     pub owner: [u8; 32],
     pub asset_description: String,
+    pub asset_class: RwaAssetClass,
     pub attestation_proof: Vec<u8>,
     pub attested_value: u64,
+    /// Block timestamp of the latest valuation (listing or revaluation)
+    pub valued_at: u64,
+    /// Block timestamp at which the asset matures
     pub maturity_date: Option<u64>,
     pub collateral_eligibility: bool,
-    pub is_active: bool,
+    /// Collateral value locked against loans
     pub pledged_amount: u64,
+    /// LUSD minted against the pledge and not yet repaid
+    pub debt: u64,
+}
+
+/// Collateralized debt position of one account.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Vault {
+    /// Locked collateral by asset ticker
+    pub collateral: BTreeMap<String, u64>,
+    /// LUSD minted against the collateral and not yet repaid
+    pub debt: u64,
+}
+
+/// Dutch auction selling a liquidated vault's collateral for LUSD. The asking
+/// price for the whole lot falls linearly from `start_price` to zero over
+/// `ProtocolParams::vault_auction_duration_blocks`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct VaultAuction {
+    pub owner: [u8; 32],
+    /// Collateral still for sale, by asset ticker
+    pub lot: BTreeMap<String, u64>,
+    /// LUSD still to raise against the vault's debt
+    pub debt: u64,
+    /// Liquidation penalty still to raise for the insurance fund
+    pub penalty: u64,
+    /// Asking price for the whole lot at `start_height`
+    pub start_price: u64,
+    pub start_height: u64,
+}
+
+impl Vault {
+    /// Key of `owner`'s vault in the state trie; hashed so it cannot collide
+    /// with the owner's account key.
+    pub fn trie_key(owner: &[u8; 32]) -> [u8; 32] {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(b"lumina:vault");
+        hasher.update(owner);
+        *hasher.finalize().as_bytes()
+    }
+}
+
+impl VaultAuction {
+    /// Key of auction `auction_id` in the state trie.
+    pub fn trie_key(auction_id: u64) -> [u8; 32] {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(b"lumina:vault_auction");
+        hasher.update(&auction_id.to_le_bytes());
+        *hasher.finalize().as_bytes()
+    }
 }
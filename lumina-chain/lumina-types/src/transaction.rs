@@ -1,6 +1,9 @@
 use crate::instruction::StablecoinInstruction;
 use serde::{Deserialize, Serialize};
 
+/// Prefix marking a `signature` made by one of the sender's session keys.
+const SESSION_SIGNATURE_TAG: &[u8] = b"LUMINA_SESSION";
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Transaction {
     pub sender: [u8; 32],
@@ -12,6 +15,10 @@ pub struct Transaction {
 }
 
 impl Transaction {
+    /// Bytes covered by the sender's signature. A bundle is signed as a single
+    /// [`StablecoinInstruction::Bundle`], so the signature commits to every
+    /// instruction and their order; single-instruction transactions encode
+    /// exactly as before.
     pub fn signing_bytes(&self) -> Vec<u8> {
         #[derive(Serialize)]
         struct SigningTx<'a> {
@@ -33,6 +40,44 @@ impl Transaction {
         bincode::serialize(&signing).expect("tx signing serialization")
     }
 
+    /// Packs member signatures over [`Self::signing_bytes`] into the single
+    /// `signature` field carried by a multisig sender.
+    pub fn set_multisig_signatures(&mut self, signatures: &[Vec<u8>]) {
+        self.signature = bincode::serialize(signatures).expect("multisig signatures");
+    }
+
+    /// Member signatures carried by a multisig sender, if `signature` decodes as such.
+    pub fn multisig_signatures(&self) -> Option<Vec<Vec<u8>>> {
+        bincode::deserialize(&self.signature).ok()
+    }
+
+    /// Marks `signature`, made over [`Self::signing_bytes`], as coming from the
+    /// sender's session key `pubkey` rather than its own key.
+    pub fn set_session_signature(&mut self, pubkey: &[u8; 32], signature: &[u8]) {
+        let mut out = SESSION_SIGNATURE_TAG.to_vec();
+        out.extend_from_slice(pubkey);
+        out.extend_from_slice(signature);
+        self.signature = out;
+    }
+
+    /// The session key and its signature, if `signature` is session-signed.
+    pub fn session_signature(&self) -> Option<([u8; 32], &[u8])> {
+        let rest = self.signature.strip_prefix(SESSION_SIGNATURE_TAG)?;
+        if rest.len() < 32 {
+            return None;
+        }
+        let (pubkey, signature) = rest.split_at(32);
+        Some((pubkey.try_into().ok()?, signature))
+    }
+
+    /// The instructions this transaction executes, in order.
+    pub fn instructions(&self) -> &[StablecoinInstruction] {
+        match &self.instruction {
+            StablecoinInstruction::Bundle { instructions } => instructions,
+            single => std::slice::from_ref(single),
+        }
+    }
+
     pub fn id(&self) -> [u8; 32] {
         let mut hasher = blake3::Hasher::new();
         hasher.update(&self.signing_bytes());